@@ -0,0 +1,202 @@
+//audio_capture.rs opens cpal input devices in any sample format and picks a supported input config
+use cpal::traits::{DeviceTrait, HostTrait};
+use cpal::{FromSample, SampleFormat, SizedSample};
+use serde::{Deserialize, Serialize};
+
+// Formats in order of preference when the device's default format isn't
+// available at the requested rate/channels: widest lossless formats first.
+const PREFERRED_FORMATS: [SampleFormat; 11] = [
+    SampleFormat::F32,
+    SampleFormat::I32,
+    SampleFormat::I24,
+    SampleFormat::I16,
+    SampleFormat::F64,
+    SampleFormat::I64,
+    SampleFormat::U32,
+    SampleFormat::U16,
+    SampleFormat::U64,
+    SampleFormat::I8,
+    SampleFormat::U8,
+];
+
+/// Input config to open the device with. Unset fields use the device default.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputConfigRequest {
+    #[serde(default)]
+    pub sample_rate: Option<u32>,
+    #[serde(default)]
+    pub channels: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NativeInputConfig {
+    pub sample_format: String,
+    pub channels: u16,
+    pub min_sample_rate: u32,
+    pub max_sample_rate: u32,
+    pub is_default: bool,
+}
+
+/// Finds an input device by name (stable), then by numeric index; falls back
+/// to the default input device.
+pub fn find_input_device(device_id: Option<&str>) -> Result<cpal::Device, String> {
+    let host = cpal::default_host();
+    let default_device = host
+        .default_input_device()
+        .ok_or_else(|| "no_default_input_device".to_string())?;
+    let Some(id) = device_id else {
+        return Ok(default_device);
+    };
+
+    let mut index_match: Option<cpal::Device> = None;
+    if let Ok(iter) = host.input_devices() {
+        for (idx, dev) in iter.enumerate() {
+            if dev.name().is_ok_and(|name| name == id) {
+                return Ok(dev);
+            }
+            if index_match.is_none() && id.parse::<usize>().ok() == Some(idx) {
+                index_match = Some(dev);
+            }
+        }
+    }
+    Ok(index_match.unwrap_or(default_device))
+}
+
+fn format_rank(format: SampleFormat) -> usize {
+    PREFERRED_FORMATS
+        .iter()
+        .position(|f| *f == format)
+        .unwrap_or(PREFERRED_FORMATS.len())
+}
+
+/// The device default config, or the supported config closest to it that
+/// matches the requested sample rate and/or channel count.
+pub fn select_input_config(
+    device: &cpal::Device,
+    wanted: &InputConfigRequest,
+) -> Result<cpal::SupportedStreamConfig, String> {
+    let default = device
+        .default_input_config()
+        .map_err(|e| format!("default_input_config_failed:{}", e));
+    if wanted.sample_rate.is_none() && wanted.channels.is_none() {
+        return default;
+    }
+    let default = default.ok();
+    let default_rate = default.as_ref().map(|c| c.sample_rate().0);
+    let default_channels = default.as_ref().map(|c| c.channels());
+    let default_format = default.as_ref().map(|c| c.sample_format());
+
+    let ranges = device
+        .supported_input_configs()
+        .map_err(|e| format!("supported_input_configs_failed:{}", e))?;
+    ranges
+        .filter(|range| wanted.channels.is_none_or(|c| range.channels() == c))
+        .filter_map(|range| {
+            let (min, max) = (range.min_sample_rate().0, range.max_sample_rate().0);
+            let rate = match (wanted.sample_rate, default_rate) {
+                (Some(rate), _) if (min..=max).contains(&rate) => rate,
+                (Some(_), _) => return None,
+                (None, Some(rate)) if (min..=max).contains(&rate) => rate,
+                (None, _) => max,
+            };
+            Some(range.with_sample_rate(cpal::SampleRate(rate)))
+        })
+        .min_by_key(|config| {
+            (
+                Some(config.channels()) != default_channels,
+                Some(config.sample_rate().0) != default_rate,
+                Some(config.sample_format()) != default_format,
+                format_rank(config.sample_format()),
+            )
+        })
+        .ok_or_else(|| {
+            format!(
+                "unsupported_input_config:{}Hz/{}ch",
+                wanted.sample_rate.map(|r| r.to_string()).unwrap_or_else(|| "any".to_string()),
+                wanted.channels.map(|c| c.to_string()).unwrap_or_else(|| "any".to_string())
+            )
+        })
+}
+
+pub fn list_input_configs(device: &cpal::Device) -> Result<Vec<NativeInputConfig>, String> {
+    let default = device.default_input_config().ok();
+    let ranges = device
+        .supported_input_configs()
+        .map_err(|e| format!("supported_input_configs_failed:{}", e))?;
+    let mut configs: Vec<NativeInputConfig> = ranges
+        .map(|range| {
+            let (min, max) = (range.min_sample_rate().0, range.max_sample_rate().0);
+            let is_default = default.as_ref().is_some_and(|d| {
+                d.channels() == range.channels()
+                    && d.sample_format() == range.sample_format()
+                    && (min..=max).contains(&d.sample_rate().0)
+            });
+            NativeInputConfig {
+                sample_format: range.sample_format().to_string(),
+                channels: range.channels(),
+                min_sample_rate: min,
+                max_sample_rate: max,
+                is_default,
+            }
+        })
+        .collect();
+    configs.sort_by(|a, b| b.is_default.cmp(&a.is_default).then(a.channels.cmp(&b.channels)));
+    Ok(configs)
+}
+
+/// Opens an input stream in the config's native sample format and hands the
+/// callback interleaved f32 samples in [-1, 1]. Stream errors go to `on_error`.
+pub fn build_input_stream<D, E>(
+    device: &cpal::Device,
+    config: &cpal::SupportedStreamConfig,
+    on_data: D,
+    on_error: E,
+) -> Result<cpal::Stream, String>
+where
+    D: FnMut(&[f32]) + Send + 'static,
+    E: FnMut(String) + Send + 'static,
+{
+    let stream_config = config.config();
+    match config.sample_format() {
+        SampleFormat::I8 => build::<i8, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::I16 => build::<i16, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::I24 => build::<cpal::I24, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::I32 => build::<i32, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::I64 => build::<i64, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::U8 => build::<u8, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::U16 => build::<u16, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::U32 => build::<u32, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::U64 => build::<u64, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::F32 => build::<f32, _, _>(device, &stream_config, on_data, on_error),
+        SampleFormat::F64 => build::<f64, _, _>(device, &stream_config, on_data, on_error),
+        other => Err(format!("unsupported_sample_format:{}", other)),
+    }
+}
+
+fn build<T, D, E>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    mut on_data: D,
+    mut on_error: E,
+) -> Result<cpal::Stream, String>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+    D: FnMut(&[f32]) + Send + 'static,
+    E: FnMut(String) + Send + 'static,
+{
+    let mut converted: Vec<f32> = Vec::new();
+    device
+        .build_input_stream(
+            config,
+            move |data: &[T], _: &cpal::InputCallbackInfo| {
+                converted.clear();
+                converted.extend(data.iter().map(|s| s.to_sample::<f32>()));
+                on_data(&converted);
+            },
+            move |err| on_error(err.to_string()),
+            None,
+        )
+        .map_err(|e| format!("build_stream_failed:{}", e))
+}
@@ -0,0 +1,135 @@
+//audio_meter.rs measures input levels (RMS/peak dBFS), clipping and silence for the native capture stream
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_METER_INTERVAL_MS: u32 = 100;
+const MIN_METER_INTERVAL_MS: u32 = 50;
+const MAX_METER_INTERVAL_MS: u32 = 100;
+
+// Reported instead of -inf for digital silence (below 16-bit resolution).
+pub const DBFS_FLOOR: f32 = -96.0;
+
+// Samples at or above this magnitude count as full scale. A single
+// full-scale sample can be legitimate, so clipping needs a run of them.
+const CLIP_LEVEL: f32 = 0.999;
+const CLIP_RUN: u32 = 3;
+
+// A block quieter than this is silent; the input is reported as silent once
+// that lasts SILENCE_HOLD_MS (a muted mic or unplugged cable, not a pause).
+const SILENCE_THRESHOLD_DB: f32 = -60.0;
+const SILENCE_HOLD_MS: u64 = 3000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioLevel {
+    pub rms_db: f32,
+    pub peak_db: f32,
+    pub clipping: bool,
+    pub clipped_samples: u32,
+    pub silent: bool,
+    pub silence_ms: u64,
+    pub block_ms: u32,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+pub fn to_dbfs(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        return DBFS_FLOOR;
+    }
+    (20.0 * amplitude.log10()).max(DBFS_FLOOR)
+}
+
+pub fn clamp_interval_ms(interval_ms: Option<u32>) -> u32 {
+    interval_ms
+        .unwrap_or(DEFAULT_METER_INTERVAL_MS)
+        .clamp(MIN_METER_INTERVAL_MS, MAX_METER_INTERVAL_MS)
+}
+
+// Accumulates interleaved samples (all channels, before downmixing so one
+// clipping channel isn't averaged away) and reports once per block.
+pub struct LevelMeter {
+    sample_rate: u32,
+    channels: u16,
+    block_frames: usize,
+    frames: usize,
+    sum_squares: f64,
+    peak: f32,
+    // Consecutive full-scale samples per channel; interleaving would otherwise
+    // break the run of a single clipping channel.
+    clip_runs: Vec<u32>,
+    clipped_samples: u32,
+    clipping: bool,
+    silence_ms: u64,
+}
+
+impl LevelMeter {
+    pub fn new(sample_rate: u32, channels: u16, interval_ms: Option<u32>) -> Self {
+        let block_ms = clamp_interval_ms(interval_ms);
+        LevelMeter {
+            sample_rate,
+            channels: channels.max(1),
+            block_frames: ((sample_rate as u64 * block_ms as u64) / 1000).max(1) as usize,
+            frames: 0,
+            sum_squares: 0.0,
+            peak: 0.0,
+            clip_runs: vec![0; channels.max(1) as usize],
+            clipped_samples: 0,
+            clipping: false,
+            silence_ms: 0,
+        }
+    }
+
+    pub fn push(&mut self, interleaved: &[f32], mut on_level: impl FnMut(AudioLevel)) {
+        let channels = self.channels as usize;
+        for frame in interleaved.chunks(channels) {
+            for (&sample, clip_run) in frame.iter().zip(self.clip_runs.iter_mut()) {
+                let magnitude = sample.abs();
+                self.sum_squares += (sample as f64) * (sample as f64);
+                self.peak = self.peak.max(magnitude);
+                if magnitude >= CLIP_LEVEL {
+                    *clip_run += 1;
+                    if *clip_run >= CLIP_RUN {
+                        self.clipping = true;
+                    }
+                    self.clipped_samples += 1;
+                } else {
+                    *clip_run = 0;
+                }
+            }
+            self.frames += 1;
+            if self.frames >= self.block_frames {
+                on_level(self.finish_block());
+            }
+        }
+    }
+
+    fn finish_block(&mut self) -> AudioLevel {
+        let samples = (self.frames * self.channels as usize).max(1);
+        let rms = (self.sum_squares / samples as f64).sqrt() as f32;
+        let rms_db = to_dbfs(rms);
+        let block_ms = ((self.frames as u64 * 1000) / self.sample_rate.max(1) as u64) as u32;
+        if rms_db < SILENCE_THRESHOLD_DB {
+            self.silence_ms += block_ms as u64;
+        } else {
+            self.silence_ms = 0;
+        }
+
+        let level = AudioLevel {
+            rms_db,
+            peak_db: to_dbfs(self.peak),
+            clipping: self.clipping,
+            clipped_samples: self.clipped_samples,
+            silent: self.silence_ms >= SILENCE_HOLD_MS,
+            silence_ms: self.silence_ms,
+            block_ms,
+            sample_rate: self.sample_rate,
+            channels: self.channels,
+        };
+
+        self.frames = 0;
+        self.sum_squares = 0.0;
+        self.peak = 0.0;
+        self.clipped_samples = 0;
+        self.clipping = false;
+        level
+    }
+}
@@ -0,0 +1,314 @@
+//audio_resample.rs picks the capture channel and resamples with a band-limited (windowed-sinc) filter
+use std::f64::consts::PI;
+
+// Kernel length in zero crossings of the sinc on each side of the centre.
+// 24 gives a transition band of about 1 kHz at 16 kHz output.
+const ZERO_CROSSINGS: usize = 24;
+// Filter phases per input sample; in-between phases are interpolated.
+const PHASES: usize = 256;
+// Kaiser window beta; ~90 dB stopband attenuation.
+const KAISER_BETA: f64 = 9.0;
+// Cutoff as a fraction of the output Nyquist frequency, leaving room for the
+// transition band so content near Nyquist doesn't fold back.
+const CUTOFF: f64 = 0.92;
+
+/// Which input channel(s) feed the mono capture stream.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChannelSelection {
+    // Average of all channels. Out-of-phase stereo mics cancel in this mode.
+    Average,
+    Left,
+    Right,
+    // Zero-based channel index, for multi-channel interfaces.
+    Channel(usize),
+}
+
+impl ChannelSelection {
+    /// Parses "average", "left", "right" or a 1-based channel number ("3").
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "average" | "mix" => Ok(ChannelSelection::Average),
+            "left" => Ok(ChannelSelection::Left),
+            "right" => Ok(ChannelSelection::Right),
+            other => match other.parse::<usize>() {
+                Ok(n) if n >= 1 => Ok(ChannelSelection::Channel(n - 1)),
+                _ => Err(format!("invalid_channel:{}", value)),
+            },
+        }
+    }
+
+    // The channel index to take, or None to average. Channels the device
+    // doesn't have fall back to the last one (e.g. "right" on a mono mic).
+    fn index(self, channels: usize) -> Option<usize> {
+        let last = channels.saturating_sub(1);
+        match self {
+            ChannelSelection::Average => None,
+            ChannelSelection::Left => Some(0),
+            ChannelSelection::Right => Some(1.min(last)),
+            ChannelSelection::Channel(n) => Some(n.min(last)),
+        }
+    }
+}
+
+/// Reduces interleaved frames to one channel according to `selection`.
+pub fn select_channel(interleaved: &[f32], channels: usize, selection: ChannelSelection, mono: &mut Vec<f32>) {
+    mono.clear();
+    if channels <= 1 {
+        mono.extend_from_slice(interleaved);
+        return;
+    }
+    match selection.index(channels) {
+        Some(ch) => mono.extend(interleaved.chunks_exact(channels).map(|frame| frame[ch])),
+        None => mono.extend(
+            interleaved
+                .chunks_exact(channels)
+                .map(|frame| frame.iter().copied().sum::<f32>() / channels as f32),
+        ),
+    }
+}
+
+// Zeroth-order modified Bessel function of the first kind, for the Kaiser window.
+fn bessel_i0(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let half = x / 2.0;
+    for k in 1..50 {
+        term *= (half / k as f64) * (half / k as f64);
+        sum += term;
+        if term < sum * 1e-12 {
+            break;
+        }
+    }
+    sum
+}
+
+/// Streaming polyphase windowed-sinc resampler for a single channel.
+/// Works for any rate ratio; when downsampling the kernel is widened so it
+/// low-passes below the output Nyquist frequency before decimating.
+pub struct SincResampler {
+    step: f64,
+    half_taps: usize,
+    // (PHASES + 1) rows of 2 * half_taps coefficients.
+    table: Vec<f32>,
+    buf: Vec<f32>,
+    pos: f64,
+    passthrough: bool,
+}
+
+impl SincResampler {
+    pub fn new(in_rate: u32, out_rate: u32) -> Self {
+        let in_rate = in_rate.max(1) as f64;
+        let out_rate = out_rate.max(1) as f64;
+        // Cutoff relative to the input Nyquist frequency.
+        let cutoff = (out_rate / in_rate).min(1.0) * CUTOFF;
+        let half_width = ZERO_CROSSINGS as f64 / cutoff;
+        let half_taps = half_width.ceil() as usize;
+        let taps = 2 * half_taps;
+
+        let window_norm = bessel_i0(KAISER_BETA);
+        let mut table = Vec::with_capacity((PHASES + 1) * taps);
+        for phase in 0..=PHASES {
+            let frac = phase as f64 / PHASES as f64;
+            let start = table.len();
+            for j in 0..taps {
+                // Distance from the output position to tap j's input sample.
+                let t = frac + half_taps as f64 - 1.0 - j as f64;
+                let x = t * cutoff;
+                let sinc = if x.abs() < 1e-12 { 1.0 } else { (PI * x).sin() / (PI * x) };
+                let r = t / half_width;
+                let window = if r.abs() >= 1.0 {
+                    0.0
+                } else {
+                    bessel_i0(KAISER_BETA * (1.0 - r * r).sqrt()) / window_norm
+                };
+                table.push((cutoff * sinc * window) as f32);
+            }
+            // Unity DC gain for every phase.
+            let sum: f32 = table[start..].iter().sum();
+            if sum.abs() > f32::EPSILON {
+                for c in &mut table[start..] {
+                    *c /= sum;
+                }
+            }
+        }
+
+        SincResampler {
+            step: in_rate / out_rate,
+            half_taps,
+            table,
+            // History of zeros so the first output lines up with the first input.
+            buf: vec![0.0; half_taps - 1],
+            pos: (half_taps - 1) as f64,
+            passthrough: in_rate == out_rate,
+        }
+    }
+
+    pub fn push_and_resample(&mut self, input: &[f32], output: &mut Vec<f32>) {
+        if self.passthrough {
+            output.extend_from_slice(input);
+            return;
+        }
+        self.buf.extend_from_slice(input);
+        let taps = 2 * self.half_taps;
+        while (self.pos.floor() as usize) + self.half_taps < self.buf.len() {
+            let i0 = self.pos.floor() as usize;
+            let scaled = (self.pos - i0 as f64) * PHASES as f64;
+            let phase = (scaled.floor() as usize).min(PHASES - 1);
+            let blend = (scaled - phase as f64) as f32;
+            let a = &self.table[phase * taps..(phase + 1) * taps];
+            let b = &self.table[(phase + 1) * taps..(phase + 2) * taps];
+            let window = &self.buf[i0 + 1 - self.half_taps..=i0 + self.half_taps];
+            let mut acc_a = 0.0f32;
+            let mut acc_b = 0.0f32;
+            for ((x, ca), cb) in window.iter().zip(a).zip(b) {
+                acc_a += x * ca;
+                acc_b += x * cb;
+            }
+            output.push(acc_a + (acc_b - acc_a) * blend);
+            self.pos += self.step;
+        }
+
+        // Drop samples no future output needs, keeping the filter history.
+        let keep_from = (self.pos.floor() as usize).saturating_sub(self.half_taps - 1);
+        if keep_from > 0 {
+            let keep_from = keep_from.min(self.buf.len());
+            self.buf.drain(0..keep_from);
+            self.pos -= keep_from as f64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(freq: f64, rate: u32, secs: f64, amplitude: f64) -> Vec<f32> {
+        let n = (rate as f64 * secs) as usize;
+        (0..n)
+            .map(|i| (amplitude * (2.0 * PI * freq * i as f64 / rate as f64).sin()) as f32)
+            .collect()
+    }
+
+    // Resamples in uneven blocks, as the capture callback does.
+    fn resample(input: &[f32], in_rate: u32, out_rate: u32) -> Vec<f32> {
+        let mut resampler = SincResampler::new(in_rate, out_rate);
+        let mut out = Vec::new();
+        for block in input.chunks(441) {
+            resampler.push_and_resample(block, &mut out);
+        }
+        out
+    }
+
+    // Drops the filter's start-up transient and the tail.
+    fn steady(samples: &[f32]) -> &[f32] {
+        let skip = samples.len() / 10;
+        &samples[skip..samples.len() - skip]
+    }
+
+    fn rms(samples: &[f32]) -> f64 {
+        (samples.iter().map(|s| (*s as f64).powi(2)).sum::<f64>() / samples.len() as f64).sqrt()
+    }
+
+    fn db(ratio: f64) -> f64 {
+        20.0 * ratio.max(1e-12).log10()
+    }
+
+    // Least-squares fit of a sinusoid at `freq`; returns (fit amplitude, residual).
+    fn fit_sine(samples: &[f32], freq: f64, rate: u32) -> (f64, Vec<f32>) {
+        let w = 2.0 * PI * freq / rate as f64;
+        let (mut ss, mut sc, mut cc, mut ys, mut yc) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for (i, y) in samples.iter().enumerate() {
+            let (s, c) = (w * i as f64).sin_cos();
+            let y = *y as f64;
+            ss += s * s;
+            sc += s * c;
+            cc += c * c;
+            ys += y * s;
+            yc += y * c;
+        }
+        let det = ss * cc - sc * sc;
+        let a = (ys * cc - yc * sc) / det;
+        let b = (yc * ss - ys * sc) / det;
+        let residual = samples
+            .iter()
+            .enumerate()
+            .map(|(i, y)| {
+                let (s, c) = (w * i as f64).sin_cos();
+                (*y as f64 - a * s - b * c) as f32
+            })
+            .collect();
+        ((a * a + b * b).sqrt(), residual)
+    }
+
+    // THD+N in dB: everything that isn't the fundamental, relative to it.
+    fn thd_n(samples: &[f32], freq: f64, rate: u32) -> f64 {
+        let (amplitude, residual) = fit_sine(samples, freq, rate);
+        db(rms(&residual) / (amplitude / 2f64.sqrt()))
+    }
+
+    #[test]
+    fn output_length_follows_rate_ratio() {
+        let input = tone(440.0, 48_000, 1.0, 0.5);
+        let out = resample(&input, 48_000, 16_000);
+        let expected = 16_000 - SincResampler::new(48_000, 16_000).half_taps / 3;
+        assert!(out.len().abs_diff(expected) <= 2, "{} vs {}", out.len(), expected);
+    }
+
+    #[test]
+    fn passband_tone_keeps_its_level_and_low_thd() {
+        for (in_rate, freq) in [(48_000, 1_000.0), (44_100, 1_000.0), (48_000, 3_000.0), (8_000, 1_000.0)] {
+            let out = resample(&tone(freq, in_rate, 1.0, 0.5), in_rate, 16_000);
+            let steady = steady(&out);
+            let (amplitude, _) = fit_sine(steady, freq, 16_000);
+            assert!((db(amplitude / 0.5)).abs() < 0.1, "{} Hz @ {}: gain {:.3} dB", freq, in_rate, db(amplitude / 0.5));
+            let thd = thd_n(steady, freq, 16_000);
+            assert!(thd < -70.0, "{} Hz @ {}: THD+N {:.1} dB", freq, in_rate, thd);
+        }
+    }
+
+    #[test]
+    fn tones_above_output_nyquist_do_not_alias() {
+        // 48k -> 16k: a 10 kHz tone would fold to 6 kHz, 12 kHz to 4 kHz.
+        for (in_rate, freq) in [(48_000, 10_000.0), (48_000, 12_000.0), (48_000, 20_000.0), (44_100, 9_000.0)] {
+            let out = resample(&tone(freq, in_rate, 1.0, 0.5), in_rate, 16_000);
+            let alias = db(rms(steady(&out)) / (0.5 / 2f64.sqrt()));
+            assert!(alias < -60.0, "{} Hz @ {}: alias {:.1} dB", freq, in_rate, alias);
+        }
+    }
+
+    #[test]
+    fn matching_rates_pass_through() {
+        let input = tone(1_000.0, 16_000, 0.1, 0.5);
+        assert_eq!(resample(&input, 16_000, 16_000), input);
+    }
+
+    #[test]
+    fn channel_selection() {
+        // Out-of-phase stereo: averaging cancels, picking a side doesn't.
+        let interleaved = [0.5, -0.5, 0.25, -0.25];
+        let mut mono = Vec::new();
+        select_channel(&interleaved, 2, ChannelSelection::Average, &mut mono);
+        assert_eq!(mono, vec![0.0, 0.0]);
+        select_channel(&interleaved, 2, ChannelSelection::Left, &mut mono);
+        assert_eq!(mono, vec![0.5, 0.25]);
+        select_channel(&interleaved, 2, ChannelSelection::Right, &mut mono);
+        assert_eq!(mono, vec![-0.5, -0.25]);
+
+        let four = [0.1, 0.2, 0.3, 0.4];
+        select_channel(&four, 4, ChannelSelection::Channel(2), &mut mono);
+        assert_eq!(mono, vec![0.3]);
+        select_channel(&four, 4, ChannelSelection::Channel(9), &mut mono);
+        assert_eq!(mono, vec![0.4]);
+        select_channel(&[0.7, 0.8], 1, ChannelSelection::Right, &mut mono);
+        assert_eq!(mono, vec![0.7, 0.8]);
+    }
+
+    #[test]
+    fn parses_channel_selection() {
+        assert_eq!(ChannelSelection::parse("Left"), Ok(ChannelSelection::Left));
+        assert_eq!(ChannelSelection::parse(""), Ok(ChannelSelection::Average));
+        assert_eq!(ChannelSelection::parse("3"), Ok(ChannelSelection::Channel(2)));
+        assert!(ChannelSelection::parse("0").is_err());
+        assert!(ChannelSelection::parse("centre").is_err());
+    }
+}
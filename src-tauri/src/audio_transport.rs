@@ -0,0 +1,134 @@
+//audio_transport.rs frames native capture PCM for the frontend as raw bytes (IPC channel) or legacy base64 events
+use serde::Serialize;
+
+pub const DEFAULT_OUT_RATE: u32 = 16_000;
+const MIN_OUT_RATE: u32 = 8_000;
+const MAX_OUT_RATE: u32 = 48_000;
+
+pub const DEFAULT_CHUNK_MS: u32 = 250;
+const MIN_CHUNK_MS: u32 = 20;
+const MAX_CHUNK_MS: u32 = 1000;
+
+// What the stream delivers: mono PCM16LE at `sample_rate`, `chunk_samples`
+// per message. Returned by `start_native_audio_stream` so clients don't
+// have to assume 16 kHz.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AudioStreamFormat {
+    pub sample_rate: u32,
+    pub chunk_ms: u32,
+    pub chunk_samples: usize,
+    pub binary: bool, // raw bytes over an IPC channel rather than base64 events
+}
+
+impl AudioStreamFormat {
+    pub fn new(sample_rate: Option<u32>, chunk_ms: Option<u32>, binary: bool) -> Self {
+        let sample_rate = sample_rate
+            .unwrap_or(DEFAULT_OUT_RATE)
+            .clamp(MIN_OUT_RATE, MAX_OUT_RATE);
+        let chunk_ms = chunk_ms.unwrap_or(DEFAULT_CHUNK_MS).clamp(MIN_CHUNK_MS, MAX_CHUNK_MS);
+        AudioStreamFormat {
+            sample_rate,
+            chunk_ms,
+            chunk_samples: ((sample_rate as u64 * chunk_ms as u64) / 1000) as usize,
+            binary,
+        }
+    }
+}
+
+pub fn encode_pcm16le(samples: &[i16]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(samples.len() * 2);
+    for s in samples {
+        bytes.extend_from_slice(&s.to_le_bytes());
+    }
+    bytes
+}
+
+pub fn decode_pcm16le(bytes: &[u8]) -> Result<Vec<i16>, String> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(format!("invalid_pcm_length:{}", bytes.len()));
+    }
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use std::time::Instant;
+
+    #[test]
+    fn format_is_clamped() {
+        let format = AudioStreamFormat::new(None, None, true);
+        assert_eq!((format.sample_rate, format.chunk_ms, format.chunk_samples), (16_000, 250, 4000));
+        let format = AudioStreamFormat::new(Some(96_000), Some(5), false);
+        assert_eq!((format.sample_rate, format.chunk_ms, format.chunk_samples), (48_000, 20, 960));
+    }
+
+    #[test]
+    fn pcm16le_round_trips() {
+        let samples = [0, 1, -1, i16::MAX, i16::MIN, 12345];
+        assert_eq!(decode_pcm16le(&encode_pcm16le(&samples)), Ok(samples.to_vec()));
+        assert!(decode_pcm16le(&[1, 2, 3]).is_err());
+    }
+
+    // Compares the legacy path (base64 in a JSON event out, JSON number
+    // array in for asr_push_audio) with raw bytes, for one hour of 16 kHz
+    // audio in 250 ms chunks. Only the Rust side is measured; the webview
+    // also saves the atob() and JSON.parse work.
+    // cargo test --release bench_audio_transport -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_audio_transport() {
+        #[derive(Serialize)]
+        struct NativeAudioChunk {
+            data_b64: String,
+        }
+        let format = AudioStreamFormat::new(None, None, true);
+        let chunk: Vec<i16> = (0..format.chunk_samples).map(|i| ((i * 7919) % 65536) as u16 as i16).collect();
+        let chunks = 3600 * 1000 / format.chunk_ms as usize;
+
+        let start = Instant::now();
+        let mut legacy_bytes = 0;
+        for _ in 0..chunks {
+            let data_b64 = base64::engine::general_purpose::STANDARD.encode(encode_pcm16le(&chunk));
+            legacy_bytes += serde_json::to_string(&NativeAudioChunk { data_b64 }).unwrap().len();
+        }
+        let legacy_out = start.elapsed();
+
+        let start = Instant::now();
+        let mut binary_bytes = 0;
+        for _ in 0..chunks {
+            binary_bytes += encode_pcm16le(&chunk).len();
+        }
+        let binary_out = start.elapsed();
+
+        let json_array = serde_json::to_string(&serde_json::json!({ "pcmChunk": chunk })).unwrap();
+        let start = Instant::now();
+        for _ in 0..chunks {
+            let value: serde_json::Value = serde_json::from_str(&json_array).unwrap();
+            let pcm: Vec<i16> = serde_json::from_value(value["pcmChunk"].clone()).unwrap();
+            assert_eq!(pcm.len(), chunk.len());
+        }
+        let legacy_in = start.elapsed();
+
+        let raw = encode_pcm16le(&chunk);
+        let start = Instant::now();
+        for _ in 0..chunks {
+            assert_eq!(decode_pcm16le(&raw).unwrap().len(), chunk.len());
+        }
+        let binary_in = start.elapsed();
+
+        println!("{} chunks of {} samples", chunks, format.chunk_samples);
+        println!("capture -> webview: base64 event {:?} ({} bytes), raw {:?} ({} bytes)", legacy_out, legacy_bytes, binary_out, binary_bytes);
+        println!(
+            "webview -> asr: JSON array {:?} ({} bytes/chunk), raw {:?} ({} bytes/chunk)",
+            legacy_in,
+            json_array.len(),
+            binary_in,
+            raw.len()
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//ccli_report.rs recognises CCLI songs and builds song usage reports from the display history
+use crate::csv_util::csv_line;
+use crate::display_history::DisplayHistoryEntry;
+use chrono::{Local, TimeZone};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CcliSong {
+    pub ccli_number: String,
+    pub title: String,
+}
+
+// One service and the songs projected in it; see `starts_new_service`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CcliService {
+    pub date: String, // local date the service started
+    pub started_at: u64, // first display history entry, epoch ms
+    pub ended_at: u64, // last display history entry, epoch ms
+    pub songs: Vec<CcliSong>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CcliReportSong {
+    pub ccli_number: String,
+    pub title: String,
+    // Distinct services the song was projected in; going back and forth
+    // between slides of the same song counts once per service.
+    pub projections: usize,
+    pub service_dates: Vec<String>, // one per service, so a date can repeat
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CcliReport {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub services: Vec<CcliService>,
+    pub songs: Vec<CcliReportSong>,
+}
+
+// Nothing on the displays for this long means the service is over.
+const SERVICE_GAP_MS: u64 = 60 * 60 * 1000;
+
+// CCLI song numbers are plain integers (up to 8 digits in practice).
+fn normalize_number(value: &str) -> Option<String> {
+    let digits: String = value.trim().trim_start_matches('#').trim().chars().take_while(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() || digits.len() > 10 {
+        return None;
+    }
+    Some(digits.trim_start_matches('0').to_string()).filter(|d| !d.is_empty())
+}
+
+// Finds "CCLI 22025", "CCLI Song # 22025", "CCLI #: 22025" or "{ccli: 22025}"
+// and returns the number with the byte range it occupied. CCLI licence
+// numbers ("CCLI License # ...") are not song numbers and are skipped.
+fn find_number(text: &str) -> Option<(String, std::ops::Range<usize>)> {
+    // ASCII lowercasing keeps byte offsets valid for slicing `text`.
+    let lower = text.to_ascii_lowercase();
+    let mut search_from = 0;
+    while let Some(pos) = lower[search_from..].find("ccli") {
+        let start = search_from + pos;
+        search_from = start + 4;
+        let mut rest = lower[start + 4..].trim_start();
+        if rest.starts_with("licen") {
+            continue;
+        }
+        while let Some(stripped) = ["song", "number", "no.", "no", "#", ":", "-", "="]
+            .iter()
+            .find_map(|p| rest.strip_prefix(p))
+        {
+            rest = stripped.trim_start();
+        }
+        if let Some(number) = normalize_number(rest) {
+            let digits_start = lower.len() - rest.len();
+            let end = digits_start + rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            return Some((number, start..end));
+        }
+    }
+    None
+}
+
+// Strips the CCLI marker and the brackets/separators around it from a title.
+fn clean_title(name: &str, range: Option<std::ops::Range<usize>>) -> String {
+    let mut title = name.to_string();
+    if let Some(range) = range {
+        title.replace_range(range, "");
+    }
+    title
+        .replace("()", "")
+        .replace("[]", "")
+        .replace("{}", "")
+        .trim()
+        .trim_end_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '|' | ',' | '(' | '['))
+        .trim()
+        .to_string()
+}
+
+/// Recognises a song from a session or playlist item: an explicit number
+/// (e.g. from a SongSelect import) wins, then a marker in the name, then one
+/// in the lyrics/footer text. Returns None when no CCLI number is present.
+pub fn detect_song(name: &str, text: &str, ccli_number: Option<&str>) -> Option<CcliSong> {
+    let name = name.trim();
+    let in_name = find_number(name);
+    let number = ccli_number
+        .and_then(normalize_number)
+        .or_else(|| in_name.as_ref().map(|(n, _)| n.clone()))
+        .or_else(|| text.lines().find_map(find_number).map(|(n, _)| n))?;
+
+    let mut title = clean_title(name, in_name.map(|(_, range)| range));
+    if title.is_empty() {
+        // ChordPro-style `{title: ...}` or a leading "Title: ..." line.
+        title = text
+            .lines()
+            .map(str::trim)
+            .find_map(|line| {
+                let lower = line.to_ascii_lowercase();
+                ["{title:", "{t:", "title:"]
+                    .iter()
+                    .find(|p| lower.starts_with(*p))
+                    .map(|p| line[p.len()..].trim_end_matches('}').trim().to_string())
+            })
+            .unwrap_or_default();
+    }
+    Some(CcliSong { ccli_number: number, title })
+}
+
+/// Finds the playlist item whose slides include `lines` and recognises its
+/// song. Playlists are the JSON the frontend publishes for the REST API.
+pub fn find_playlist_song(playlists: &serde_json::Value, lines: &[String]) -> Option<CcliSong> {
+    let wanted: Vec<&str> = lines.iter().map(|l| l.trim()).filter(|l| !l.is_empty()).collect();
+    if wanted.is_empty() {
+        return None;
+    }
+    let items = playlists
+        .as_array()?
+        .iter()
+        .filter_map(|p| p.get("items").and_then(|i| i.as_array()))
+        .flatten();
+    for item in items {
+        let slides: Vec<&str> = item
+            .get("slides")
+            .and_then(|s| s.as_array())
+            .map(|s| s.iter().filter_map(|slide| slide.get("text").and_then(|t| t.as_str())).collect())
+            .unwrap_or_default();
+        let matches = slides.iter().any(|text| {
+            let slide_lines: Vec<&str> = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+            slide_lines == wanted
+        });
+        if !matches {
+            continue;
+        }
+        let title = item.get("title").and_then(|t| t.as_str()).unwrap_or("");
+        let number = item.get("ccliNumber").and_then(|n| n.as_str());
+        return detect_song(title, &slides.join("\n"), number);
+    }
+    None
+}
+
+fn service_date(timestamp: u64) -> String {
+    Local
+        .timestamp_millis_opt(timestamp as i64)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+// The display history has no service marker, so a service ends after an hour
+// with nothing going live, or when the schedule starts over from its first
+// item (back-to-back services run the same schedule again).
+fn starts_new_service(previous: &DisplayHistoryEntry, entry: &DisplayHistoryEntry) -> bool {
+    entry.timestamp.saturating_sub(previous.timestamp) > SERVICE_GAP_MS
+        || (entry.schedule_index == Some(0) && previous.schedule_index.is_some_and(|i| i > 0))
+}
+
+struct ServiceSongs {
+    started_at: u64,
+    ended_at: u64,
+    // ccli number -> title (first non-empty title seen wins)
+    songs: BTreeMap<String, String>,
+}
+
+pub fn build_report(entries: &[DisplayHistoryEntry], from: Option<u64>, to: Option<u64>) -> CcliReport {
+    let mut sorted: Vec<&DisplayHistoryEntry> = entries.iter().collect();
+    sorted.sort_by_key(|e| e.timestamp);
+
+    let mut by_service: Vec<ServiceSongs> = Vec::new();
+    let mut previous: Option<&DisplayHistoryEntry> = None;
+    for entry in sorted {
+        if previous.is_none_or(|p| starts_new_service(p, entry)) {
+            by_service.push(ServiceSongs {
+                started_at: entry.timestamp,
+                ended_at: entry.timestamp,
+                songs: BTreeMap::new(),
+            });
+        }
+        previous = Some(entry);
+        let Some(service) = by_service.last_mut() else {
+            continue;
+        };
+        service.ended_at = entry.timestamp;
+
+        let Some(number) = entry.ccli_number.as_deref().and_then(normalize_number) else {
+            continue;
+        };
+        let known = service.songs.entry(number).or_default();
+        if known.is_empty() {
+            *known = entry.song_title.clone().unwrap_or_default();
+        }
+    }
+
+    let mut totals: BTreeMap<String, CcliReportSong> = BTreeMap::new();
+    let services = by_service
+        .into_iter()
+        .filter(|service| !service.songs.is_empty())
+        .map(|service| {
+            let date = service_date(service.started_at);
+            let songs: Vec<CcliSong> = service
+                .songs
+                .into_iter()
+                .map(|(ccli_number, title)| CcliSong { ccli_number, title })
+                .collect();
+            for song in &songs {
+                let total = totals.entry(song.ccli_number.clone()).or_insert_with(|| CcliReportSong {
+                    ccli_number: song.ccli_number.clone(),
+                    title: String::new(),
+                    projections: 0,
+                    service_dates: Vec::new(),
+                });
+                if total.title.is_empty() {
+                    total.title = song.title.clone();
+                }
+                total.projections += 1;
+                total.service_dates.push(date.clone());
+            }
+            CcliService {
+                date,
+                started_at: service.started_at,
+                ended_at: service.ended_at,
+                songs,
+            }
+        })
+        .collect();
+
+    let mut songs: Vec<CcliReportSong> = totals.into_values().collect();
+    songs.sort_by(|a, b| b.projections.cmp(&a.projections).then_with(|| a.title.to_lowercase().cmp(&b.title.to_lowercase())));
+    CcliReport {
+        from,
+        to,
+        services,
+        songs,
+    }
+}
+
+// Column layout follows CCLI's usage reporting (song number, title, and a
+// count per reproduction type); only projection is tracked here.
+pub fn export_csv(report: &CcliReport) -> String {
+    let mut out = csv_line(&["CCLI Song Number", "Song Title", "Projections", "Service Dates"]);
+    for song in &report.songs {
+        out.push_str(&csv_line(&[
+            &song.ccli_number,
+            &song.title,
+            &song.projections.to_string(),
+            &song.service_dates.join("; "),
+        ]));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display_history::DisplayHistoryKind;
+
+    const MINUTE: u64 = 60 * 1000;
+
+    fn entry(timestamp: u64, ccli_number: Option<&str>, title: &str, schedule_index: Option<usize>) -> DisplayHistoryEntry {
+        let mut entry = DisplayHistoryEntry::new(DisplayHistoryKind::SlideGoLive, timestamp);
+        entry.ccli_number = ccli_number.map(str::to_string);
+        entry.song_title = Some(title.to_string()).filter(|t| !t.is_empty());
+        entry.schedule_index = schedule_index;
+        entry
+    }
+
+    fn numbers(service: &CcliService) -> Vec<&str> {
+        service.songs.iter().map(|s| s.ccli_number.as_str()).collect()
+    }
+
+    #[test]
+    fn finds_song_numbers_but_not_licence_numbers() {
+        assert_eq!(find_number("Amazing Grace (CCLI 22025)").map(|(n, _)| n).as_deref(), Some("22025"));
+        assert_eq!(find_number("CCLI Song # 0022025").map(|(n, _)| n).as_deref(), Some("22025"));
+        assert_eq!(find_number("ccli #: 4768151").map(|(n, _)| n).as_deref(), Some("4768151"));
+        assert_eq!(find_number("{ccli: 7127647}").map(|(n, _)| n).as_deref(), Some("7127647"));
+        assert_eq!(find_number("CCLI License # 11223344"), None);
+        assert_eq!(
+            find_number("CCLI Licence No. 11223344, CCLI Song 22025").map(|(n, _)| n).as_deref(),
+            Some("22025")
+        );
+        assert_eq!(find_number("CCLI"), None);
+    }
+
+    #[test]
+    fn detects_title_and_number() {
+        let song = detect_song("Amazing Grace (CCLI 22025)", "", None).unwrap();
+        assert_eq!(song, CcliSong { ccli_number: "22025".to_string(), title: "Amazing Grace".to_string() });
+
+        let song = detect_song("", "{title: Cornerstone}\nMy hope is built\nCCLI Song # 6158927", None).unwrap();
+        assert_eq!(song.ccli_number, "6158927");
+        assert_eq!(song.title, "Cornerstone");
+
+        assert_eq!(detect_song("Amazing Grace", "CCLI 1", Some("22025")).unwrap().ccli_number, "22025");
+        assert_eq!(detect_song("Welcome", "CCLI License # 123", None), None);
+    }
+
+    #[test]
+    fn counts_each_song_once_per_service() {
+        let start = 1_000 * MINUTE;
+        let entries = vec![
+            entry(start, Some("22025"), "Amazing Grace", Some(0)),
+            entry(start + MINUTE, Some("22025"), "", Some(0)),
+            entry(start + 2 * MINUTE, None, "", Some(1)),
+            entry(start + 3 * MINUTE, Some("22025"), "Amazing Grace", Some(1)),
+            entry(start + 4 * MINUTE, Some("6158927"), "Cornerstone", Some(2)),
+        ];
+        let report = build_report(&entries, None, None);
+        assert_eq!(report.services.len(), 1);
+        assert_eq!(numbers(&report.services[0]), vec!["22025", "6158927"]);
+        assert_eq!(report.services[0].started_at, start);
+        assert_eq!(report.services[0].ended_at, start + 4 * MINUTE);
+        assert_eq!(report.songs[0].projections, 1);
+    }
+
+    #[test]
+    fn services_are_split_by_gaps_and_schedule_restarts() {
+        let first = 1_000 * MINUTE;
+        let entries = vec![
+            // 9:00 service
+            entry(first, Some("22025"), "Amazing Grace", Some(0)),
+            entry(first + 50 * MINUTE, Some("6158927"), "Cornerstone", Some(3)),
+            // 11:00 service, the schedule starts over right after the last item
+            entry(first + 70 * MINUTE, Some("22025"), "Amazing Grace", Some(0)),
+            // Evening service after a long gap, no schedule
+            entry(first + 9 * 60 * MINUTE, Some("22025"), "Amazing Grace", None),
+            entry(first + 9 * 60 * MINUTE + MINUTE, None, "", None),
+        ];
+        let report = build_report(&entries, Some(0), None);
+        assert_eq!(report.services.len(), 3);
+        assert_eq!(numbers(&report.services[0]), vec!["22025", "6158927"]);
+        assert_eq!(numbers(&report.services[1]), vec!["22025"]);
+        assert_eq!(numbers(&report.services[2]), vec!["22025"]);
+
+        let grace = &report.songs[0];
+        assert_eq!((grace.ccli_number.as_str(), grace.projections), ("22025", 3));
+        assert_eq!(grace.service_dates.len(), 3);
+        assert_eq!(report.songs[1].projections, 1);
+    }
+
+    #[test]
+    fn services_without_songs_are_left_out() {
+        let entries = vec![entry(MINUTE, None, "", None), entry(3 * 60 * MINUTE, Some("22025"), "Amazing Grace", None)];
+        let report = build_report(&entries, None, None);
+        assert_eq!(report.services.len(), 1);
+        assert_eq!(report.services[0].started_at, 3 * 60 * MINUTE);
+    }
+}
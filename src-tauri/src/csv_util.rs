@@ -0,0 +1,14 @@
+//csv_util.rs writes RFC 4180 CSV fields and lines for the report and schedule exports
+
+pub fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn csv_line(fields: &[&str]) -> String {
+    let escaped: Vec<String> = fields.iter().map(|f| csv_escape(f)).collect();
+    format!("{}\r\n", escaped.join(","))
+}
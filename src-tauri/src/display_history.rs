@@ -0,0 +1,321 @@
+//display_history.rs keeps a persisted, timestamped log of what went live on the displays
+use crate::csv_util::csv_line;
+use chrono::{Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::sync::{mpsc, Mutex, OnceLock};
+
+pub const DISPLAY_HISTORY_FILE_NAME: &str = "display-history.jsonl";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayHistoryKind {
+    DisplayUpdate,
+    SlideGoLive,
+    ScriptureGoLive,
+}
+
+impl DisplayHistoryKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            DisplayHistoryKind::DisplayUpdate => "display_update",
+            DisplayHistoryKind::SlideGoLive => "slide_go_live",
+            DisplayHistoryKind::ScriptureGoLive => "scripture_go_live",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayHistoryEntry {
+    pub timestamp: u64,
+    pub kind: DisplayHistoryKind,
+    #[serde(default)]
+    pub output: Option<String>,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub translation: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub live_slide_session_id: Option<String>,
+    #[serde(default)]
+    pub live_slide_session_name: Option<String>,
+    #[serde(default)]
+    pub slide_index: Option<usize>,
+    // Song recognised from the session or playlist item, for CCLI reporting.
+    #[serde(default)]
+    pub ccli_number: Option<String>,
+    #[serde(default)]
+    pub song_title: Option<String>,
+    // Schedule item that was active when the entry was recorded.
+    #[serde(default)]
+    pub schedule_index: Option<usize>,
+    #[serde(default)]
+    pub schedule_session: Option<String>,
+    #[serde(default)]
+    pub schedule_minister: Option<String>,
+}
+
+impl DisplayHistoryEntry {
+    pub fn new(kind: DisplayHistoryKind, timestamp: u64) -> Self {
+        DisplayHistoryEntry {
+            timestamp,
+            kind,
+            output: None,
+            reference: None,
+            translation: None,
+            text: String::new(),
+            live_slide_session_id: None,
+            live_slide_session_name: None,
+            slide_index: None,
+            ccli_number: None,
+            song_title: None,
+            schedule_index: None,
+            schedule_session: None,
+            schedule_minister: None,
+        }
+    }
+
+    // Identifies what was on screen, so repeated `update_display_state` calls
+    // for settings-only changes don't produce duplicate entries.
+    fn content_key(&self) -> String {
+        format!(
+            "{}\u{1f}{}\u{1f}{}\u{1f}{}\u{1f}{:?}",
+            self.kind.as_str(),
+            self.reference.as_deref().unwrap_or(""),
+            self.translation.as_deref().unwrap_or(""),
+            self.text,
+            self.slide_index
+        )
+    }
+
+    fn stream_key(&self) -> String {
+        match self.kind {
+            DisplayHistoryKind::DisplayUpdate => format!("display:{}", self.output.as_deref().unwrap_or("")),
+            DisplayHistoryKind::SlideGoLive => {
+                format!("slides:{}", self.live_slide_session_id.as_deref().unwrap_or(""))
+            }
+            DisplayHistoryKind::ScriptureGoLive => "scripture".to_string(),
+        }
+    }
+}
+
+// Work for the writer thread, which applies it in the order it was queued.
+#[derive(Debug)]
+enum WriterCommand {
+    Append(Box<DisplayHistoryEntry>),
+    ForgetStream(String),
+    Clear(mpsc::Sender<Result<(), String>>),
+}
+
+#[derive(Debug, Default)]
+pub struct DisplayHistoryLog {
+    path: OnceLock<PathBuf>,
+    // Last content per output/session; also serialises appends to the file.
+    last_keys: Mutex<HashMap<String, String>>,
+    writer: OnceLock<mpsc::Sender<WriterCommand>>,
+}
+
+impl DisplayHistoryLog {
+    // Also starts the writer thread, so callers on the async runtime never
+    // wait on file I/O.
+    pub fn set_path(&'static self, path: PathBuf) {
+        if self.path.set(path).is_err() {
+            return;
+        }
+        let (tx, rx) = mpsc::channel::<WriterCommand>();
+        std::thread::spawn(move || {
+            for command in rx {
+                let result = match command {
+                    WriterCommand::Append(entry) => self.append(&entry).map(|_| ()),
+                    WriterCommand::ForgetStream(stream) => self.forget_stream(&stream),
+                    WriterCommand::Clear(reply) => {
+                        let _ = reply.send(self.remove_log());
+                        Ok(())
+                    }
+                };
+                if let Err(e) = result {
+                    eprintln!("[Display] Failed to record display history: {}", e);
+                }
+            }
+        });
+        let _ = self.writer.set(tx);
+    }
+
+    fn send(&self, command: WriterCommand) -> Result<(), String> {
+        let writer = self.writer.get().ok_or_else(|| "display_history_unavailable".to_string())?;
+        writer.send(command).map_err(|_| "display_history_unavailable".to_string())
+    }
+
+    pub fn record(&self, entry: DisplayHistoryEntry) -> Result<(), String> {
+        self.send(WriterCommand::Append(Box::new(entry)))
+    }
+
+    // The entry's output or session was cleared: the next content shown there
+    // is recorded even if it repeats what was on screen before the clear.
+    pub fn screen_cleared(&self, entry: &DisplayHistoryEntry) -> Result<(), String> {
+        self.send(WriterCommand::ForgetStream(entry.stream_key()))
+    }
+
+    fn path(&self) -> Result<&PathBuf, String> {
+        self.path.get().ok_or_else(|| "display_history_unavailable".to_string())
+    }
+
+    // Returns Ok(false) when the entry repeats what is already on screen.
+    fn append(&self, entry: &DisplayHistoryEntry) -> Result<bool, String> {
+        let path = self.path()?;
+        let mut last_keys = self.last_keys.lock().map_err(|_| "display_history_lock_poisoned".to_string())?;
+        let key = entry.content_key();
+        if last_keys.get(&entry.stream_key()) == Some(&key) {
+            return Ok(false);
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("create_dir_failed:{}", e))?;
+        }
+        let line = serde_json::to_string(entry).map_err(|e| format!("serialize_failed:{}", e))?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("write_failed:{}", e))?;
+        writeln!(file, "{}", line).map_err(|e| format!("write_failed:{}", e))?;
+
+        last_keys.insert(entry.stream_key(), key);
+        Ok(true)
+    }
+
+    pub fn query(&self, from: Option<u64>, to: Option<u64>) -> Result<Vec<DisplayHistoryEntry>, String> {
+        let path = self.path()?;
+        let file = match std::fs::File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(format!("read_failed:{}", e)),
+        };
+
+        let mut entries = Vec::new();
+        for line in std::io::BufReader::new(file).lines() {
+            let line = line.map_err(|e| format!("read_failed:{}", e))?;
+            // A torn last line (crash mid-write) shouldn't hide the rest of the log.
+            let Ok(entry) = serde_json::from_str::<DisplayHistoryEntry>(&line) else {
+                continue;
+            };
+            if from.is_some_and(|f| entry.timestamp < f) || to.is_some_and(|t| entry.timestamp > t) {
+                continue;
+            }
+            entries.push(entry);
+        }
+        entries.sort_by_key(|e| e.timestamp);
+        Ok(entries)
+    }
+
+    fn forget_stream(&self, stream: &str) -> Result<(), String> {
+        let mut last_keys = self.last_keys.lock().map_err(|_| "display_history_lock_poisoned".to_string())?;
+        last_keys.remove(stream);
+        Ok(())
+    }
+
+    // Queued behind pending appends so none of them land after the clear.
+    // Waits for the writer thread, so call it off the async runtime.
+    pub fn clear(&self) -> Result<(), String> {
+        let (reply, result) = mpsc::channel();
+        self.send(WriterCommand::Clear(reply))?;
+        result.recv().map_err(|_| "display_history_unavailable".to_string())?
+    }
+
+    fn remove_log(&self) -> Result<(), String> {
+        let path = self.path()?;
+        let mut last_keys = self.last_keys.lock().map_err(|_| "display_history_lock_poisoned".to_string())?;
+        match std::fs::remove_file(path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("write_failed:{}", e)),
+        }
+        last_keys.clear();
+        Ok(())
+    }
+}
+
+// Accepts epoch milliseconds, RFC 3339 or a plain `YYYY-MM-DD` (local time).
+// A bare date used as the upper bound covers the whole day.
+pub fn parse_time_bound(value: &str, end_of_day: bool) -> Result<u64, String> {
+    let value = value.trim();
+    if let Ok(millis) = value.parse::<u64>() {
+        return Ok(millis);
+    }
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return u64::try_from(dt.timestamp_millis()).map_err(|_| format!("invalid_time:{}", value));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let naive = if end_of_day {
+            date.and_hms_milli_opt(23, 59, 59, 999)
+        } else {
+            date.and_hms_opt(0, 0, 0)
+        }
+        .ok_or_else(|| format!("invalid_time:{}", value))?;
+        let local = Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .ok_or_else(|| format!("invalid_time:{}", value))?;
+        return u64::try_from(local.timestamp_millis()).map_err(|_| format!("invalid_time:{}", value));
+    }
+    Err(format!("invalid_time:{}", value))
+}
+
+pub fn parse_time_range(from: Option<&str>, to: Option<&str>) -> Result<(Option<u64>, Option<u64>), String> {
+    let from = from.filter(|v| !v.trim().is_empty()).map(|v| parse_time_bound(v, false)).transpose()?;
+    let to = to.filter(|v| !v.trim().is_empty()).map(|v| parse_time_bound(v, true)).transpose()?;
+    if let (Some(f), Some(t)) = (from, to) {
+        if f > t {
+            return Err("invalid_time_range".to_string());
+        }
+    }
+    Ok((from, to))
+}
+
+fn format_timestamp(millis: u64) -> String {
+    Local
+        .timestamp_millis_opt(millis as i64)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+pub fn export_csv(entries: &[DisplayHistoryEntry]) -> String {
+    let mut out = csv_line(&[
+        "Time",
+        "Kind",
+        "Output",
+        "Reference",
+        "Translation",
+        "Text",
+        "Live Slides Session",
+        "Slide",
+        "CCLI Song Number",
+        "Song Title",
+        "Schedule Session",
+        "Minister",
+    ]);
+    for entry in entries {
+        let slide = entry.slide_index.map(|i| (i + 1).to_string()).unwrap_or_default();
+        out.push_str(&csv_line(&[
+            &format_timestamp(entry.timestamp),
+            entry.kind.as_str(),
+            entry.output.as_deref().unwrap_or(""),
+            entry.reference.as_deref().unwrap_or(""),
+            entry.translation.as_deref().unwrap_or(""),
+            &entry.text,
+            entry.live_slide_session_name.as_deref().unwrap_or(""),
+            &slide,
+            entry.ccli_number.as_deref().unwrap_or(""),
+            entry.song_title.as_deref().unwrap_or(""),
+            entry.schedule_session.as_deref().unwrap_or(""),
+            entry.schedule_minister.as_deref().unwrap_or(""),
+        ]));
+    }
+    out
+}
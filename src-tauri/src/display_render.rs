@@ -0,0 +1,383 @@
+//display_render.rs draws a display output to SVG and rasterizes it to PNG on the CPU (no webview)
+use crate::display_settings::{DisplaySettings, FontStyle, LayoutRect, SlideLineStyle};
+use crate::DisplayState;
+use resvg::{tiny_skia, usvg};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, OnceLock};
+
+pub const DEFAULT_SNAPSHOT_WIDTH: u32 = 1280;
+pub const DEFAULT_SNAPSHOT_HEIGHT: u32 = 720;
+const MAX_SNAPSHOT_WIDTH: u32 = 3840;
+const MAX_SNAPSHOT_HEIGHT: u32 = 2160;
+const LINE_HEIGHT: f64 = 1.2; // matches /display and the audience window
+
+// Loading system fonts takes a while, so the database is built once.
+fn font_database() -> Arc<fontdb::Database> {
+    static FONTS: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+    FONTS
+        .get_or_init(|| {
+            let mut db = fontdb::Database::new();
+            db.load_system_fonts();
+            // fontdb's generic families default to Windows/macOS names; point
+            // them at whatever is installed so Linux gets a sensible face too.
+            let installed = |candidates: &[&str]| {
+                candidates
+                    .iter()
+                    .find(|name| db.faces().any(|face| face.families.iter().any(|(family, _)| family == *name)))
+                    .map(|name| name.to_string())
+            };
+            let sans = installed(&["Segoe UI", "Helvetica Neue", "Arial", "Noto Sans", "DejaVu Sans", "Liberation Sans"]);
+            let serif = installed(&["Times New Roman", "Georgia", "Noto Serif", "DejaVu Serif", "Liberation Serif"]);
+            let mono = installed(&["Consolas", "Menlo", "Courier New", "DejaVu Sans Mono", "Liberation Mono"]);
+            if let Some(name) = sans {
+                db.set_sans_serif_family(name);
+            }
+            if let Some(name) = serif {
+                db.set_serif_family(name);
+            }
+            if let Some(name) = mono {
+                db.set_monospace_family(name);
+            }
+            Arc::new(db)
+        })
+        .clone()
+}
+
+pub fn snapshot_size(width: Option<u32>, height: Option<u32>) -> (u32, u32) {
+    let width = width.unwrap_or(DEFAULT_SNAPSHOT_WIDTH).clamp(16, MAX_SNAPSHOT_WIDTH);
+    // Keep 16:9 unless a height is given.
+    let height = height
+        .unwrap_or(width * DEFAULT_SNAPSHOT_HEIGHT / DEFAULT_SNAPSHOT_WIDTH)
+        .clamp(16, MAX_SNAPSHOT_HEIGHT);
+    (width, height)
+}
+
+struct ResolvedFont {
+    id: fontdb::ID,
+    family: String, // the face's own family name, so usvg picks the same face
+}
+
+// Picks the first family of a CSS font-family list that is installed. Browser
+// aliases like system-ui fall back to the generic sans-serif family.
+fn resolve_font(db: &fontdb::Database, families: &str, bold: bool, italic: bool) -> Option<ResolvedFont> {
+    let names: Vec<String> = families
+        .split(',')
+        .map(|name| name.trim().trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let mut query_families: Vec<fontdb::Family> = names
+        .iter()
+        .map(|name| match name.to_ascii_lowercase().as_str() {
+            "serif" => fontdb::Family::Serif,
+            "monospace" => fontdb::Family::Monospace,
+            "cursive" => fontdb::Family::Cursive,
+            "fantasy" => fontdb::Family::Fantasy,
+            "sans-serif" | "system-ui" | "-apple-system" | "blinkmacsystemfont" => fontdb::Family::SansSerif,
+            _ => fontdb::Family::Name(name),
+        })
+        .collect();
+    query_families.push(fontdb::Family::SansSerif);
+
+    let query = fontdb::Query {
+        families: &query_families,
+        weight: if bold { fontdb::Weight::BOLD } else { fontdb::Weight::NORMAL },
+        style: if italic { fontdb::Style::Italic } else { fontdb::Style::Normal },
+        stretch: fontdb::Stretch::Normal,
+    };
+    let id = db.query(&query).or_else(|| db.faces().next().map(|face| face.id))?;
+    let family = db.face(id)?.families.first()?.0.clone();
+    Some(ResolvedFont { id, family })
+}
+
+struct TextBlock {
+    lines: Vec<String>,
+    font_size: f64,
+    baseline_shift: f64, // from a line's vertical center to its baseline, in px
+}
+
+// Word-wraps `text` into `width` x `height`, searching for the largest size
+// that fits like the frontend's auto-fit, unless a fixed size is given.
+fn layout_text(
+    db: &fontdb::Database,
+    font: &ResolvedFont,
+    text: &str,
+    (width, height): (f64, f64),
+    fixed_size: Option<f64>,
+    (min_size, max_size): (u32, u32),
+) -> Option<TextBlock> {
+    db.with_face_data(font.id, |data, index| {
+        let face = ttf_parser::Face::parse(data, index).ok()?;
+        let units_per_em = face.units_per_em() as f64;
+        let advances: HashMap<char, f64> = text
+            .chars()
+            .map(|c| {
+                let advance = face
+                    .glyph_index(c)
+                    .and_then(|glyph| face.glyph_hor_advance(glyph))
+                    .map(|a| a as f64)
+                    .unwrap_or(units_per_em * 0.5);
+                (c, advance / units_per_em)
+            })
+            .collect();
+        let measure = |s: &str| -> f64 {
+            s.chars()
+                .map(|c| advances.get(&c).copied().unwrap_or(0.5))
+                .sum()
+        };
+
+        let wrap = |size: f64| -> Vec<String> {
+            let max_em = width / size;
+            let mut lines = Vec::new();
+            for paragraph in text.split('\n') {
+                let mut line = String::new();
+                for word in paragraph.split_whitespace() {
+                    let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+                    if measure(&candidate) <= max_em {
+                        line = candidate;
+                        continue;
+                    }
+                    if !line.is_empty() {
+                        lines.push(std::mem::take(&mut line));
+                    }
+                    // Break words that are wider than the box on their own.
+                    for c in word.chars() {
+                        if !line.is_empty() && measure(&format!("{}{}", line, c)) > max_em {
+                            lines.push(std::mem::take(&mut line));
+                        }
+                        line.push(c);
+                    }
+                }
+                lines.push(line);
+            }
+            lines
+        };
+        let fits = |size: f64, lines: &[String]| {
+            lines.len() as f64 * size * LINE_HEIGHT <= height && lines.iter().all(|l| measure(l) * size <= width)
+        };
+
+        let font_size = match fixed_size {
+            Some(size) => size,
+            None => {
+                let (mut low, mut high, mut best) = (min_size, max_size, min_size);
+                while low <= high {
+                    let mid = (low + high) / 2;
+                    if fits(mid as f64, &wrap(mid as f64)) {
+                        best = mid;
+                        low = mid + 1;
+                    } else {
+                        high = mid - 1;
+                    }
+                }
+                best as f64
+            }
+        };
+
+        let ascender = face.ascender() as f64 / units_per_em;
+        let descender = face.descender() as f64 / units_per_em;
+        Some(TextBlock {
+            lines: wrap(font_size),
+            font_size,
+            baseline_shift: (ascender + descender) / 2.0 * font_size,
+        })
+    })
+    .flatten()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// Same placement as rectStyle() in /display: boxes live inside the margins.
+fn box_px(settings: &DisplaySettings, rect: &LayoutRect, width: f64, height: f64, padding: f64) -> (f64, f64, f64, f64) {
+    let m = &settings.margins;
+    let scale_x = 1.0 - m.left - m.right;
+    let scale_y = 1.0 - m.top - m.bottom;
+    let x = (m.left + rect.x * scale_x) * width + padding;
+    let y = (m.top + rect.y * scale_y) * height + padding;
+    let w = (rect.width * scale_x * width - padding * 2.0).max(1.0);
+    let h = (rect.height * scale_y * height - padding * 2.0).max(1.0);
+    (x, y, w, h)
+}
+
+struct TextRequest<'a> {
+    text: &'a str,
+    rect: LayoutRect,
+    padding: f64,
+    font_family: &'a str,
+    font_size: Option<f64>,
+    size_range: (u32, u32),
+    style: FontStyle,
+}
+
+fn write_text(svg: &mut String, defs: &mut String, db: &fontdb::Database, settings: &DisplaySettings, request: &TextRequest, size: (f64, f64)) {
+    let style = &request.style;
+    let Some(font) = resolve_font(db, request.font_family, style.bold, style.italic) else {
+        return;
+    };
+    let (x, y, w, h) = box_px(settings, &request.rect, size.0, size.1, request.padding);
+    let Some(block) = layout_text(db, &font, request.text, (w, h), request.font_size, request.size_range) else {
+        return;
+    };
+
+    let mut attributes = format!(
+        r#"text-anchor="middle" font-family="{}" font-size="{:.1}" font-weight="{}" font-style="{}" fill="{}""#,
+        escape_xml(&font.family),
+        block.font_size,
+        if style.bold { "bold" } else { "normal" },
+        if style.italic { "italic" } else { "normal" },
+        escape_xml(&style.color),
+    );
+    if let Some(stroke) = &style.stroke {
+        let _ = write!(attributes, r#" stroke="{}" stroke-width="{}""#, escape_xml(&stroke.color), stroke.width);
+    }
+    if let Some(shadow) = &style.shadow {
+        let id = format!("shadow{}", defs.matches("<filter").count());
+        let _ = write!(
+            defs,
+            r#"<filter id="{}" x="-20%" y="-20%" width="140%" height="140%"><feDropShadow dx="{}" dy="{}" stdDeviation="{}" flood-color="{}"/></filter>"#,
+            id,
+            shadow.offset_x,
+            shadow.offset_y,
+            shadow.blur / 2.0,
+            escape_xml(&shadow.color)
+        );
+        let _ = write!(attributes, r#" filter="url(#{})""#, id);
+    }
+
+    let line_height = block.font_size * LINE_HEIGHT;
+    let top = y + (h - line_height * block.lines.len() as f64) / 2.0;
+    let _ = write!(svg, "<g {}>", attributes);
+    for (i, line) in block.lines.iter().enumerate() {
+        let baseline = top + line_height * (i as f64 + 0.5) + block.baseline_shift;
+        let _ = write!(svg, r#"<text x="{:.1}" y="{:.1}" xml:space="preserve">{}</text>"#, x + w / 2.0, baseline, escape_xml(line));
+    }
+    svg.push_str("</g>");
+}
+
+fn slide_line_style(base: &FontStyle, line: Option<&SlideLineStyle>) -> FontStyle {
+    let Some(line) = line else {
+        return base.clone();
+    };
+    FontStyle {
+        color: line.color.clone().unwrap_or_else(|| base.color.clone()),
+        bold: line.bold.unwrap_or(base.bold),
+        italic: line.italic.unwrap_or(base.italic),
+        stroke: line.stroke.clone().or_else(|| base.stroke.clone()),
+        shadow: line.shadow.clone().or_else(|| base.shadow.clone()),
+    }
+}
+
+// The reference as /display shows it, with the translation appended.
+fn display_reference(state: &DisplayState) -> String {
+    let reference = state.scripture.reference.trim();
+    match state.scripture.translation_short_name.as_deref() {
+        Some(translation) if state.settings.display_translation && !translation.is_empty() && !reference.is_empty() => {
+            let suffix = format!(" ({})", translation);
+            if reference.ends_with(&suffix) {
+                reference.to_string()
+            } else {
+                format!("{}{}", reference, suffix)
+            }
+        }
+        _ => reference.to_string(),
+    }
+}
+
+pub fn render_svg(state: &DisplayState, width: u32, height: u32) -> String {
+    let db = font_database();
+    let settings = &state.settings;
+    let size = (width as f64, height as f64);
+    let mut defs = String::new();
+    let mut body = String::new();
+
+    let _ = write!(body, r#"<rect width="100%" height="100%" fill="{}"/>"#, escape_xml(&settings.background_color));
+    if !settings.background_image_data_url.is_empty() {
+        let _ = write!(
+            body,
+            r#"<image width="{}" height="{}" preserveAspectRatio="xMidYMid slice" href="{}"/>"#,
+            width,
+            height,
+            escape_xml(&settings.background_image_data_url)
+        );
+    }
+
+    let slides: Vec<(usize, &String)> = state
+        .slides
+        .iter()
+        .enumerate()
+        .take(6)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    let slides_active = !slides.is_empty();
+    let mut requests = Vec::new();
+    if slides_active {
+        for (i, line) in slides {
+            let layout = &settings.slides_layout;
+            let rect = layout
+                .get(i)
+                .or(layout.last())
+                .copied()
+                .unwrap_or(LayoutRect { x: 0.08, y: 0.62, width: 0.84, height: 0.12 });
+            let line_style = settings.slides_line_styles.get(i);
+            requests.push(TextRequest {
+                text: line,
+                rect,
+                padding: 8.0,
+                font_family: line_style.and_then(|s| s.font_family.as_deref()).unwrap_or(&settings.text_font),
+                font_size: settings.text_font_size,
+                size_range: (14, 300),
+                style: slide_line_style(&settings.text_style, line_style),
+            });
+        }
+    } else if !state.scripture.verse_text.is_empty() {
+        requests.push(TextRequest {
+            text: &state.scripture.verse_text,
+            rect: settings.layout.text,
+            padding: 8.0,
+            font_family: &settings.text_font,
+            font_size: settings.text_font_size,
+            size_range: (14, 220),
+            style: settings.text_style.clone(),
+        });
+    }
+    let reference = display_reference(state);
+    if !slides_active && !reference.is_empty() {
+        requests.push(TextRequest {
+            text: &reference,
+            rect: settings.layout.reference,
+            padding: 6.0,
+            font_family: &settings.reference_font,
+            font_size: settings.reference_font_size,
+            size_range: (12, 160),
+            style: settings.reference_style.clone(),
+        });
+    }
+    for request in &requests {
+        write_text(&mut body, &mut defs, &db, settings, request, size);
+    }
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}"><defs>{defs}</defs>{body}</svg>"#,
+        w = width,
+        h = height,
+        defs = defs,
+        body = body
+    )
+}
+
+pub fn render_png(state: &DisplayState, width: u32, height: u32) -> Result<Vec<u8>, String> {
+    let svg = render_svg(state, width, height);
+    let options = usvg::Options {
+        fontdb: font_database(),
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_str(&svg, &options).map_err(|e| format!("snapshot_render_failed:{}", e))?;
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).ok_or_else(|| "snapshot_render_failed:invalid size".to_string())?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(|e| format!("snapshot_render_failed:{}", e))
+}
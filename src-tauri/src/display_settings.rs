@@ -0,0 +1,362 @@
+//display_settings.rs contains the typed audience display settings shared by /display and the desktop window
+use crate::monitors::SafeMonitorInfo;
+use serde::{Deserialize, Serialize};
+
+// Bump when a field changes meaning, and teach `DisplaySettings::from_json` to migrate.
+pub const DISPLAY_SETTINGS_VERSION: u32 = 1;
+
+const DEFAULT_FONT: &str = "system-ui, -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif";
+const MAX_SLIDE_LINES: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LayoutRect {
+    pub x: f64, // fractions of the output size
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl LayoutRect {
+    const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        LayoutRect { x, y, width, height }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayLayout {
+    pub text: LayoutRect,
+    pub reference: LayoutRect,
+}
+
+impl Default for DisplayLayout {
+    fn default() -> Self {
+        DisplayLayout {
+            text: LayoutRect::new(0.08, 0.1, 0.84, 0.6),
+            reference: LayoutRect::new(0.08, 0.75, 0.84, 0.16),
+        }
+    }
+}
+
+fn default_slides_layout() -> Vec<LayoutRect> {
+    vec![
+        LayoutRect::new(0.08, 0.62, 0.84, 0.12),
+        LayoutRect::new(0.08, 0.76, 0.84, 0.12),
+    ]
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextStroke {
+    pub width: f64,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextShadow {
+    pub offset_x: f64,
+    pub offset_y: f64,
+    pub blur: f64,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FontStyle {
+    pub color: String,
+    pub bold: bool,
+    pub italic: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stroke: Option<TextStroke>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<TextShadow>,
+}
+
+impl Default for FontStyle {
+    fn default() -> Self {
+        FontStyle {
+            color: "#ffffff".to_string(),
+            bold: false,
+            italic: false,
+            stroke: None,
+            shadow: None,
+        }
+    }
+}
+
+// Per-line overrides for slides; unset fields fall back to the text style.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SlideLineStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stroke: Option<TextStroke>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<TextShadow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+}
+
+// Safe area around all content, as fractions of the output size.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayMargins {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionKind {
+    #[default]
+    None,
+    Fade,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DisplayTransition {
+    pub kind: TransitionKind,
+    pub duration_ms: u32,
+}
+
+impl Default for DisplayTransition {
+    fn default() -> Self {
+        DisplayTransition {
+            kind: TransitionKind::None,
+            duration_ms: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DisplaySettings {
+    pub version: u32,
+    pub enabled: bool,
+    pub window_audience_screen: bool,
+    pub web_enabled: bool,
+    pub display_translation: bool,
+    pub monitor_index: Option<u32>,
+    // The monitor picked by `monitor_index`; it still finds the monitor after
+    // they are rearranged, which shifts the indexes.
+    pub monitor: Option<SafeMonitorInfo>,
+    pub background_color: String,
+    pub background_image_path: String,
+    pub background_image_data_url: String, // the image for web clients, as a data: URL
+    pub text_font: String,
+    pub reference_font: String,
+    pub text_font_size: Option<f64>, // px; None fits the text to its box
+    pub reference_font_size: Option<f64>,
+    pub text_style: FontStyle,
+    pub reference_style: FontStyle,
+    pub layout: DisplayLayout,
+    pub slides_layout: Vec<LayoutRect>,
+    pub slides_line_styles: Vec<SlideLineStyle>,
+    pub margins: DisplayMargins,
+    pub transition: DisplayTransition,
+    pub show_timer: bool,
+    pub timer_font_size: f64,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        DisplaySettings {
+            version: DISPLAY_SETTINGS_VERSION,
+            enabled: false,
+            window_audience_screen: false,
+            web_enabled: false,
+            display_translation: true,
+            monitor_index: None,
+            monitor: None,
+            background_color: "#000000".to_string(),
+            background_image_path: String::new(),
+            background_image_data_url: String::new(),
+            text_font: DEFAULT_FONT.to_string(),
+            reference_font: DEFAULT_FONT.to_string(),
+            text_font_size: None,
+            reference_font_size: None,
+            text_style: FontStyle::default(),
+            reference_style: FontStyle::default(),
+            layout: DisplayLayout::default(),
+            slides_layout: default_slides_layout(),
+            slides_line_styles: Vec::new(),
+            margins: DisplayMargins::default(),
+            transition: DisplayTransition::default(),
+            show_timer: false,
+            timer_font_size: 150.0,
+        }
+    }
+}
+
+impl DisplaySettings {
+    // Reads settings from any version of the JSON the app has stored. Missing
+    // fields take defaults; settings without a `version` predate versioning.
+    pub fn from_json(value: serde_json::Value) -> Result<Self, String> {
+        let serde_json::Value::Object(mut object) = value else {
+            return Err("invalid_display_settings:expected an object".to_string());
+        };
+        let version = match object.get("version") {
+            None | Some(serde_json::Value::Null) => 0,
+            Some(v) => v
+                .as_u64()
+                .ok_or_else(|| "invalid_display_settings:version must be a number".to_string())?
+                as u32,
+        };
+        if version > DISPLAY_SETTINGS_VERSION {
+            return Err(format!("unsupported_display_settings_version:{}", version));
+        }
+
+        // A null means "unset" here, so the default applies.
+        object.retain(|_, value| !value.is_null());
+        for key in ["textStyle", "referenceStyle"] {
+            if let Some(serde_json::Value::Object(style)) = object.get_mut(key) {
+                style.retain(|_, v| !v.is_null());
+            }
+        }
+
+        if version == 0 {
+            // An empty list meant "use the default boxes".
+            if object.get("slidesLayout").and_then(|v| v.as_array()).is_some_and(|a| a.is_empty()) {
+                object.remove("slidesLayout");
+            }
+            // Old slide line styles could be null placeholders.
+            if let Some(serde_json::Value::Array(styles)) = object.get_mut("slidesLineStyles") {
+                for style in styles.iter_mut() {
+                    if !style.is_object() {
+                        *style = serde_json::json!({});
+                    }
+                }
+            }
+        }
+
+        let mut settings: DisplaySettings = serde_json::from_value(serde_json::Value::Object(object))
+            .map_err(|e| format!("invalid_display_settings:{}", e))?;
+        settings.version = DISPLAY_SETTINGS_VERSION;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let invalid = |field: &str, detail: &str| Err(format!("invalid_display_settings:{} {}", field, detail));
+
+        check_color("backgroundColor", &self.background_color)?;
+        check_font("textFont", &self.text_font)?;
+        check_font("referenceFont", &self.reference_font)?;
+        check_font_style("textStyle", &self.text_style)?;
+        check_font_style("referenceStyle", &self.reference_style)?;
+        for (field, size) in [("textFontSize", self.text_font_size), ("referenceFontSize", self.reference_font_size)] {
+            if size.is_some_and(|s| !(1.0..=1000.0).contains(&s)) {
+                return invalid(field, "must be between 1 and 1000");
+            }
+        }
+        if !(8.0..=1000.0).contains(&self.timer_font_size) {
+            return invalid("timerFontSize", "must be between 8 and 1000");
+        }
+        if !self.background_image_data_url.is_empty() && !self.background_image_data_url.starts_with("data:image/") {
+            return invalid("backgroundImageDataUrl", "must be a data:image/ URL");
+        }
+
+        check_rect("layout.text", &self.layout.text)?;
+        check_rect("layout.reference", &self.layout.reference)?;
+        if self.slides_layout.len() > MAX_SLIDE_LINES {
+            return invalid("slidesLayout", "has more than 6 lines");
+        }
+        for (i, rect) in self.slides_layout.iter().enumerate() {
+            check_rect(&format!("slidesLayout[{}]", i), rect)?;
+        }
+        if self.slides_line_styles.len() > MAX_SLIDE_LINES {
+            return invalid("slidesLineStyles", "has more than 6 lines");
+        }
+        for (i, style) in self.slides_line_styles.iter().enumerate() {
+            let field = format!("slidesLineStyles[{}]", i);
+            if let Some(color) = &style.color {
+                check_color(&field, color)?;
+            }
+            if let Some(font) = &style.font_family {
+                check_font(&field, font)?;
+            }
+            check_effects(&field, style.stroke.as_ref(), style.shadow.as_ref())?;
+        }
+
+        let m = &self.margins;
+        if [m.top, m.right, m.bottom, m.left].iter().any(|v| !(0.0..=0.45).contains(v)) {
+            return invalid("margins", "must be between 0 and 0.45");
+        }
+        if self.transition.duration_ms > 10_000 {
+            return invalid("transition.durationMs", "must be at most 10000");
+        }
+        Ok(())
+    }
+}
+
+fn check_rect(field: &str, rect: &LayoutRect) -> Result<(), String> {
+    const EPSILON: f64 = 1e-6;
+    let values = [rect.x, rect.y, rect.width, rect.height];
+    if values.iter().any(|v| !v.is_finite() || *v < 0.0)
+        || rect.width <= 0.0
+        || rect.height <= 0.0
+        || rect.x + rect.width > 1.0 + EPSILON
+        || rect.y + rect.height > 1.0 + EPSILON
+    {
+        return Err(format!("invalid_display_settings:{} must lie within the output", field));
+    }
+    Ok(())
+}
+
+// Colors end up in inline CSS on /display, so only plain color syntax is allowed.
+fn check_color(field: &str, color: &str) -> Result<(), String> {
+    let color = color.trim();
+    let valid = if let Some(hex) = color.strip_prefix('#') {
+        matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some(args) = ["rgb(", "rgba(", "hsl(", "hsla("]
+        .iter()
+        .find_map(|prefix| color.strip_prefix(prefix))
+    {
+        args.strip_suffix(')').is_some_and(|inner| {
+            inner.chars().all(|c| c.is_ascii_alphanumeric() || " .,%/+-".contains(c))
+        })
+    } else {
+        !color.is_empty() && color.chars().all(|c| c.is_ascii_alphabetic())
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("invalid_display_settings:{} is not a color: {}", field, color))
+    }
+}
+
+fn check_font(field: &str, font: &str) -> Result<(), String> {
+    if font.trim().is_empty() || font.len() > 300 || font.contains([';', '{', '}', '<', '>', '\\']) {
+        return Err(format!("invalid_display_settings:{} is not a font family", field));
+    }
+    Ok(())
+}
+
+fn check_font_style(field: &str, style: &FontStyle) -> Result<(), String> {
+    check_color(field, &style.color)?;
+    check_effects(field, style.stroke.as_ref(), style.shadow.as_ref())
+}
+
+fn check_effects(field: &str, stroke: Option<&TextStroke>, shadow: Option<&TextShadow>) -> Result<(), String> {
+    if let Some(stroke) = stroke {
+        check_color(field, &stroke.color)?;
+        if !(0.0..=50.0).contains(&stroke.width) {
+            return Err(format!("invalid_display_settings:{} stroke width must be between 0 and 50", field));
+        }
+    }
+    if let Some(shadow) = shadow {
+        check_color(field, &shadow.color)?;
+        if [shadow.offset_x, shadow.offset_y, shadow.blur].iter().any(|v| !v.is_finite() || v.abs() > 200.0) {
+            return Err(format!("invalid_display_settings:{} shadow is out of range", field));
+        }
+    }
+    Ok(())
+}
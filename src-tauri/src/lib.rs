@@ -18,7 +18,40 @@ use std::sync::Mutex;
 use base64::Engine;
 use tauri::{Emitter, Manager};
 
+mod audio_capture;
+mod audio_meter;
+mod audio_resample;
+mod audio_transport;
+mod ccli_report;
+mod csv_util;
+mod display_history;
+mod display_render;
+mod display_settings;
+mod live_slides_export;
+mod live_slides_history;
+mod live_slides_import;
+mod monitor_watcher;
+mod monitors;
+mod schedule_engine;
+mod schedule_io;
+mod timer_alerts;
+mod timer_engine;
 mod window_commands;
+use audio_capture::{InputConfigRequest, NativeInputConfig};
+use audio_meter::{AudioLevel, LevelMeter};
+use audio_resample::{select_channel, ChannelSelection, SincResampler};
+use audio_transport::AudioStreamFormat;
+use ccli_report::{CcliReport, CcliSong};
+use display_history::{DisplayHistoryEntry, DisplayHistoryKind, DisplayHistoryLog};
+use display_settings::DisplaySettings;
+use live_slides_export::ExportFormat;
+use live_slides_history::{LiveSlideRevision, LiveSlideRevisionDiff, SessionHistory};
+use live_slides_import::{ImportFormat, ImportWarning};
+use monitors::{DisplayLayoutProfile, LayoutProfileOutput, SafeMonitorInfo};
+use schedule_engine::{ScheduleAutoAdvance, ScheduleReport, ScheduleRunLog, ScheduleValidation};
+use schedule_io::{ScheduleExportFormat, ScheduleImportFormat, ScheduleImportOptions, ScheduleImportResult};
+use timer_alerts::{TimerAlert, TimerAlertRule, TimerAlertTracker};
+use timer_engine::{TimerEngine, TimerMode, TIMER_TICK_INTERVAL};
 use window_commands::{open_dialog, close_dialog};
 
 // ============================================================================
@@ -52,6 +85,9 @@ pub struct LiveSlideSession {
     pub slides: Vec<LiveSlide>,
     pub raw_text: String,
     pub created_at: u64,
+    // CCLI song number carried over from an import, for usage reporting.
+    #[serde(default)]
+    pub ccli_number: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +98,23 @@ pub struct LiveSlidesState {
     pub local_ip: String,
 }
 
+// The slide currently on screen for a session; `index` is None when cleared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveSlideCursor {
+    pub session_id: String,
+    pub index: Option<usize>,
+    pub total: usize,
+    pub slide: Option<LiveSlide>,
+    pub updated_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveSlideImportResult {
+    pub session: LiveSlideSession,
+    pub ccli_number: Option<String>,
+    pub warnings: Vec<ImportWarning>,
+}
+
 // ============================================================================
 // Types for Schedule
 // ============================================================================
@@ -82,31 +135,83 @@ pub struct ScheduleState {
     pub current_session_index: Option<usize>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StageMessagePriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Urgent,
+}
+
+// Private message for the stage (timer and schedule views), never the audience.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageMessage {
+    pub id: String,
+    pub text: String,
+    pub flash: bool,
+    pub priority: StageMessagePriority,
+    pub created_at: u64,
+    pub expires_at: Option<u64>, // None = shown until cleared
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TimerState {
     pub is_running: bool,
     pub time_left: i32, // seconds
     pub session_name: Option<String>,
     pub end_time: Option<String>,
     pub is_overrun: bool,
+    #[serde(default)]
+    pub mode: TimerMode,
+    // Server Unix time (ms) at which a running countdown reaches zero, or at
+    // which a running count-up read zero. Clients that synced their clock via
+    // `time_sync` render from this instead of `time_left` so every screen
+    // flips at the same moment.
+    #[serde(default)]
+    pub deadline: Option<u64>,
+    #[serde(default)]
+    pub server_time: Option<u64>, // when this snapshot was taken
 }
 
 // ============================================================================
 // Types for Display (Audience Display)
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DisplayScripture {
     pub verse_text: String,
     pub reference: String,
     pub translation_short_name: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DisplayState {
     pub scripture: DisplayScripture,
     pub slides: Vec<String>,
-    pub settings: serde_json::Value, // DisplaySettings as JSON
+    pub settings: DisplaySettings,
+}
+
+// Each display output (main projector, stage monitor, lower-third, ...) has its
+// own state. "main" always exists and is what clients get without an output id.
+pub const MAIN_DISPLAY_OUTPUT: &str = "main";
+
+// Output ids end up in window labels and URLs, so keep them simple.
+fn resolve_display_output(output: Option<&str>) -> Result<String, String> {
+    let id = output.map(str::trim).filter(|id| !id.is_empty()).unwrap_or(MAIN_DISPLAY_OUTPUT);
+    if id.len() > 32 || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("invalid_display_output:{}", id));
+    }
+    Ok(id.to_ascii_lowercase())
+}
+
+fn display_output_window_label(output: &str) -> String {
+    if output == MAIN_DISPLAY_OUTPUT {
+        "audience-display".to_string()
+    } else {
+        format!("audience-display-{}", output)
+    }
 }
 
 // ============================================================================
@@ -123,6 +228,73 @@ pub struct ApiScriptureGoLiveRequest {
 pub struct ApiTimerStartRequest {
     pub seconds: Option<f64>,
     pub minutes: Option<f64>,
+    pub mode: Option<String>, // "countdown" (default) | "count_up"
+    #[serde(rename = "endTime")]
+    pub end_time: Option<String>, // local time of day to count down to, e.g. "11:45 AM"
+    #[serde(rename = "sessionName")]
+    pub session_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiTimerAdjustRequest {
+    pub seconds: Option<f64>,
+    pub minutes: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiScheduleRequest {
+    pub schedule: Vec<ScheduleItem>,
+    #[serde(rename = "currentSessionIndex", alias = "current_session_index")]
+    pub current_session_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiScheduleItemPatch {
+    pub session: Option<String>,
+    #[serde(rename = "startTime", alias = "start_time")]
+    pub start_time: Option<String>,
+    #[serde(rename = "endTime", alias = "end_time")]
+    pub end_time: Option<String>,
+    pub duration: Option<String>,
+    pub minister: Option<String>, // empty string clears it
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiScheduleAutoAdvanceRequest {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiScheduleImportRequest {
+    pub format: String,
+    pub content: String,
+    #[serde(flatten)]
+    pub options: ScheduleImportOptions,
+    pub apply: Option<bool>, // replace the current schedule (default true)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiTimerAlertRulesRequest {
+    pub rules: Vec<TimerAlertRule>,
+}
+
+// Fields left out keep the output's current value; a new output starts from
+// the main output's settings with nothing shown.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiDisplayStateRequest {
+    pub scripture: Option<DisplayScripture>,
+    pub slides: Option<Vec<String>>,
+    pub settings: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiStageMessageRequest {
+    pub text: String,
+    pub duration: Option<u64>, // seconds; omitted or 0 keeps it until cleared
+    #[serde(default)]
+    pub flash: bool,
+    #[serde(default)]
+    pub priority: StageMessagePriority,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,6 +304,40 @@ pub struct ApiTranscriptionPinRequest {
     pub label: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiLiveSlideSessionRequest {
+    pub name: Option<String>,
+    #[serde(rename = "rawText", alias = "raw_text")]
+    pub raw_text: Option<String>,
+    #[serde(rename = "clientId")]
+    pub client_id: Option<String>,
+    // Absent keeps the session's number, null clears it.
+    #[serde(
+        rename = "ccliNumber",
+        alias = "ccli_number",
+        default,
+        deserialize_with = "deserialize_present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ccli_number: Option<Option<String>>,
+}
+
+// Wraps any present value (null included) in Some, so an absent field (None
+// through `default`) can be told apart from an explicit null.
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiLiveSlideRestoreRequest {
+    #[serde(rename = "clientId")]
+    pub client_id: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PinnedTranscriptionClient {
     #[serde(rename = "clientId")]
@@ -221,11 +427,26 @@ pub enum SyncMessage {
 pub enum WsMessage {
     // From notepad to server
     #[serde(rename = "text_update")]
-    TextUpdate { session_id: String, text: String },
+    TextUpdate {
+        session_id: String,
+        text: String,
+        #[serde(default)]
+        client_id: Option<String>,
+    },
     #[serde(rename = "join_session")]
     JoinSession { session_id: String, client_type: String },
     #[serde(rename = "join_schedule")]
     JoinSchedule,
+    #[serde(rename = "time_sync")]
+    TimeSync { client_time: f64 },
+    #[serde(rename = "live_slide_next")]
+    LiveSlideNext { session_id: String },
+    #[serde(rename = "live_slide_prev")]
+    LiveSlidePrev { session_id: String },
+    #[serde(rename = "live_slide_goto")]
+    LiveSlideGoto { session_id: String, index: usize },
+    #[serde(rename = "live_slide_clear")]
+    LiveSlideClear { session_id: String },
     
     // From external clients (e.g., browser transcription) to server
     // We keep this flexible because the payload can evolve without requiring
@@ -248,6 +469,14 @@ pub enum WsMessage {
     SessionCreated { session: LiveSlideSession },
     #[serde(rename = "session_deleted")]
     SessionDeleted { session_id: String },
+    #[serde(rename = "live_slide_changed")]
+    LiveSlideChanged {
+        session_id: String,
+        index: Option<usize>,
+        total: usize,
+        slide: Option<LiveSlide>,
+        updated_at: u64,
+    },
     #[serde(rename = "schedule_update")]
     ScheduleUpdate { 
         schedule: Vec<ScheduleItem>, 
@@ -256,16 +485,36 @@ pub enum WsMessage {
     },
     #[serde(rename = "timer_update")]
     TimerUpdate { timer_state: TimerState },
+    #[serde(rename = "timer_alert")]
+    TimerAlert { alert: TimerAlert },
+    #[serde(rename = "stage_message")]
+    StageMessage { message: Option<StageMessage> }, // None clears the message
+    #[serde(rename = "time_sync_reply")]
+    TimeSyncReply {
+        client_time: f64,
+        server_receive_time: f64,
+        server_send_time: f64,
+    },
     #[serde(rename = "join_timer")]
     JoinTimer,
     #[serde(rename = "join_display")]
-    JoinDisplay,
+    JoinDisplay {
+        #[serde(default)]
+        output: Option<String>, // defaults to "main"
+    },
     #[serde(rename = "display_update")]
     DisplayUpdate { 
+        output: String,
         scripture: DisplayScripture,
         slides: Vec<String>,
-        settings: serde_json::Value,
+        settings: Box<DisplaySettings>,
     },
+    // Asks for `native_audio_level` messages, which are only sent on request
+    #[serde(rename = "join_audio_levels")]
+    JoinAudioLevels,
+    // Input level of the native capture stream, for VU meters
+    #[serde(rename = "native_audio_level")]
+    NativeAudioLevel { level: AudioLevel },
     #[serde(rename = "error")]
     Error { message: String },
 }
@@ -273,12 +522,23 @@ pub enum WsMessage {
 // Global state for the WebSocket server
 struct ServerState {
     sessions: RwLock<HashMap<String, LiveSlideSession>>,
+    session_history: RwLock<HashMap<String, SessionHistory>>,
+    live_slide_cursors: RwLock<HashMap<String, usize>>,
     schedule: RwLock<ScheduleState>,
+    schedule_auto_advance: RwLock<ScheduleAutoAdvance>,
+    schedule_runs: RwLock<ScheduleRunLog>,
     timer_state: RwLock<TimerState>,
-    display_state: RwLock<DisplayState>,
+    timer_engine: RwLock<TimerEngine>,
+    timer_alert_rules: RwLock<Vec<TimerAlertRule>>,
+    timer_alert_tracker: RwLock<TimerAlertTracker>,
+    stage_message: RwLock<Option<StageMessage>>,
+    display_outputs: RwLock<HashMap<String, DisplayState>>,
+    display_history: DisplayHistoryLog,
     pinned_transcription_clients: RwLock<HashMap<String, PinnedTranscriptionClient>>,
     api_playlists: RwLock<Option<serde_json::Value>>,
     broadcast_tx: broadcast::Sender<String>,
+    // Native input levels (10-20 a second), for /ws clients that sent join_audio_levels
+    audio_level_tx: broadcast::Sender<String>,
     running: RwLock<bool>,
     port: RwLock<u16>,
     shutdown_tx: RwLock<Option<tokio::sync::oneshot::Sender<()>>>,
@@ -303,29 +563,34 @@ struct SyncServerState {
 lazy_static::lazy_static! {
     static ref SERVER_STATE: Arc<ServerState> = Arc::new(ServerState {
         sessions: RwLock::new(HashMap::new()),
+        session_history: RwLock::new(HashMap::new()),
+        live_slide_cursors: RwLock::new(HashMap::new()),
         schedule: RwLock::new(ScheduleState {
             schedule: Vec::new(),
             current_session_index: None,
         }),
+        schedule_auto_advance: RwLock::new(ScheduleAutoAdvance::default()),
+        schedule_runs: RwLock::new(ScheduleRunLog::default()),
         timer_state: RwLock::new(TimerState {
             is_running: false,
             time_left: 0,
             session_name: None,
             end_time: None,
             is_overrun: false,
+            mode: TimerMode::Countdown,
+            deadline: None,
+            server_time: None,
         }),
-        display_state: RwLock::new(DisplayState {
-            scripture: DisplayScripture {
-                verse_text: String::new(),
-                reference: String::new(),
-                translation_short_name: None,
-            },
-            slides: Vec::new(),
-            settings: serde_json::json!({}),
-        }),
+        timer_engine: RwLock::new(TimerEngine::default()),
+        timer_alert_rules: RwLock::new(timer_alerts::default_alert_rules()),
+        timer_alert_tracker: RwLock::new(TimerAlertTracker::default()),
+        stage_message: RwLock::new(None),
+        display_outputs: RwLock::new(HashMap::from([(MAIN_DISPLAY_OUTPUT.to_string(), DisplayState::default())])),
+        display_history: DisplayHistoryLog::default(),
         pinned_transcription_clients: RwLock::new(HashMap::new()),
         api_playlists: RwLock::new(None),
         broadcast_tx: broadcast::channel(100).0,
+        audio_level_tx: broadcast::channel(16).0,
         running: RwLock::new(false),
         port: RwLock::new(9876),
         shutdown_tx: RwLock::new(None),
@@ -507,211 +772,2308 @@ fn parse_notepad_text(text: &str) -> Vec<LiveSlide> {
     slides
 }
 
-// ============================================================================
-// WebSocket Handler (using warp)
-// ============================================================================
+fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
 
-async fn handle_ws_connection(ws: WebSocket, state: Arc<ServerState>) {
-    let (mut ws_sender, mut ws_receiver) = ws.split();
-    let mut broadcast_rx = state.broadcast_tx.subscribe();
-    
-    // Spawn task to forward broadcasts to this client
-    let forward_task = tokio::spawn(async move {
-        while let Ok(msg) = broadcast_rx.recv().await {
-            if ws_sender.send(WarpWsMessage::text(msg)).await.is_err() {
-                break;
-            }
-        }
-    });
+// Paths typed or pasted by users: trim whitespace and expand "~" / "~/" to the
+// home directory (a common expectation on macOS/Linux).
+fn normalize_user_path(path: &str) -> Result<String, String> {
+    let trimmed = path.trim();
+    if trimmed != "~" && !trimmed.starts_with("~/") {
+        return Ok(trimmed.to_string());
+    }
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .map_err(|_| "home_dir_unavailable".to_string())?;
+    if trimmed == "~" {
+        Ok(home)
+    } else {
+        Ok(format!("{}/{}", home.trim_end_matches('/'), &trimmed[2..]))
+    }
+}
 
-    // Handle incoming messages
-    while let Some(result) = ws_receiver.next().await {
-        if let Ok(msg) = result {
-            if let Ok(text) = msg.to_str() {
-                if let Ok(ws_msg) = serde_json::from_str::<WsMessage>(text) {
-                    match ws_msg {
-                        WsMessage::TextUpdate { session_id, text } => {
-                            let slides = parse_notepad_text(&text);
-                            
-                            // Update session
-                            {
-                                let mut sessions = state.sessions.write().await;
-                                if let Some(session) = sessions.get_mut(&session_id) {
-                                    session.slides = slides.clone();
-                                    session.raw_text = text.clone();
-                                }
-                            }
-                            
-                            // Broadcast update to all clients
-                            let update = WsMessage::SlidesUpdate {
-                                session_id,
-                                slides,
-                                raw_text: text,
-                            };
-                            if let Ok(json) = serde_json::to_string(&update) {
-                                let _ = state.broadcast_tx.send(json);
-                            }
-                        }
-                        WsMessage::JoinSession { session_id, client_type: _ } => {
-                            // Send current session state to the joining client
-                            let sessions = state.sessions.read().await;
-                            if let Some(session) = sessions.get(&session_id) {
-                                let update = WsMessage::SlidesUpdate {
-                                    session_id: session_id.clone(),
-                                    slides: session.slides.clone(),
-                                    raw_text: session.raw_text.clone(),
-                                };
-                                if let Ok(json) = serde_json::to_string(&update) {
-                                    let _ = state.broadcast_tx.send(json);
-                                }
-                            }
-                        }
-                        WsMessage::JoinSchedule => {
-                            // Send current schedule state to the joining client
-                            let schedule_state = state.schedule.read().await;
-                            let update = WsMessage::ScheduleUpdate {
-                                schedule: schedule_state.schedule.clone(),
-                                current_session_index: schedule_state.current_session_index,
-                            };
-                            if let Ok(json) = serde_json::to_string(&update) {
-                                let _ = state.broadcast_tx.send(json);
-                            }
-                        }
-                        WsMessage::JoinTimer => {
-                            // Send current timer state to the joining client
-                            let timer_state = state.timer_state.read().await;
-                            let update = WsMessage::TimerUpdate {
-                                timer_state: timer_state.clone(),
-                            };
-                            if let Ok(json) = serde_json::to_string(&update) {
-                                let _ = state.broadcast_tx.send(json);
-                            }
-                        }
-                        WsMessage::JoinDisplay => {
-                            // Send current display state to the joining client
-                            let display_state = state.display_state.read().await;
-                            let update = WsMessage::DisplayUpdate {
-                                scripture: display_state.scripture.clone(),
-                                slides: display_state.slides.clone(),
-                                settings: display_state.settings.clone(),
-                            };
-                            if let Ok(json) = serde_json::to_string(&update) {
-                                let _ = state.broadcast_tx.send(json);
-                            }
-                        }
-                        WsMessage::TranscriptionStream { .. } => {
-                            // Re-broadcast browser transcription stream messages to all clients.
-                            // We forward the original JSON string so fields remain intact.
-                            let _ = state.broadcast_tx.send(text.to_string());
-                        }
-                        _ => {}
-                    }
-                }
+// Writes an exported file off the async runtime, creating its directory, and
+// returns the path written.
+async fn write_user_file(path: std::path::PathBuf, data: Vec<u8>) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("create_dir_failed:{}", e))?;
             }
         }
+        std::fs::write(&path, data).map_err(|e| format!("write_failed:{}:{}", path.display(), e))?;
+        Ok(path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|e| format!("write_failed:{}", e))?
+}
+
+// Unix time in fractional milliseconds, for clock synchronization.
+fn precise_now_millis() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64()
+        * 1000.0
+}
+
+fn broadcast_ws_message(state: &ServerState, msg: &WsMessage) {
+    if let Ok(json) = serde_json::to_string(msg) {
+        let _ = state.broadcast_tx.send(json);
     }
-    
-    forward_task.abort();
 }
 
 // ============================================================================
-// Static File Server (using warp + rust-embed)
+// Live Slides Session Updates
 // ============================================================================
 
-fn serve_embedded_file(path: &str) -> Option<(Vec<u8>, String)> {
-    // Try exact path first
-    if let Some(content) = FrontendAssets::get(path) {
-        let mime = mime_guess::from_path(path).first_or_octet_stream();
-        return Some((content.data.to_vec(), mime.to_string()));
-    }
+async fn create_live_slide_session_impl(state: &ServerState, name: String) -> LiveSlideSession {
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let session = LiveSlideSession {
+        id: session_id.clone(),
+        name,
+        slides: Vec::new(),
+        raw_text: String::new(),
+        created_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        ccli_number: None,
+    };
 
-    // In dev, fall back to serving from /public when dist isn't built yet.
-    if cfg!(debug_assertions) && !path.contains("..") {
-        let public_path = std::path::Path::new("../public").join(path);
-        if public_path.exists() {
-            if let Ok(data) = std::fs::read(&public_path) {
-                let mime = mime_guess::from_path(&public_path).first_or_octet_stream();
-                return Some((data, mime.to_string()));
-            }
-        }
+    state.sessions.write().await.insert(session_id, session.clone());
+    record_live_slide_revision(state, &session, None, "create").await;
+
+    broadcast_ws_message(state, &WsMessage::SessionCreated { session: session.clone() });
+
+    session
+}
+
+// Returns false when the session did not exist; `session_deleted` is broadcast
+// either way so stale clients drop it too.
+async fn delete_live_slide_session_impl(state: &ServerState, session_id: String) -> bool {
+    let existed = state.sessions.write().await.remove(&session_id).is_some();
+    state.session_history.write().await.remove(&session_id);
+    state.live_slide_cursors.write().await.remove(&session_id);
+
+    broadcast_ws_message(state, &WsMessage::SessionDeleted { session_id });
+
+    existed
+}
+
+// Shared by the `upsert_live_slide_session` command, the REST API and
+// importers: replaces a session's text (creating the session if needed) and
+// broadcasts the result. A `ccli_number` of None keeps the session's current
+// one, Some(None) clears it.
+async fn upsert_live_slide_session_impl(
+    state: &ServerState,
+    session_id: String,
+    name: String,
+    raw_text: String,
+    ccli_number: Option<Option<String>>,
+    client_id: Option<String>,
+    source: &str,
+) -> LiveSlideSession {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut sessions = state.sessions.write().await;
+    let existing = sessions.get(&session_id);
+    let is_new = existing.is_none();
+    let session = LiveSlideSession {
+        id: session_id.clone(),
+        name,
+        slides: parse_notepad_text(&raw_text),
+        raw_text,
+        created_at: existing.map(|s| s.created_at).unwrap_or(now),
+        ccli_number: ccli_number.unwrap_or_else(|| existing.and_then(|s| s.ccli_number.clone())),
+    };
+    sessions.insert(session_id, session.clone());
+    drop(sessions);
+
+    publish_live_slide_session(state, &session, is_new, client_id, source).await;
+    session
+}
+
+// Merges the given fields into an existing session under one write guard, so
+// concurrent patches can't drop each other's changes. None when the session
+// doesn't exist.
+async fn patch_live_slide_session_impl(
+    state: &ServerState,
+    session_id: &str,
+    name: Option<String>,
+    raw_text: Option<String>,
+    ccli_number: Option<Option<String>>,
+    client_id: Option<String>,
+) -> Option<LiveSlideSession> {
+    let mut sessions = state.sessions.write().await;
+    let session = sessions.get_mut(session_id)?;
+    if let Some(name) = name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()) {
+        session.name = name;
     }
-    
-    // For SPA routing, return index.html for non-file paths
-    if !path.contains('.') || path.ends_with('/') {
-        if let Some(content) = FrontendAssets::get("index.html") {
-            return Some((content.data.to_vec(), "text/html".to_string()));
-        }
+    if let Some(raw_text) = raw_text {
+        session.slides = parse_notepad_text(&raw_text);
+        session.raw_text = raw_text;
     }
-    
-    None
+    if let Some(ccli_number) = ccli_number {
+        session.ccli_number = ccli_number;
+    }
+    let session = session.clone();
+    drop(sessions);
+
+    publish_live_slide_session(state, &session, false, client_id, "upsert").await;
+    Some(session)
 }
 
-fn json_response(value: serde_json::Value, status: StatusCode) -> warp::reply::Response {
-    warp::reply::with_status(warp::reply::json(&value), status).into_response()
+// Records a revision of an updated session and broadcasts it to clients.
+async fn publish_live_slide_session(
+    state: &ServerState,
+    session: &LiveSlideSession,
+    is_new: bool,
+    client_id: Option<String>,
+    source: &str,
+) {
+    record_live_slide_revision(state, session, client_id, source).await;
+
+    if is_new {
+        broadcast_ws_message(
+            state,
+            &WsMessage::SessionCreated {
+                session: session.clone(),
+            },
+        );
+    }
+
+    broadcast_ws_message(
+        state,
+        &WsMessage::SlidesUpdate {
+            session_id: session.id.clone(),
+            slides: session.slides.clone(),
+            raw_text: session.raw_text.clone(),
+        },
+    );
 }
 
 // ============================================================================
-// Combined HTTP + WebSocket Server
+// Live Slides Revision History
 // ============================================================================
 
-async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), String> {
-    let state = SERVER_STATE.clone();
-    
-    // Create shutdown channel
-    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
-    *state.shutdown_tx.write().await = Some(shutdown_tx);
-    
-    // WebSocket route at /ws
-    let ws_state = state.clone();
+async fn record_live_slide_revision(
+    state: &ServerState,
+    session: &LiveSlideSession,
+    author: Option<String>,
+    source: &str,
+) {
+    let mut history = state.session_history.write().await;
+    history.entry(session.id.clone()).or_default().record(
+        &session.id,
+        &session.name,
+        &session.raw_text,
+        author,
+        source,
+        now_millis(),
+    );
+}
+
+async fn list_live_slide_revisions_impl(
+    state: &ServerState,
+    session_id: &str,
+) -> Result<Vec<LiveSlideRevision>, String> {
+    if !state.sessions.read().await.contains_key(session_id) {
+        return Err("session_not_found".to_string());
+    }
+    let history = state.session_history.read().await;
+    Ok(history.get(session_id).map(|h| h.list()).unwrap_or_default())
+}
+
+async fn diff_live_slide_revisions_impl(
+    state: &ServerState,
+    session_id: &str,
+    from_revision: u64,
+    to_revision: u64,
+) -> Result<LiveSlideRevisionDiff, String> {
+    let history = state.session_history.read().await;
+    history
+        .get(session_id)
+        .ok_or_else(|| "session_not_found".to_string())?
+        .diff(from_revision, to_revision)
+}
+
+// Restoring writes the old text back as a new revision (so the restore itself
+// can be undone) and broadcasts a regular `slides_update`.
+async fn restore_live_slide_revision_impl(
+    state: &ServerState,
+    session_id: &str,
+    revision: u64,
+    author: Option<String>,
+) -> Result<LiveSlideSession, String> {
+    let raw_text = {
+        let history = state.session_history.read().await;
+        history
+            .get(session_id)
+            .and_then(|h| h.get(revision))
+            .map(|r| r.raw_text.clone())
+            .ok_or_else(|| format!("revision_not_found:{}", revision))?
+    };
+
+    let session = {
+        let mut sessions = state.sessions.write().await;
+        let session = sessions
+            .get_mut(session_id)
+            .ok_or_else(|| "session_not_found".to_string())?;
+        session.slides = parse_notepad_text(&raw_text);
+        session.raw_text = raw_text;
+        session.clone()
+    };
+
+    record_live_slide_revision(state, &session, author, "restore").await;
+
+    broadcast_ws_message(
+        state,
+        &WsMessage::SlidesUpdate {
+            session_id: session.id.clone(),
+            slides: session.slides.clone(),
+            raw_text: session.raw_text.clone(),
+        },
+    );
+
+    Ok(session)
+}
+
+// ============================================================================
+// Live Slides Cursor (slide currently on screen)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy)]
+enum LiveSlideAction {
+    Next,
+    Prev,
+    Goto(usize),
+    Clear,
+}
+
+impl LiveSlideAction {
+    fn parse(action: &str, index: Option<usize>) -> Result<Self, String> {
+        match action {
+            "next" => Ok(LiveSlideAction::Next),
+            "prev" | "previous" => Ok(LiveSlideAction::Prev),
+            "goto" => index
+                .map(LiveSlideAction::Goto)
+                .ok_or_else(|| "index_required".to_string()),
+            "clear" => Ok(LiveSlideAction::Clear),
+            other => Err(format!("unknown_action:{}", other)),
+        }
+    }
+}
+
+async fn get_live_slide_cursor_impl(state: &ServerState, session_id: &str) -> Result<LiveSlideCursor, String> {
+    let sessions = state.sessions.read().await;
+    let session = sessions
+        .get(session_id)
+        .ok_or_else(|| "session_not_found".to_string())?;
+    let index = state
+        .live_slide_cursors
+        .read()
+        .await
+        .get(session_id)
+        .copied()
+        .filter(|i| *i < session.slides.len());
+
+    Ok(LiveSlideCursor {
+        session_id: session_id.to_string(),
+        index,
+        total: session.slides.len(),
+        slide: index.and_then(|i| session.slides.get(i).cloned()),
+        updated_at: now_millis(),
+    })
+}
+
+// Moves the cursor and broadcasts `live_slide_changed`. Next/prev stop at the
+// first and last slide; from a cleared cursor both go to the first slide.
+async fn move_live_slide_cursor(
+    state: &ServerState,
+    session_id: &str,
+    action: LiveSlideAction,
+) -> Result<LiveSlideCursor, String> {
+    let cursor = {
+        let sessions = state.sessions.read().await;
+        let session = sessions
+            .get(session_id)
+            .ok_or_else(|| "session_not_found".to_string())?;
+        let total = session.slides.len();
+
+        let mut cursors = state.live_slide_cursors.write().await;
+        let current = cursors.get(session_id).copied().filter(|i| *i < total);
+        let index = match action {
+            LiveSlideAction::Clear => None,
+            _ if total == 0 => return Err("no_slides".to_string()),
+            LiveSlideAction::Next => Some(current.map(|i| (i + 1).min(total - 1)).unwrap_or(0)),
+            LiveSlideAction::Prev => Some(current.map(|i| i.saturating_sub(1)).unwrap_or(0)),
+            LiveSlideAction::Goto(n) if n < total => Some(n),
+            LiveSlideAction::Goto(n) => return Err(format!("slide_not_found:{}", n)),
+        };
+
+        match index {
+            Some(i) => cursors.insert(session_id.to_string(), i),
+            None => cursors.remove(session_id),
+        };
+
+        (
+            LiveSlideCursor {
+                session_id: session_id.to_string(),
+                index,
+                total,
+                slide: index.and_then(|i| session.slides.get(i).cloned()),
+                updated_at: now_millis(),
+            },
+            session.name.clone(),
+            ccli_report::detect_song(&session.name, &session.raw_text, session.ccli_number.as_deref()),
+        )
+    };
+    let (cursor, session_name, song) = cursor;
+
+    broadcast_live_slide_cursor(state, &cursor);
+    if let (Some(index), Some(slide)) = (cursor.index, cursor.slide.as_ref()) {
+        let mut entry = DisplayHistoryEntry::new(DisplayHistoryKind::SlideGoLive, cursor.updated_at);
+        entry.text = slide.items.iter().map(|item| item.text.as_str()).collect::<Vec<_>>().join("\n");
+        entry.live_slide_session_id = Some(cursor.session_id.clone());
+        entry.live_slide_session_name = Some(session_name);
+        entry.slide_index = Some(index);
+        if let Some(song) = song {
+            entry.ccli_number = Some(song.ccli_number);
+            entry.song_title = Some(song.title).filter(|t| !t.is_empty());
+        }
+        record_display_history(state, entry).await;
+    } else {
+        let mut cleared = DisplayHistoryEntry::new(DisplayHistoryKind::SlideGoLive, cursor.updated_at);
+        cleared.live_slide_session_id = Some(cursor.session_id.clone());
+        if let Err(e) = state.display_history.screen_cleared(&cleared) {
+            eprintln!("[Display] Failed to record display history: {}", e);
+        }
+    }
+    Ok(cursor)
+}
+
+fn broadcast_live_slide_cursor(state: &ServerState, cursor: &LiveSlideCursor) {
+    broadcast_ws_message(
+        state,
+        &WsMessage::LiveSlideChanged {
+            session_id: cursor.session_id.clone(),
+            index: cursor.index,
+            total: cursor.total,
+            slide: cursor.slide.clone(),
+            updated_at: cursor.updated_at,
+        },
+    );
+}
+
+// Lets the desktop UI follow cursor changes made by remote controllers.
+fn emit_live_slide_changed(app: &tauri::AppHandle, cursor: &LiveSlideCursor) {
+    if let Err(e) = app.emit("live-slide-changed", cursor) {
+        eprintln!("[LiveSlides] Failed to emit live-slide-changed: {}", e);
+    }
+}
+
+// WebSocket controls have no reply channel, so failures are only logged.
+async fn apply_live_slide_ws_control(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    session_id: &str,
+    action: LiveSlideAction,
+) {
+    match move_live_slide_cursor(state, session_id, action).await {
+        Ok(cursor) => emit_live_slide_changed(app, &cursor),
+        Err(e) => eprintln!("[LiveSlides] Cursor change failed for {}: {}", session_id, e),
+    }
+}
+
+// ============================================================================
+// Server Timer Engine
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum TimerControl {
+    Start {
+        seconds: i64,
+        session_name: Option<String>,
+        end_time: Option<String>,
+    },
+    StartUntil {
+        target: chrono::NaiveTime,
+        session_name: Option<String>,
+    },
+    StartCountUp {
+        session_name: Option<String>,
+    },
+    Pause,
+    Resume,
+    Reset,
+    Stop,
+    AddTime(i64),
+}
+
+// Picks the kind of timer to start: count-up when `mode` asks for it, a plain
+// countdown when a duration is given, otherwise a countdown to `end_time`.
+fn timer_start_control(
+    seconds: Option<f64>,
+    mode: Option<&str>,
+    end_time: Option<String>,
+    session_name: Option<String>,
+) -> Result<TimerControl, String> {
+    let session_name = session_name.filter(|n| !n.trim().is_empty());
+    let end_time = end_time.filter(|t| !t.trim().is_empty());
+    match mode.map(|m| m.trim().to_lowercase()).as_deref() {
+        None | Some("") | Some("countdown") => {}
+        Some("count_up") | Some("countup") | Some("stopwatch") => {
+            return Ok(TimerControl::StartCountUp { session_name });
+        }
+        Some(other) => return Err(format!("invalid_mode:{}", other)),
+    }
+
+    match (seconds, end_time) {
+        (Some(seconds), end_time) => {
+            if !seconds.is_finite() || seconds.floor() < 1.0 {
+                return Err("seconds_required".to_string());
+            }
+            Ok(TimerControl::Start {
+                seconds: seconds.floor() as i64,
+                session_name,
+                end_time,
+            })
+        }
+        (None, Some(end_time)) => Ok(TimerControl::StartUntil {
+            target: timer_engine::parse_time_of_day(&end_time)?,
+            session_name,
+        }),
+        (None, None) => Err("seconds_required".to_string()),
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref TIMER_TICKER_STARTED: AtomicBool = AtomicBool::new(false);
+}
+
+// Stores the state for late joiners, broadcasts `timer_update`, emits
+// `timer-update` so the desktop UI renders the same countdown as the stage,
+// and fires any alert the countdown just crossed.
+async fn publish_timer_state(state: &ServerState, app: &tauri::AppHandle, timer_state: TimerState) {
+    *state.timer_state.write().await = timer_state.clone();
+    let _ = app.emit("timer-update", &timer_state);
+    broadcast_ws_message(state, &WsMessage::TimerUpdate { timer_state: timer_state.clone() });
+    if timer_state.mode == TimerMode::Countdown {
+        fire_timer_alerts(state, app, &timer_state).await;
+    }
+}
+
+// A single ticker task publishes the running timer at a fixed rate; it idles
+// while the timer is paused or reset.
+fn ensure_timer_ticker(app: &tauri::AppHandle) {
+    if TIMER_TICKER_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TIMER_TICK_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let state = SERVER_STATE.clone();
+            let snapshot = {
+                let engine = state.timer_engine.read().await;
+                if !engine.is_running() {
+                    continue;
+                }
+                engine.snapshot(std::time::Instant::now(), now_millis())
+            };
+            publish_timer_state(&state, &app, snapshot).await;
+        }
+    });
+}
+
+// Broadcasts `timer_alert` for each rule the countdown just crossed and runs
+// the rule's MIDI note and webhook in the background.
+async fn fire_timer_alerts(state: &ServerState, app: &tauri::AppHandle, timer_state: &TimerState) {
+    let time_left = timer_state.time_left as i64;
+    let alerts: Vec<(TimerAlert, TimerAlertRule)> = {
+        let rules = state.timer_alert_rules.read().await;
+        let mut tracker = state.timer_alert_tracker.write().await;
+        tracker
+            .evaluate(&rules, time_left)
+            .into_iter()
+            .map(|(rule, message)| {
+                let alert = TimerAlert {
+                    rule_id: rule.id.clone(),
+                    message,
+                    flash: rule.flash,
+                    color: rule.color.clone(),
+                    time_left,
+                    session_name: timer_state.session_name.clone(),
+                    fired_at: now_millis(),
+                };
+                (alert, rule.clone())
+            })
+            .collect()
+    };
+
+    for (alert, rule) in alerts {
+        let _ = app.emit("timer-alert", &alert);
+        broadcast_ws_message(state, &WsMessage::TimerAlert { alert: alert.clone() });
+
+        if let Some(midi) = rule.midi {
+            tokio::task::spawn_blocking(move || {
+                if let Err(e) = send_midi_note_impl(&midi.device_id, midi.channel, midi.note, midi.velocity) {
+                    eprintln!("[Timer] Alert {} MIDI failed: {}", rule.id, e);
+                }
+            });
+        }
+        if let Some(url) = rule.webhook_url {
+            tokio::spawn(async move {
+                let result = reqwest::Client::new()
+                    .post(&url)
+                    .timeout(std::time::Duration::from_secs(5))
+                    .json(&alert)
+                    .send()
+                    .await;
+                if let Err(e) = result.and_then(|r| r.error_for_status()) {
+                    eprintln!("[Timer] Alert {} webhook failed: {}", alert.rule_id, e);
+                }
+            });
+        }
+    }
+}
+
+async fn apply_timer_control(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    control: TimerControl,
+) -> Result<TimerState, String> {
+    // Starting over re-arms every alert; pausing and adjusting keep their place.
+    if !matches!(control, TimerControl::Pause | TimerControl::Resume | TimerControl::AddTime(_)) {
+        state.timer_alert_tracker.write().await.reset();
+    }
+    let snapshot = {
+        let mut engine = state.timer_engine.write().await;
+        let now = std::time::Instant::now();
+        match control {
+            TimerControl::Start {
+                seconds,
+                session_name,
+                end_time,
+            } => {
+                if seconds <= 0 {
+                    return Err("seconds_required".to_string());
+                }
+                engine.start(seconds, session_name, end_time, now);
+            }
+            TimerControl::StartUntil { target, session_name } => {
+                let seconds = timer_engine::seconds_until(target, chrono::Local::now());
+                engine.start_until(target, seconds, session_name, now);
+            }
+            TimerControl::StartCountUp { session_name } => engine.start_count_up(session_name, now),
+            TimerControl::Pause => engine.pause(now)?,
+            TimerControl::Resume => engine.resume(now)?,
+            TimerControl::Reset => match engine.target() {
+                // Time-of-day timers follow the wall clock, so reset re-syncs
+                // them to the target instead of rewinding.
+                Some(target) => {
+                    let seconds = timer_engine::seconds_until(target, chrono::Local::now());
+                    let session_name = engine.session_name();
+                    engine.start_until(target, seconds, session_name, now);
+                }
+                None => engine.reset()?,
+            },
+            TimerControl::Stop => engine.stop(),
+            TimerControl::AddTime(seconds) => engine.add_time(seconds)?,
+        }
+        engine.snapshot(now, now_millis())
+    };
+
+    ensure_timer_ticker(app);
+    publish_timer_state(state, app, snapshot.clone()).await;
+    Ok(snapshot)
+}
+
+fn timer_alerts_path(app_handle: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("app_data_dir_failed:{}", e))?;
+    Ok(dir.join(timer_alerts::TIMER_ALERTS_FILE_NAME))
+}
+
+// Validates, saves to app data (so rules survive a restart) and applies.
+async fn set_timer_alert_rules_impl(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    rules: Vec<TimerAlertRule>,
+) -> Result<(), String> {
+    timer_alerts::validate_rules(&rules)?;
+    let path = timer_alerts_path(app)?;
+    let saved = rules.clone();
+    tokio::task::spawn_blocking(move || timer_alerts::save_rules(&path, &saved))
+        .await
+        .map_err(|e| format!("save_failed:{}", e))??;
+    *state.timer_alert_rules.write().await = rules;
+    Ok(())
+}
+
+// The stored state lags the engine by up to one tick; compute it fresh instead.
+async fn current_timer_state(state: &ServerState) -> TimerState {
+    let engine = state.timer_engine.read().await;
+    if engine.is_active() {
+        engine.snapshot(std::time::Instant::now(), now_millis())
+    } else {
+        state.timer_state.read().await.clone()
+    }
+}
+
+// ============================================================================
+// Schedule Engine
+// ============================================================================
+
+lazy_static::lazy_static! {
+    static ref SCHEDULE_TICKER_STARTED: AtomicBool = AtomicBool::new(false);
+}
+
+fn broadcast_schedule_state(state: &ServerState, schedule_state: &ScheduleState) {
+    broadcast_ws_message(
+        state,
+        &WsMessage::ScheduleUpdate {
+            schedule: schedule_state.schedule.clone(),
+            current_session_index: schedule_state.current_session_index,
+        },
+    );
+}
+
+async fn update_schedule_impl(
+    state: &ServerState,
+    schedule: Vec<ScheduleItem>,
+    current_session_index: Option<usize>,
+) -> ScheduleValidation {
+    let validation = schedule_engine::validate_schedule(&schedule);
+    let schedule_state = ScheduleState {
+        schedule,
+        current_session_index,
+    };
+    *state.schedule.write().await = schedule_state.clone();
+    record_session_run(state, &schedule_state).await;
+    broadcast_schedule_state(state, &schedule_state);
+    validation
+}
+
+async fn record_session_run(state: &ServerState, schedule_state: &ScheduleState) {
+    let current = schedule_state
+        .current_session_index
+        .and_then(|index| schedule_state.schedule.get(index).map(|item| (index, item)));
+    state
+        .schedule_runs
+        .write()
+        .await
+        .transition(current, chrono::Local::now());
+}
+
+// Replaces the schedule from outside the desktop UI (REST, imports) and tells
+// the UI, which owns the schedule, to adopt it.
+async fn replace_schedule_from_api(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    schedule: Vec<ScheduleItem>,
+    current_session_index: Option<usize>,
+) -> ScheduleValidation {
+    let schedule_state = ScheduleState {
+        schedule,
+        current_session_index,
+    };
+    *state.schedule.write().await = schedule_state.clone();
+    publish_schedule_from_api(state, app, &schedule_state).await
+}
+
+// Announces a schedule already stored by an API write to clients and the UI.
+async fn publish_schedule_from_api(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    schedule_state: &ScheduleState,
+) -> ScheduleValidation {
+    let validation = schedule_engine::validate_schedule(&schedule_state.schedule);
+    record_session_run(state, schedule_state).await;
+    broadcast_schedule_state(state, schedule_state);
+    let _ = app.emit(
+        "api-schedule-updated",
+        serde_json::json!({
+            "schedule": schedule_state.schedule,
+            "currentSessionIndex": schedule_state.current_session_index,
+        }),
+    );
+    validation
+}
+
+// Makes `index` the current session, broadcasts `schedule_update`, emits
+// `schedule-session-changed` for the desktop UI and, when `start_timer` is set,
+// starts the session's countdown (to its end time, or for its duration).
+async fn set_current_session(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    index: Option<usize>,
+    start_timer: bool,
+    source: &str,
+) -> Result<ScheduleState, String> {
+    let schedule_state = {
+        let mut schedule_state = state.schedule.write().await;
+        if let Some(index) = index {
+            if index >= schedule_state.schedule.len() {
+                return Err(format!("session_not_found:{}", index));
+            }
+        }
+        schedule_state.current_session_index = index;
+        schedule_state.clone()
+    };
+
+    record_session_run(state, &schedule_state).await;
+    broadcast_schedule_state(state, &schedule_state);
+    let _ = app.emit(
+        "schedule-session-changed",
+        serde_json::json!({ "currentSessionIndex": index, "source": source, "startTimer": start_timer }),
+    );
+
+    if let (true, Some(index)) = (start_timer, index) {
+        let item = &schedule_state.schedule[index];
+        let parsed = match schedule_engine::parse_schedule(std::slice::from_ref(item)) {
+            Ok(mut parsed) => parsed.remove(0),
+            Err(issues) => {
+                // The session still changes; only its countdown can't start.
+                let messages: Vec<String> = issues.into_iter().map(|i| i.message).collect();
+                eprintln!("[Schedule] No countdown for session {}: {}", index, messages.join("; "));
+                return Ok(schedule_state);
+            }
+        };
+        let session_name = Some(item.session.clone()).filter(|n| !n.trim().is_empty());
+        let control = if parsed.has_end_time {
+            TimerControl::StartUntil {
+                target: parsed.end,
+                session_name,
+            }
+        } else {
+            TimerControl::Start {
+                seconds: parsed.duration_secs.max(1),
+                session_name,
+                end_time: None,
+            }
+        };
+        apply_timer_control(state, app, control).await?;
+    }
+
+    Ok(schedule_state)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ScheduleStep {
+    Advance,
+    Back,
+    Goto(usize),
+}
+
+// Next/previous from the current session; advancing with no current session
+// starts the first one.
+async fn step_current_session(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    step: ScheduleStep,
+    source: &str,
+) -> Result<ScheduleState, String> {
+    let (current, len) = {
+        let schedule_state = state.schedule.read().await;
+        (schedule_state.current_session_index, schedule_state.schedule.len())
+    };
+    if len == 0 {
+        return Err("schedule_empty".to_string());
+    }
+    let index = match (step, current) {
+        (ScheduleStep::Advance, None) => 0,
+        (ScheduleStep::Advance, Some(i)) if i + 1 < len => i + 1,
+        (ScheduleStep::Advance, Some(_)) => return Err("at_last_session".to_string()),
+        (ScheduleStep::Back, Some(i)) if i > 0 => i - 1,
+        (ScheduleStep::Back, _) => return Err("at_first_session".to_string()),
+        (ScheduleStep::Goto(i), _) => i,
+    };
+    set_current_session(state, app, Some(index), true, source).await
+}
+
+// Checks once a second whether a start time has been crossed and, if
+// auto-advance is on and the schedule is valid, moves to that session.
+fn ensure_schedule_ticker(app: &tauri::AppHandle) {
+    if SCHEDULE_TICKER_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let state = SERVER_STATE.clone();
+            if !state.schedule_auto_advance.read().await.enabled {
+                continue;
+            }
+
+            let (due, current) = {
+                let schedule_state = state.schedule.read().await;
+                let Ok(parsed) = schedule_engine::parse_schedule(&schedule_state.schedule) else {
+                    continue;
+                };
+                let now = chrono::Local::now().time();
+                (
+                    schedule_engine::due_item(&parsed, now).map(|p| p.index),
+                    schedule_state.current_session_index,
+                )
+            };
+
+            {
+                let mut auto_advance = state.schedule_auto_advance.write().await;
+                if auto_advance.last_index == due {
+                    continue;
+                }
+                auto_advance.last_index = due;
+            }
+
+            if let Some(index) = due {
+                if current != Some(index) {
+                    if let Err(e) = set_current_session(&state, &app, Some(index), true, "auto").await {
+                        eprintln!("[Schedule] Auto-advance to {} failed: {}", index, e);
+                    }
+                }
+            }
+        }
+    });
+}
+
+// Turning auto-advance on requires a schedule whose times all parse. Emits
+// `schedule-auto-advance-changed` so the desktop setting follows API changes.
+async fn set_schedule_auto_advance_impl(state: &ServerState, app: &tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    if enabled {
+        let schedule = state.schedule.read().await.schedule.clone();
+        if let Err(issues) = schedule_engine::parse_schedule(&schedule) {
+            let messages: Vec<String> = issues.into_iter().map(|i| i.message).collect();
+            return Err(format!("schedule_invalid:{}", messages.join("; ")));
+        }
+    }
+
+    *state.schedule_auto_advance.write().await = ScheduleAutoAdvance {
+        enabled,
+        last_index: None,
+    };
+    if enabled {
+        ensure_schedule_ticker(app);
+    }
+    let _ = app.emit("schedule-auto-advance-changed", serde_json::json!({ "enabled": enabled }));
+    Ok(())
+}
+
+// ============================================================================
+// Stage Messages
+// ============================================================================
+
+fn active_stage_message(message: &Option<StageMessage>) -> Option<StageMessage> {
+    message
+        .clone()
+        .filter(|m| m.expires_at.is_none_or(|expires_at| expires_at > now_millis()))
+}
+
+// Shows `text` on stage clients. A message only replaces an active one of the
+// same or lower priority; timed messages clear themselves when they expire.
+async fn send_stage_message_impl(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    request: ApiStageMessageRequest,
+) -> Result<StageMessage, String> {
+    let text = request.text.trim().to_string();
+    if text.is_empty() {
+        return Err("text_required".to_string());
+    }
+
+    let now = now_millis();
+    let message = StageMessage {
+        id: uuid::Uuid::new_v4().to_string(),
+        text,
+        flash: request.flash,
+        priority: request.priority,
+        created_at: now,
+        expires_at: request
+            .duration
+            .filter(|d| *d > 0)
+            .map(|d| now.saturating_add(d.saturating_mul(1000))),
+    };
+    {
+        let mut current = state.stage_message.write().await;
+        if let Some(active) = active_stage_message(&current) {
+            if active.priority > message.priority {
+                return Err("higher_priority_message_active".to_string());
+            }
+        }
+        *current = Some(message.clone());
+    }
+    publish_stage_message(state, app, Some(message.clone()));
+
+    if let Some(expires_at) = message.expires_at {
+        let app = app.clone();
+        let id = message.id.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(expires_at.saturating_sub(now_millis()))).await;
+            let state = SERVER_STATE.clone();
+            let expired = {
+                let mut current = state.stage_message.write().await;
+                match current.as_ref() {
+                    Some(active) if active.id == id => current.take().is_some(),
+                    _ => false,
+                }
+            };
+            if expired {
+                publish_stage_message(&state, &app, None);
+            }
+        });
+    }
+    Ok(message)
+}
+
+// Only the joining client gets the current message; others already have it.
+async fn send_active_stage_message(state: &ServerState, direct_tx: &tokio::sync::mpsc::UnboundedSender<String>) {
+    let Some(message) = active_stage_message(&*state.stage_message.read().await) else {
+        return;
+    };
+    if let Ok(json) = serde_json::to_string(&WsMessage::StageMessage { message: Some(message) }) {
+        let _ = direct_tx.send(json);
+    }
+}
+
+async fn clear_stage_message_impl(state: &ServerState, app: &tauri::AppHandle) {
+    state.stage_message.write().await.take();
+    publish_stage_message(state, app, None);
+}
+
+// Stage clients get `stage_message`; the desktop shows it via `stage-message`.
+fn publish_stage_message(state: &ServerState, app: &tauri::AppHandle, message: Option<StageMessage>) {
+    let _ = app.emit("stage-message", &message);
+    broadcast_ws_message(state, &WsMessage::StageMessage { message });
+}
+
+// ============================================================================
+// WebSocket Handler (using warp)
+// ============================================================================
+
+async fn handle_ws_connection(ws: WebSocket, state: Arc<ServerState>, app: tauri::AppHandle) {
+    let (mut ws_sender, mut ws_receiver) = ws.split();
+    let mut broadcast_rx = state.broadcast_tx.subscribe();
+    
+    // Replies meant for this client only (e.g. time_sync)
+    let (direct_tx, mut direct_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    // Audio levels are only useful while fresh: at most one waits to be sent
+    // and newer readings are dropped until it is, so a slow client can't
+    // build up a backlog.
+    let (levels_tx, mut levels_rx) = tokio::sync::mpsc::channel::<String>(1);
+    let mut audio_levels_task: Option<tokio::task::JoinHandle<()>> = None;
+
+    // Spawn task to forward broadcasts and direct replies to this client
+    let forward_task = tokio::spawn(async move {
+        loop {
+            let msg = tokio::select! {
+                msg = broadcast_rx.recv() => match msg {
+                    Ok(msg) => msg,
+                    Err(_) => break,
+                },
+                Some(msg) = direct_rx.recv() => msg,
+                Some(msg) = levels_rx.recv() => msg,
+            };
+            if ws_sender.send(WarpWsMessage::text(msg)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    // Handle incoming messages
+    while let Some(result) = ws_receiver.next().await {
+        if let Ok(msg) = result {
+            if let Ok(text) = msg.to_str() {
+                if let Ok(ws_msg) = serde_json::from_str::<WsMessage>(text) {
+                    match ws_msg {
+                        WsMessage::TextUpdate { session_id, text, client_id } => {
+                            let slides = parse_notepad_text(&text);
+                            
+                            // Update session
+                            let updated = {
+                                let mut sessions = state.sessions.write().await;
+                                sessions.get_mut(&session_id).map(|session| {
+                                    session.slides = slides.clone();
+                                    session.raw_text = text.clone();
+                                    session.clone()
+                                })
+                            };
+                            if let Some(session) = updated {
+                                record_live_slide_revision(&state, &session, client_id, "text_update").await;
+                            }
+                            
+                            // Broadcast update to all clients
+                            let update = WsMessage::SlidesUpdate {
+                                session_id,
+                                slides,
+                                raw_text: text,
+                            };
+                            if let Ok(json) = serde_json::to_string(&update) {
+                                let _ = state.broadcast_tx.send(json);
+                            }
+                        }
+                        WsMessage::JoinSession { session_id, client_type: _ } => {
+                            // Send current session state to the joining client
+                            let sessions = state.sessions.read().await;
+                            if let Some(session) = sessions.get(&session_id) {
+                                let update = WsMessage::SlidesUpdate {
+                                    session_id: session_id.clone(),
+                                    slides: session.slides.clone(),
+                                    raw_text: session.raw_text.clone(),
+                                };
+                                if let Ok(json) = serde_json::to_string(&update) {
+                                    let _ = state.broadcast_tx.send(json);
+                                }
+                            }
+                            drop(sessions);
+                            // Followed by the slide currently on screen, if any
+                            if state.live_slide_cursors.read().await.contains_key(&session_id) {
+                                if let Ok(cursor) = get_live_slide_cursor_impl(&state, &session_id).await {
+                                    broadcast_live_slide_cursor(&state, &cursor);
+                                }
+                            }
+                        }
+                        WsMessage::TimeSync { client_time } => {
+                            // NTP-style exchange: the client records t3 on receipt and computes
+                            // offset = ((t1 - t0) + (t2 - t3)) / 2, round trip = (t3 - t0) - (t2 - t1).
+                            let server_receive_time = precise_now_millis();
+                            let reply = WsMessage::TimeSyncReply {
+                                client_time,
+                                server_receive_time,
+                                server_send_time: precise_now_millis(),
+                            };
+                            if let Ok(json) = serde_json::to_string(&reply) {
+                                let _ = direct_tx.send(json);
+                            }
+                        }
+                        WsMessage::LiveSlideNext { session_id } => {
+                            apply_live_slide_ws_control(&state, &app, &session_id, LiveSlideAction::Next).await;
+                        }
+                        WsMessage::LiveSlidePrev { session_id } => {
+                            apply_live_slide_ws_control(&state, &app, &session_id, LiveSlideAction::Prev).await;
+                        }
+                        WsMessage::LiveSlideGoto { session_id, index } => {
+                            apply_live_slide_ws_control(&state, &app, &session_id, LiveSlideAction::Goto(index)).await;
+                        }
+                        WsMessage::LiveSlideClear { session_id } => {
+                            apply_live_slide_ws_control(&state, &app, &session_id, LiveSlideAction::Clear).await;
+                        }
+                        WsMessage::JoinSchedule => {
+                            // Send current schedule state to the joining client
+                            let schedule_state = state.schedule.read().await;
+                            let update = WsMessage::ScheduleUpdate {
+                                schedule: schedule_state.schedule.clone(),
+                                current_session_index: schedule_state.current_session_index,
+                            };
+                            if let Ok(json) = serde_json::to_string(&update) {
+                                let _ = state.broadcast_tx.send(json);
+                            }
+                            send_active_stage_message(&state, &direct_tx).await;
+                        }
+                        WsMessage::JoinTimer => {
+                            // Send current timer state to the joining client only
+                            let update = WsMessage::TimerUpdate {
+                                timer_state: current_timer_state(&state).await,
+                            };
+                            if let Ok(json) = serde_json::to_string(&update) {
+                                let _ = direct_tx.send(json);
+                            }
+                            send_active_stage_message(&state, &direct_tx).await;
+                        }
+                        WsMessage::JoinDisplay { output } => {
+                            // Send the selected output's current state to the joining client.
+                            // Outputs that don't exist yet start out empty.
+                            let output = match resolve_display_output(output.as_deref()) {
+                                Ok(output) => output,
+                                Err(message) => {
+                                    if let Ok(json) = serde_json::to_string(&WsMessage::Error { message }) {
+                                        let _ = direct_tx.send(json);
+                                    }
+                                    continue;
+                                }
+                            };
+                            let display_state = state
+                                .display_outputs
+                                .read()
+                                .await
+                                .get(&output)
+                                .cloned()
+                                .unwrap_or_default();
+                            let update = WsMessage::DisplayUpdate {
+                                output,
+                                scripture: display_state.scripture,
+                                slides: display_state.slides,
+                                settings: Box::new(display_state.settings),
+                            };
+                            if let Ok(json) = serde_json::to_string(&update) {
+                                let _ = direct_tx.send(json);
+                            }
+                        }
+                        WsMessage::JoinAudioLevels => {
+                            if audio_levels_task.is_some() {
+                                continue;
+                            }
+                            let mut levels = state.audio_level_tx.subscribe();
+                            let levels_tx = levels_tx.clone();
+                            audio_levels_task = Some(tokio::spawn(async move {
+                                loop {
+                                    match levels.recv().await {
+                                        Ok(json) => match levels_tx.try_send(json) {
+                                            Ok(()) | Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {}
+                                            Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => break,
+                                        },
+                                        // A slow client just misses a few meter readings.
+                                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                                        Err(broadcast::error::RecvError::Closed) => break,
+                                    }
+                                }
+                            }));
+                        }
+                        WsMessage::TranscriptionStream { .. } => {
+                            // Re-broadcast browser transcription stream messages to all clients.
+                            // We forward the original JSON string so fields remain intact.
+                            let _ = state.broadcast_tx.send(text.to_string());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    
+    if let Some(task) = audio_levels_task {
+        task.abort();
+    }
+    forward_task.abort();
+}
+
+// ============================================================================
+// Static File Server (using warp + rust-embed)
+// ============================================================================
+
+fn serve_embedded_file(path: &str) -> Option<(Vec<u8>, String)> {
+    // Try exact path first
+    if let Some(content) = FrontendAssets::get(path) {
+        let mime = mime_guess::from_path(path).first_or_octet_stream();
+        return Some((content.data.to_vec(), mime.to_string()));
+    }
+
+    // In dev, fall back to serving from /public when dist isn't built yet.
+    if cfg!(debug_assertions) && !path.contains("..") {
+        let public_path = std::path::Path::new("../public").join(path);
+        if public_path.exists() {
+            if let Ok(data) = std::fs::read(&public_path) {
+                let mime = mime_guess::from_path(&public_path).first_or_octet_stream();
+                return Some((data, mime.to_string()));
+            }
+        }
+    }
+    
+    // For SPA routing, return index.html for non-file paths
+    if !path.contains('.') || path.ends_with('/') {
+        if let Some(content) = FrontendAssets::get("index.html") {
+            return Some((content.data.to_vec(), "text/html".to_string()));
+        }
+    }
+    
+    None
+}
+
+fn json_response(value: serde_json::Value, status: StatusCode) -> warp::reply::Response {
+    warp::reply::with_status(warp::reply::json(&value), status).into_response()
+}
+
+async fn api_timer_control(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    control: TimerControl,
+) -> warp::reply::Response {
+    if !*state.api_enabled.read().await {
+        return json_response(
+            serde_json::json!({ "error": "api_disabled" }),
+            StatusCode::FORBIDDEN,
+        );
+    }
+
+    match apply_timer_control(state, app, control).await {
+        Ok(timer_state) => json_response(
+            serde_json::json!({ "status": "ok", "timer_state": timer_state }),
+            StatusCode::OK,
+        ),
+        // e.g. timer_not_running, timer_not_started
+        Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::CONFLICT),
+    }
+}
+
+async fn api_live_slide_control(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    session_id: &str,
+    action: LiveSlideAction,
+) -> warp::reply::Response {
+    if !*state.api_enabled.read().await {
+        return json_response(
+            serde_json::json!({ "error": "api_disabled" }),
+            StatusCode::FORBIDDEN,
+        );
+    }
+
+    match move_live_slide_cursor(state, session_id, action).await {
+        Ok(cursor) => {
+            emit_live_slide_changed(app, &cursor);
+            json_response(serde_json::json!(cursor), StatusCode::OK)
+        }
+        Err(err) => {
+            let status = if err == "no_slides" {
+                StatusCode::CONFLICT
+            } else {
+                StatusCode::NOT_FOUND
+            };
+            json_response(serde_json::json!({ "error": err }), status)
+        }
+    }
+}
+
+async fn api_schedule_step(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    step: ScheduleStep,
+) -> warp::reply::Response {
+    if !*state.api_enabled.read().await {
+        return json_response(
+            serde_json::json!({ "error": "api_disabled" }),
+            StatusCode::FORBIDDEN,
+        );
+    }
+
+    match step_current_session(state, app, step, "api").await {
+        Ok(schedule_state) => json_response(
+            serde_json::json!({
+                "currentSessionIndex": schedule_state.current_session_index,
+                "session": schedule_state.current_session_index.and_then(|i| schedule_state.schedule.get(i)),
+                "timer_state": current_timer_state(state).await,
+            }),
+            StatusCode::OK,
+        ),
+        Err(err) => {
+            let status = if err.starts_with("session_not_found") {
+                StatusCode::NOT_FOUND
+            } else {
+                // schedule_empty, at_first_session, at_last_session
+                StatusCode::CONFLICT
+            };
+            json_response(serde_json::json!({ "error": err }), status)
+        }
+    }
+}
+
+// ============================================================================
+// Combined HTTP + WebSocket Server
+// ============================================================================
+
+async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), String> {
+    let state = SERVER_STATE.clone();
+    
+    // Create shutdown channel
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    *state.shutdown_tx.write().await = Some(shutdown_tx);
+    
+    // WebSocket route at /ws
+    let ws_state = state.clone();
+    let ws_app = app.clone();
     let ws_route = warp::path("ws")
         .and(warp::ws())
         .map(move |ws: warp::ws::Ws| {
             let state_clone = ws_state.clone();
-            ws.on_upgrade(move |socket| handle_ws_connection(socket, state_clone))
+            let app_clone = ws_app.clone();
+            ws.on_upgrade(move |socket| handle_ws_connection(socket, state_clone, app_clone))
+        });
+    
+    // Schedule API route
+    let api_state = state.clone();
+    let schedule_api_route = warp::path("api")
+        .and(warp::path("schedule"))
+        .and(warp::path::end())
+        .and_then(move || {
+            let state_clone = api_state.clone();
+            async move {
+                let schedule_state = state_clone.schedule.read().await;
+                let response = serde_json::json!({
+                    "schedule": schedule_state.schedule,
+                    "currentSessionIndex": schedule_state.current_session_index,
+                });
+                Ok::<_, warp::Rejection>(warp::reply::json(&response))
+            }
+        });
+    
+    // Live Slides API route - exposes all sessions as JSON for master/slave sync
+    let live_slides_api_state = state.clone();
+    let live_slides_api_route = warp::path("api")
+        .and(warp::path("live-slides"))
+        .and(warp::path::end())
+        .and_then(move || {
+            let state_clone = live_slides_api_state.clone();
+            async move {
+                let sessions = state_clone.sessions.read().await;
+                let session_list: Vec<&LiveSlideSession> = sessions.values().collect();
+                let response = serde_json::json!({
+                    "sessions": session_list,
+                    "server_running": true,
+                });
+                Ok::<_, warp::Rejection>(warp::reply::json(&response))
+            }
+        });
+
+    // Live Slides revision history: list, diff and restore
+    let revisions_list_state = state.clone();
+    let live_slides_revisions_route = warp::path("api")
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("revisions"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move |session_id: String| {
+            let state_clone = revisions_list_state.clone();
+            async move {
+                let response = match list_live_slide_revisions_impl(&state_clone, &session_id).await {
+                    Ok(revisions) => json_response(
+                        serde_json::json!({ "session_id": session_id, "revisions": revisions }),
+                        StatusCode::OK,
+                    ),
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::NOT_FOUND),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let revisions_diff_state = state.clone();
+    let live_slides_revisions_diff_route = warp::path("api")
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("revisions"))
+        .and(warp::path("diff"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |session_id: String, query: HashMap<String, String>| {
+            let state_clone = revisions_diff_state.clone();
+            async move {
+                let from = query.get("from").and_then(|v| v.parse::<u64>().ok());
+                let to = query.get("to").and_then(|v| v.parse::<u64>().ok());
+                let (from, to) = match (from, to) {
+                    (Some(from), Some(to)) => (from, to),
+                    _ => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": "from_and_to_required" }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+
+                let response = match diff_live_slide_revisions_impl(&state_clone, &session_id, from, to).await {
+                    Ok(diff) => json_response(serde_json::json!(diff), StatusCode::OK),
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::NOT_FOUND),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let revisions_restore_state = state.clone();
+    let live_slides_revisions_restore_route = warp::path("api")
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("revisions"))
+        .and(warp::path::param::<u64>())
+        .and(warp::path("restore"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::bytes())
+        .and_then(move |session_id: String, revision: u64, body: warp::hyper::body::Bytes| {
+            let state_clone = revisions_restore_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                // The body is optional; it only carries the restoring client's id.
+                let client_id = serde_json::from_slice::<ApiLiveSlideRestoreRequest>(&body)
+                    .ok()
+                    .and_then(|b| b.client_id);
+
+                let response = match restore_live_slide_revision_impl(&state_clone, &session_id, revision, client_id).await {
+                    Ok(session) => json_response(
+                        serde_json::json!({ "status": "restored", "revision": revision, "session": session }),
+                        StatusCode::OK,
+                    ),
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::NOT_FOUND),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    // Live Slides export: GET /api/live-slides/{id}/export?format=pptx|openlyrics|txt
+    let export_state = state.clone();
+    let live_slides_export_route = warp::path("api")
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("export"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |session_id: String, query: HashMap<String, String>| {
+            let state_clone = export_state.clone();
+            async move {
+                let format = match ExportFormat::parse(query.get("format").map(|f| f.as_str()).unwrap_or("pptx")) {
+                    Ok(format) => format,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+
+                let session = match state_clone.sessions.read().await.get(&session_id) {
+                    Some(session) => session.clone(),
+                    None => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": "session_not_found" }),
+                            StatusCode::NOT_FOUND,
+                        ));
+                    }
+                };
+
+                let response = match live_slides_export::export_session(&session, format) {
+                    Ok(file) => warp::http::Response::builder()
+                        .header("Content-Type", file.content_type)
+                        .header(
+                            "Content-Disposition",
+                            format!("attachment; filename=\"{}\"", file.file_name),
+                        )
+                        .header("Access-Control-Allow-Origin", "*")
+                        .body(warp::hyper::Body::from(file.data))
+                        .unwrap()
+                        .into_response(),
+                    Err(err) => json_response(
+                        serde_json::json!({ "error": err }),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    ),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    // API v1: Live Slides session resources. Reads are open like /api/live-slides;
+    // writes require the API to be enabled and broadcast the same WsMessages as
+    // the Tauri commands.
+    let v1_sessions_list_state = state.clone();
+    let api_live_slides_sessions_list_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = v1_sessions_list_state.clone();
+            async move {
+                let sessions = state_clone.sessions.read().await;
+                let mut session_list: Vec<&LiveSlideSession> = sessions.values().collect();
+                session_list.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "sessions": session_list }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    let v1_sessions_create_state = state.clone();
+    let api_live_slides_sessions_create_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(move |body: ApiLiveSlideSessionRequest| {
+            let state_clone = v1_sessions_create_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let name = body.name.as_deref().unwrap_or("").trim().to_string();
+                if name.is_empty() {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "name_required" }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+
+                let session = match body.raw_text.filter(|t| !t.is_empty()) {
+                    Some(raw_text) => {
+                        let session_id = uuid::Uuid::new_v4().to_string();
+                        upsert_live_slide_session_impl(&state_clone, session_id, name, raw_text, body.ccli_number, body.client_id, "create").await
+                    }
+                    None => create_live_slide_session_impl(&state_clone, name).await,
+                };
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "session": session }),
+                    StatusCode::CREATED,
+                ))
+            }
+        });
+
+    let v1_session_get_state = state.clone();
+    let api_live_slides_session_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move |session_id: String| {
+            let state_clone = v1_session_get_state.clone();
+            async move {
+                let response = match state_clone.sessions.read().await.get(&session_id) {
+                    Some(session) => json_response(serde_json::json!({ "session": session }), StatusCode::OK),
+                    None => json_response(
+                        serde_json::json!({ "error": "session_not_found" }),
+                        StatusCode::NOT_FOUND,
+                    ),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let v1_session_put_state = state.clone();
+    let api_live_slides_session_put_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::put())
+        .and(warp::body::json())
+        .and_then(move |session_id: String, body: ApiLiveSlideSessionRequest| {
+            let state_clone = v1_session_put_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let name = body.name.as_deref().unwrap_or("").trim().to_string();
+                if name.is_empty() {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "name_required" }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+
+                // PUT replaces the whole session, creating it under this id if needed.
+                let raw_text = body.raw_text.unwrap_or_default();
+                let session = upsert_live_slide_session_impl(
+                    &state_clone,
+                    session_id,
+                    name,
+                    raw_text,
+                    body.ccli_number,
+                    body.client_id,
+                    "upsert",
+                )
+                .await;
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "session": session }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    let v1_session_patch_state = state.clone();
+    let api_live_slides_session_patch_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::patch())
+        .and(warp::body::json())
+        .and_then(move |session_id: String, body: ApiLiveSlideSessionRequest| {
+            let state_clone = v1_session_patch_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                if body.name.is_none() && body.raw_text.is_none() && body.ccli_number.is_none() {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "name_or_raw_text_required" }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+
+                let session = patch_live_slide_session_impl(
+                    &state_clone,
+                    &session_id,
+                    body.name,
+                    body.raw_text,
+                    body.ccli_number,
+                    body.client_id,
+                )
+                .await;
+                let Some(session) = session else {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "session_not_found" }),
+                        StatusCode::NOT_FOUND,
+                    ));
+                };
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "session": session }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    let v1_session_delete_state = state.clone();
+    let api_live_slides_session_delete_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::delete())
+        .and_then(move |session_id: String| {
+            let state_clone = v1_session_delete_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let response = if delete_live_slide_session_impl(&state_clone, session_id.clone()).await {
+                    json_response(
+                        serde_json::json!({ "status": "deleted", "session_id": session_id }),
+                        StatusCode::OK,
+                    )
+                } else {
+                    json_response(
+                        serde_json::json!({ "error": "session_not_found" }),
+                        StatusCode::NOT_FOUND,
+                    )
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let v1_session_slide_state = state.clone();
+    let api_live_slides_session_slide_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path("sessions"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("slides"))
+        .and(warp::path::param::<usize>())
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move |session_id: String, index: usize| {
+            let state_clone = v1_session_slide_state.clone();
+            async move {
+                let sessions = state_clone.sessions.read().await;
+                let Some(session) = sessions.get(&session_id) else {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "session_not_found" }),
+                        StatusCode::NOT_FOUND,
+                    ));
+                };
+
+                let response = match session.slides.get(index) {
+                    Some(slide) => json_response(
+                        serde_json::json!({
+                            "session_id": session_id,
+                            "index": index,
+                            "total": session.slides.len(),
+                            "slide": slide,
+                        }),
+                        StatusCode::OK,
+                    ),
+                    None => json_response(
+                        serde_json::json!({ "error": "slide_not_found" }),
+                        StatusCode::NOT_FOUND,
+                    ),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let api_live_slides_sessions_route = api_live_slides_sessions_list_route
+        .or(api_live_slides_sessions_create_route)
+        .or(api_live_slides_session_get_route)
+        .or(api_live_slides_session_put_route)
+        .or(api_live_slides_session_patch_route)
+        .or(api_live_slides_session_delete_route)
+        .or(api_live_slides_session_slide_route);
+
+    // API v1: Live Slides cursor — POST next|prev|clear, POST goto/{n}, GET current
+    let cursor_state = state.clone();
+    let cursor_app = app.clone();
+    let api_live_slides_cursor_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::post())
+        .and_then(move |session_id: String, action: String| {
+            let state_clone = cursor_state.clone();
+            let app_clone = cursor_app.clone();
+            async move {
+                let action = match action.as_str() {
+                    "next" => LiveSlideAction::Next,
+                    "prev" => LiveSlideAction::Prev,
+                    "clear" => LiveSlideAction::Clear,
+                    _ => return Err(warp::reject::not_found()),
+                };
+                Ok::<_, warp::Rejection>(api_live_slide_control(&state_clone, &app_clone, &session_id, action).await)
+            }
+        });
+
+    let cursor_goto_state = state.clone();
+    let cursor_goto_app = app.clone();
+    let api_live_slides_cursor_goto_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("goto"))
+        .and(warp::path::param::<usize>())
+        .and(warp::path::end())
+        .and(warp::post())
+        .and_then(move |session_id: String, index: usize| {
+            let state_clone = cursor_goto_state.clone();
+            let app_clone = cursor_goto_app.clone();
+            async move {
+                Ok::<_, warp::Rejection>(
+                    api_live_slide_control(&state_clone, &app_clone, &session_id, LiveSlideAction::Goto(index)).await,
+                )
+            }
+        });
+
+    let cursor_get_state = state.clone();
+    let api_live_slides_cursor_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("live-slides"))
+        .and(warp::path::param::<String>())
+        .and(warp::path("current"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move |session_id: String| {
+            let state_clone = cursor_get_state.clone();
+            async move {
+                let response = match get_live_slide_cursor_impl(&state_clone, &session_id).await {
+                    Ok(cursor) => json_response(serde_json::json!(cursor), StatusCode::OK),
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::NOT_FOUND),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let api_live_slides_cursor_routes = api_live_slides_cursor_route
+        .or(api_live_slides_cursor_goto_route)
+        .or(api_live_slides_cursor_get_route);
+
+    // API: Playlists (full slides) for network import
+    let api_playlists_state = state.clone();
+    let api_playlists_route = warp::path("api")
+        .and(warp::path("playlists"))
+        .and(warp::path::end())
+        .and_then(move || {
+            let state_clone = api_playlists_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let playlists = state_clone
+                    .api_playlists
+                    .read()
+                    .await
+                    .clone()
+                    .unwrap_or_else(|| serde_json::json!([]));
+                let response = serde_json::json!({ "playlists": playlists });
+                Ok::<_, warp::Rejection>(json_response(response, StatusCode::OK))
+            }
+        });
+
+    // API: Remote transcription pin — GET list pinned clients, POST pin a client
+    let transcription_pin_list_state = state.clone();
+    let transcription_pin_list_route = warp::path("api")
+        .and(warp::path("transcription"))
+        .and(warp::path("pin"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = transcription_pin_list_state.clone();
+            async move {
+                let pinned_clients = state_clone.pinned_transcription_clients.read().await;
+                let list: Vec<&PinnedTranscriptionClient> = pinned_clients.values().collect();
+                let response = serde_json::json!({ "pinned": list });
+                Ok::<_, warp::Rejection>(warp::reply::json(&response))
+            }
+        });
+
+    let transcription_pin_post_state = state.clone();
+    let transcription_pin_post_route = warp::path("api")
+        .and(warp::path("transcription"))
+        .and(warp::path("pin"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(move |body: ApiTranscriptionPinRequest| {
+            let state_clone = transcription_pin_post_state.clone();
+            async move {
+                let client_id = body.client_id.trim().to_string();
+                if client_id.is_empty() {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "client_id_required" }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+
+                let pinned_at = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_millis() as u64;
+                let pinned = PinnedTranscriptionClient {
+                    client_id: client_id.clone(),
+                    label: body.label.clone(),
+                    pinned_at,
+                };
+
+                {
+                    let mut pinned_clients = state_clone.pinned_transcription_clients.write().await;
+                    pinned_clients.insert(client_id.clone(), pinned.clone());
+                }
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({
+                        "status": "pinned",
+                        "clientId": pinned.client_id,
+                        "label": pinned.label,
+                        "pinnedAt": pinned.pinned_at,
+                    }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    let transcription_pin_route = transcription_pin_list_route.or(transcription_pin_post_route);
+
+    // API v1: Scripture go-live
+    let api_app = app.clone();
+    let api_scripture_state = state.clone();
+    let api_scripture_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("scripture"))
+        .and(warp::path("go-live"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(move |body: ApiScriptureGoLiveRequest| {
+            let state_clone = api_scripture_state.clone();
+            let app_clone = api_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let reference = body.reference.trim().to_string();
+                if reference.is_empty() {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "reference_required" }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+
+                let translation = body
+                    .translation
+                    .as_deref()
+                    .unwrap_or("")
+                    .trim()
+                    .to_string();
+                let translation = if translation.is_empty() {
+                    None
+                } else {
+                    Some(translation)
+                };
+
+                if let Err(err) = app_clone.emit(
+                    "api-scripture-go-live",
+                    serde_json::json!({
+                        "reference": reference,
+                        "translation": translation,
+                    }),
+                ) {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({
+                            "error": "emit_failed",
+                            "detail": err.to_string()
+                        }),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    ));
+                }
+
+                let mut entry = DisplayHistoryEntry::new(DisplayHistoryKind::ScriptureGoLive, now_millis());
+                entry.reference = Some(reference.clone());
+                entry.translation = translation.clone();
+                record_display_history(&state_clone, entry).await;
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({
+                        "status": "queued",
+                        "reference": reference
+                    }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    // API v1: Timer start
+    let api_timer_state = state.clone();
+    let api_timer_app = app.clone();
+    let api_timer_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path("start"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(move |body: ApiTimerStartRequest| {
+            let state_clone = api_timer_state.clone();
+            let app_clone = api_timer_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let control = match timer_start_control(
+                    body.seconds.or_else(|| body.minutes.map(|m| m * 60.0)),
+                    body.mode.as_deref(),
+                    body.end_time.clone(),
+                    body.session_name.clone(),
+                ) {
+                    Ok(control) => control,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+                let is_count_up = matches!(control, TimerControl::StartCountUp { .. });
+
+                let timer_state = match apply_timer_control(&state_clone, &app_clone, control).await {
+                    Ok(timer_state) => timer_state,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+                let seconds = timer_state.time_left;
+
+                // The desktop UI still listens for this to switch to the timer view.
+                let emitted = if is_count_up {
+                    Ok(())
+                } else {
+                    app_clone.emit("api-timer-start", serde_json::json!({ "seconds": seconds }))
+                };
+                if let Err(err) = emitted {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({
+                            "error": "emit_failed",
+                            "detail": err.to_string()
+                        }),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    ));
+                }
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({
+                        "status": "started",
+                        "seconds": seconds,
+                        "timer_state": timer_state
+                    }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    // Helper macro to create simple event-emitting API routes
+    macro_rules! create_event_route {
+        ($state:expr, $app:expr, $($segment:expr),+ => $event:expr) => {
+            {
+                let state_clone = $state.clone();
+                let app_clone = $app.clone();
+                let event_name = $event;
+                warp::path("api")
+                    $(.and(warp::path($segment)))+
+                    .and(warp::path::end())
+                    .and(warp::post())
+                    .and_then(move || {
+                        let state_inner = state_clone.clone();
+                        let app_inner = app_clone.clone();
+                        let event = event_name;
+                        async move {
+                            if !*state_inner.api_enabled.read().await {
+                                return Ok::<_, warp::Rejection>(json_response(
+                                    serde_json::json!({ "error": "api_disabled" }),
+                                    StatusCode::FORBIDDEN,
+                                ));
+                            }
+
+                            if let Err(err) = app_inner.emit(event, serde_json::json!({})) {
+                                return Ok::<_, warp::Rejection>(json_response(
+                                    serde_json::json!({
+                                        "error": "emit_failed",
+                                        "detail": err.to_string()
+                                    }),
+                                    StatusCode::INTERNAL_SERVER_ERROR,
+                                ));
+                            }
+
+                            Ok::<_, warp::Rejection>(json_response(
+                                serde_json::json!({ "status": "queued" }),
+                                StatusCode::OK,
+                            ))
+                        }
+                    })
+            }
+        };
+    }
+
+    // API v1: Timer stop (resets the server timer)
+    let api_timer_stop_state = state.clone();
+    let api_timer_stop_app = app.clone();
+    let api_timer_stop_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path("stop"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and_then(move || {
+            let state_clone = api_timer_stop_state.clone();
+            let app_clone = api_timer_stop_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                let timer_state = apply_timer_control(&state_clone, &app_clone, TimerControl::Stop)
+                    .await
+                    .unwrap_or_default();
+
+                if let Err(err) = app_clone.emit("api-timer-stop", serde_json::json!({})) {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({
+                            "error": "emit_failed",
+                            "detail": err.to_string()
+                        }),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    ));
+                }
+
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "status": "stopped", "timer_state": timer_state }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    // API v1: Timer state and pause/resume/reset/adjust
+    let api_timer_get_state = state.clone();
+    let api_timer_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = api_timer_get_state.clone();
+            async move {
+                let timer_state = current_timer_state(&state_clone).await;
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!(timer_state), StatusCode::OK))
+            }
+        });
+
+    let api_timer_control_state = state.clone();
+    let api_timer_control_app = app.clone();
+    let api_timer_control_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::post())
+        .and_then(move |action: String| {
+            let state_clone = api_timer_control_state.clone();
+            let app_clone = api_timer_control_app.clone();
+            async move {
+                let control = match action.as_str() {
+                    "pause" => TimerControl::Pause,
+                    "resume" => TimerControl::Resume,
+                    "reset" => TimerControl::Reset,
+                    _ => return Err(warp::reject::not_found()),
+                };
+                Ok::<_, warp::Rejection>(api_timer_control(&state_clone, &app_clone, control).await)
+            }
         });
-    
-    // Schedule API route
-    let api_state = state.clone();
-    let schedule_api_route = warp::path("api")
-        .and(warp::path("schedule"))
+
+    let api_timer_adjust_state = state.clone();
+    let api_timer_adjust_app = app.clone();
+    let api_timer_adjust_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path("adjust"))
         .and(warp::path::end())
-        .and_then(move || {
-            let state_clone = api_state.clone();
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(move |body: ApiTimerAdjustRequest| {
+            let state_clone = api_timer_adjust_state.clone();
+            let app_clone = api_timer_adjust_app.clone();
             async move {
-                let schedule_state = state_clone.schedule.read().await;
-                let response = serde_json::json!({
-                    "schedule": schedule_state.schedule,
-                    "currentSessionIndex": schedule_state.current_session_index,
-                });
-                Ok::<_, warp::Rejection>(warp::reply::json(&response))
+                let seconds = body
+                    .seconds
+                    .or_else(|| body.minutes.map(|m| m * 60.0))
+                    .filter(|s| s.is_finite())
+                    .map(|s| s.trunc() as i64)
+                    .unwrap_or(0);
+                if seconds == 0 {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "seconds_required" }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+                Ok::<_, warp::Rejection>(
+                    api_timer_control(&state_clone, &app_clone, TimerControl::AddTime(seconds)).await,
+                )
             }
         });
-    
-    // Live Slides API route - exposes all sessions as JSON for master/slave sync
-    let live_slides_api_state = state.clone();
-    let live_slides_api_route = warp::path("api")
-        .and(warp::path("live-slides"))
+
+    // API v1: Timer alert rules (GET is open, PUT requires the API enabled)
+    let api_timer_alerts_get_state = state.clone();
+    let api_timer_alerts_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path("alerts"))
         .and(warp::path::end())
+        .and(warp::get())
         .and_then(move || {
-            let state_clone = live_slides_api_state.clone();
+            let state_clone = api_timer_alerts_get_state.clone();
             async move {
-                let sessions = state_clone.sessions.read().await;
-                let session_list: Vec<&LiveSlideSession> = sessions.values().collect();
-                let response = serde_json::json!({
-                    "sessions": session_list,
-                    "server_running": true,
-                });
-                Ok::<_, warp::Rejection>(warp::reply::json(&response))
+                let rules = state_clone.timer_alert_rules.read().await.clone();
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!({ "rules": rules }), StatusCode::OK))
             }
         });
 
-    // API: Playlists (full slides) for network import
-    let api_playlists_state = state.clone();
-    let api_playlists_route = warp::path("api")
-        .and(warp::path("playlists"))
+    let api_timer_alerts_put_state = state.clone();
+    let api_timer_alerts_put_app = app.clone();
+    let api_timer_alerts_put_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("timer"))
+        .and(warp::path("alerts"))
         .and(warp::path::end())
-        .and_then(move || {
-            let state_clone = api_playlists_state.clone();
+        .and(warp::put())
+        .and(warp::body::content_length_limit(64 * 1024))
+        .and(warp::body::json())
+        .and_then(move |body: ApiTimerAlertRulesRequest| {
+            let state_clone = api_timer_alerts_put_state.clone();
+            let app_clone = api_timer_alerts_put_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+                if let Err(err) = timer_alerts::validate_rules(&body.rules) {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": err }),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+                if let Err(err) = set_timer_alert_rules_impl(&state_clone, &app_clone, body.rules.clone()).await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": err }),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    ));
+                }
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "rules": body.rules }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    let api_timer_extra_routes = api_timer_get_route
+        .or(api_timer_alerts_get_route)
+        .or(api_timer_alerts_put_route)
+        .or(api_timer_adjust_route)
+        .or(api_timer_control_route);
+
+    // API v1: Schedule import (CSV, iCalendar, Planning Center) and export
+    let schedule_import_state = state.clone();
+    let schedule_import_app = app.clone();
+    let api_schedule_import_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("import"))
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::content_length_limit(8 * 1024 * 1024))
+        .and(warp::body::json())
+        .and_then(move |body: ApiScheduleImportRequest| {
+            let state_clone = schedule_import_state.clone();
+            let app_clone = schedule_import_app.clone();
             async move {
                 if !*state_clone.api_enabled.read().await {
                     return Ok::<_, warp::Rejection>(json_response(
@@ -720,94 +3082,377 @@ async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), Str
                     ));
                 }
 
-                let playlists = state_clone
-                    .api_playlists
-                    .read()
-                    .await
-                    .clone()
-                    .unwrap_or_else(|| serde_json::json!([]));
-                let response = serde_json::json!({ "playlists": playlists });
-                Ok::<_, warp::Rejection>(json_response(response, StatusCode::OK))
+                let imported = ScheduleImportFormat::parse(&body.format).and_then(|format| {
+                    schedule_io::import_schedule(body.content.as_bytes(), format, &body.options)
+                });
+                let imported = match imported {
+                    Ok(imported) => imported,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+
+                let validation = if body.apply.unwrap_or(true) {
+                    replace_schedule_from_api(&state_clone, &app_clone, imported.schedule.clone(), None).await
+                } else {
+                    schedule_engine::validate_schedule(&imported.schedule)
+                };
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({
+                        "schedule": imported.schedule,
+                        "warnings": imported.warnings,
+                        "validation": validation,
+                        "applied": body.apply.unwrap_or(true),
+                    }),
+                    StatusCode::OK,
+                ))
             }
         });
 
-    // API: Remote transcription pin — GET list pinned clients, POST pin a client
-    let transcription_pin_list_state = state.clone();
-    let transcription_pin_list_route = warp::path("api")
-        .and(warp::path("transcription"))
-        .and(warp::path("pin"))
+    // GET /api/v1/schedule/export?format=csv|ics|pco&date=YYYY-MM-DD
+    let schedule_export_state = state.clone();
+    let api_schedule_export_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("export"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |query: HashMap<String, String>| {
+            let state_clone = schedule_export_state.clone();
+            async move {
+                let format = ScheduleExportFormat::parse(query.get("format").map(|f| f.as_str()).unwrap_or("csv"));
+                let date = match query.get("date") {
+                    Some(date) => chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                        .map_err(|_| format!("invalid_date:{}", date)),
+                    None => Ok(chrono::Local::now().date_naive()),
+                };
+                let (format, date) = match (format, date) {
+                    (Ok(format), Ok(date)) => (format, date),
+                    (Err(err), _) | (_, Err(err)) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+
+                let schedule = state_clone.schedule.read().await.schedule.clone();
+                let file = schedule_io::export_schedule(&schedule, format, date, &format!("schedule-{}", date));
+                let response = warp::http::Response::builder()
+                    .header("Content-Type", file.content_type)
+                    .header(
+                        "Content-Disposition",
+                        format!("attachment; filename=\"{}\"", file.file_name),
+                    )
+                    .header("Access-Control-Allow-Origin", "*")
+                    .body(warp::hyper::Body::from(file.data))
+                    .unwrap()
+                    .into_response();
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let api_schedule_io_routes = api_schedule_import_route.or(api_schedule_export_route);
+
+    // API v1: Schedule resource. Writes require the API to be enabled and
+    // broadcast `schedule_update` like the desktop UI does.
+    let v1_schedule_get_state = state.clone();
+    let api_schedule_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
         .and(warp::path::end())
         .and(warp::get())
         .and_then(move || {
-            let state_clone = transcription_pin_list_state.clone();
+            let state_clone = v1_schedule_get_state.clone();
             async move {
-                let pinned_clients = state_clone.pinned_transcription_clients.read().await;
-                let list: Vec<&PinnedTranscriptionClient> = pinned_clients.values().collect();
-                let response = serde_json::json!({ "pinned": list });
-                Ok::<_, warp::Rejection>(warp::reply::json(&response))
+                let schedule_state = state_clone.schedule.read().await.clone();
+                let validation = schedule_engine::validate_schedule(&schedule_state.schedule);
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({
+                        "schedule": schedule_state.schedule,
+                        "currentSessionIndex": schedule_state.current_session_index,
+                        "validation": validation,
+                    }),
+                    StatusCode::OK,
+                ))
             }
         });
 
-    let transcription_pin_post_state = state.clone();
-    let transcription_pin_post_route = warp::path("api")
-        .and(warp::path("transcription"))
-        .and(warp::path("pin"))
+    let v1_schedule_put_state = state.clone();
+    let v1_schedule_put_app = app.clone();
+    let api_schedule_put_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
         .and(warp::path::end())
-        .and(warp::post())
+        .and(warp::put())
+        .and(warp::body::content_length_limit(1024 * 1024))
         .and(warp::body::json())
-        .and_then(move |body: ApiTranscriptionPinRequest| {
-            let state_clone = transcription_pin_post_state.clone();
+        .and_then(move |body: ApiScheduleRequest| {
+            let state_clone = v1_schedule_put_state.clone();
+            let app_clone = v1_schedule_put_app.clone();
             async move {
-                let client_id = body.client_id.trim().to_string();
-                if client_id.is_empty() {
+                if !*state_clone.api_enabled.read().await {
                     return Ok::<_, warp::Rejection>(json_response(
-                        serde_json::json!({ "error": "client_id_required" }),
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+                if body.current_session_index.is_some_and(|i| i >= body.schedule.len()) {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "session_not_found" }),
                         StatusCode::BAD_REQUEST,
                     ));
                 }
 
-                let pinned_at = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64;
-                let pinned = PinnedTranscriptionClient {
-                    client_id: client_id.clone(),
-                    label: body.label.clone(),
-                    pinned_at,
+                let validation = replace_schedule_from_api(
+                    &state_clone,
+                    &app_clone,
+                    body.schedule.clone(),
+                    body.current_session_index,
+                )
+                .await;
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({
+                        "schedule": body.schedule,
+                        "currentSessionIndex": body.current_session_index,
+                        "validation": validation,
+                    }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    // PATCH /api/v1/schedule/items/{id}: update the fields given for one item
+    let v1_schedule_patch_state = state.clone();
+    let v1_schedule_patch_app = app.clone();
+    let api_schedule_patch_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("items"))
+        .and(warp::path::param::<i32>())
+        .and(warp::path::end())
+        .and(warp::patch())
+        .and(warp::body::content_length_limit(64 * 1024))
+        .and(warp::body::json())
+        .and_then(move |item_id: i32, body: ApiScheduleItemPatch| {
+            let state_clone = v1_schedule_patch_state.clone();
+            let app_clone = v1_schedule_patch_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+
+                // Edit in place under one write guard so concurrent PUTs and
+                // session changes aren't overwritten with a stale copy.
+                let (item, schedule_state) = {
+                    let mut schedule_state = state_clone.schedule.write().await;
+                    let Some(item) = schedule_state.schedule.iter_mut().find(|item| item.id == item_id) else {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": "item_not_found" }),
+                            StatusCode::NOT_FOUND,
+                        ));
+                    };
+                    if let Some(session) = body.session {
+                        item.session = session;
+                    }
+                    if let Some(start_time) = body.start_time {
+                        item.start_time = start_time;
+                    }
+                    if let Some(end_time) = body.end_time {
+                        item.end_time = end_time;
+                    }
+                    if let Some(duration) = body.duration {
+                        item.duration = duration;
+                    }
+                    if let Some(minister) = body.minister {
+                        item.minister = Some(minister).filter(|m| !m.trim().is_empty());
+                    }
+                    (item.clone(), schedule_state.clone())
                 };
 
-                {
-                    let mut pinned_clients = state_clone.pinned_transcription_clients.write().await;
-                    pinned_clients.insert(client_id.clone(), pinned.clone());
+                let validation = publish_schedule_from_api(&state_clone, &app_clone, &schedule_state).await;
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "item": item, "validation": validation }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    // POST /api/v1/schedule/{advance|back} and /api/v1/schedule/goto/{index}
+    let v1_schedule_step_state = state.clone();
+    let v1_schedule_step_app = app.clone();
+    let api_schedule_step_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::post())
+        .and_then(move |action: String| {
+            let state_clone = v1_schedule_step_state.clone();
+            let app_clone = v1_schedule_step_app.clone();
+            async move {
+                let step = match action.as_str() {
+                    "advance" => ScheduleStep::Advance,
+                    "back" => ScheduleStep::Back,
+                    _ => return Err(warp::reject::not_found()),
+                };
+                Ok::<_, warp::Rejection>(api_schedule_step(&state_clone, &app_clone, step).await)
+            }
+        });
+
+    let v1_schedule_goto_state = state.clone();
+    let v1_schedule_goto_app = app.clone();
+    let api_schedule_goto_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("goto"))
+        .and(warp::path::param::<usize>())
+        .and(warp::path::end())
+        .and(warp::post())
+        .and_then(move |index: usize| {
+            let state_clone = v1_schedule_goto_state.clone();
+            let app_clone = v1_schedule_goto_app.clone();
+            async move {
+                Ok::<_, warp::Rejection>(
+                    api_schedule_step(&state_clone, &app_clone, ScheduleStep::Goto(index)).await,
+                )
+            }
+        });
+
+    // GET /api/v1/schedule/report: planned vs actual times for the sessions run
+    let v1_schedule_report_state = state.clone();
+    let api_schedule_report_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("report"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = v1_schedule_report_state.clone();
+            async move {
+                let report = state_clone.schedule_runs.read().await.report(chrono::Local::now());
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!(report), StatusCode::OK))
+            }
+        });
+
+    let v1_schedule_report_clear_state = state.clone();
+    let api_schedule_report_clear_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("report"))
+        .and(warp::path::end())
+        .and(warp::delete())
+        .and_then(move || {
+            let state_clone = v1_schedule_report_clear_state.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
+                }
+                state_clone.schedule_runs.write().await.clear();
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "status": "cleared" }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    // GET/PUT /api/v1/schedule/auto-advance: follow the schedule's start times
+    let v1_schedule_auto_get_state = state.clone();
+    let api_schedule_auto_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("auto-advance"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = v1_schedule_auto_get_state.clone();
+            async move {
+                let enabled = state_clone.schedule_auto_advance.read().await.enabled;
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "enabled": enabled }),
+                    StatusCode::OK,
+                ))
+            }
+        });
+
+    let v1_schedule_auto_put_state = state.clone();
+    let v1_schedule_auto_put_app = app.clone();
+    let api_schedule_auto_put_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("schedule"))
+        .and(warp::path("auto-advance"))
+        .and(warp::path::end())
+        .and(warp::put())
+        .and(warp::body::content_length_limit(1024))
+        .and(warp::body::json())
+        .and_then(move |body: ApiScheduleAutoAdvanceRequest| {
+            let state_clone = v1_schedule_auto_put_state.clone();
+            let app_clone = v1_schedule_auto_put_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
+                    ));
                 }
-
-                Ok::<_, warp::Rejection>(json_response(
-                    serde_json::json!({
-                        "status": "pinned",
-                        "clientId": pinned.client_id,
-                        "label": pinned.label,
-                        "pinnedAt": pinned.pinned_at,
-                    }),
-                    StatusCode::OK,
-                ))
+                let response = match set_schedule_auto_advance_impl(&state_clone, &app_clone, body.enabled).await {
+                    Ok(()) => json_response(serde_json::json!({ "enabled": body.enabled }), StatusCode::OK),
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::CONFLICT),
+                };
+                Ok::<_, warp::Rejection>(response)
             }
         });
 
-    let transcription_pin_route = transcription_pin_list_route.or(transcription_pin_post_route);
+    let api_schedule_v1_routes = api_schedule_io_routes
+        .or(api_schedule_auto_get_route)
+        .or(api_schedule_auto_put_route)
+        .or(api_schedule_report_route)
+        .or(api_schedule_report_clear_route)
+        .or(api_schedule_get_route)
+        .or(api_schedule_put_route)
+        .or(api_schedule_patch_route)
+        .or(api_schedule_goto_route)
+        .or(api_schedule_step_route);
+
+    // API v1: Stage messages for the speaker (timer/schedule views only)
+    let stage_message_get_state = state.clone();
+    let api_stage_message_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("stage"))
+        .and(warp::path("message"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = stage_message_get_state.clone();
+            async move {
+                let message = active_stage_message(&*state_clone.stage_message.read().await);
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!({ "message": message }), StatusCode::OK))
+            }
+        });
 
-    // API v1: Scripture go-live
-    let api_app = app.clone();
-    let api_scripture_state = state.clone();
-    let api_scripture_route = warp::path("api")
+    let stage_message_post_state = state.clone();
+    let stage_message_post_app = app.clone();
+    let api_stage_message_post_route = warp::path("api")
         .and(warp::path("v1"))
-        .and(warp::path("scripture"))
-        .and(warp::path("go-live"))
+        .and(warp::path("stage"))
+        .and(warp::path("message"))
         .and(warp::path::end())
         .and(warp::post())
+        .and(warp::body::content_length_limit(16 * 1024))
         .and(warp::body::json())
-        .and_then(move |body: ApiScriptureGoLiveRequest| {
-            let state_clone = api_scripture_state.clone();
-            let app_clone = api_app.clone();
+        .and_then(move |body: ApiStageMessageRequest| {
+            let state_clone = stage_message_post_state.clone();
+            let app_clone = stage_message_post_app.clone();
             async move {
                 if !*state_clone.api_enabled.read().await {
                     return Ok::<_, warp::Rejection>(json_response(
@@ -815,166 +3460,343 @@ async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), Str
                         StatusCode::FORBIDDEN,
                     ));
                 }
+                let response = match send_stage_message_impl(&state_clone, &app_clone, body).await {
+                    Ok(message) => json_response(serde_json::json!({ "message": message }), StatusCode::OK),
+                    Err(err) if err == "text_required" => {
+                        json_response(serde_json::json!({ "error": err }), StatusCode::BAD_REQUEST)
+                    }
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::CONFLICT),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
 
-                let reference = body.reference.trim().to_string();
-                if reference.is_empty() {
+    // POST /api/v1/stage/message/clear (DELETE /api/v1/stage/message does the same)
+    let stage_message_clear_state = state.clone();
+    let stage_message_clear_app = app.clone();
+    let api_stage_message_clear_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("stage"))
+        .and(warp::path("message"))
+        .and(
+            warp::path("clear")
+                .and(warp::path::end())
+                .and(warp::post())
+                .or(warp::path::end().and(warp::delete()))
+                .unify(),
+        )
+        .and_then(move || {
+            let state_clone = stage_message_clear_state.clone();
+            let app_clone = stage_message_clear_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
                     return Ok::<_, warp::Rejection>(json_response(
-                        serde_json::json!({ "error": "reference_required" }),
-                        StatusCode::BAD_REQUEST,
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
                     ));
                 }
+                clear_stage_message_impl(&state_clone, &app_clone).await;
+                Ok::<_, warp::Rejection>(json_response(
+                    serde_json::json!({ "status": "cleared" }),
+                    StatusCode::OK,
+                ))
+            }
+        });
 
-                let translation = body
-                    .translation
-                    .as_deref()
-                    .unwrap_or("")
-                    .trim()
-                    .to_string();
-                let translation = if translation.is_empty() {
-                    None
-                } else {
-                    Some(translation)
-                };
+    let api_stage_message_routes = api_stage_message_get_route
+        .or(api_stage_message_post_route)
+        .or(api_stage_message_clear_route);
 
-                if let Err(err) = app_clone.emit(
-                    "api-scripture-go-live",
-                    serde_json::json!({
-                        "reference": reference,
-                        "translation": translation,
-                    }),
-                ) {
+    // API v1: What a display output is showing, with its settings (?output=, default "main")
+    let display_get_state = state.clone();
+    let api_display_get_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("display"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |query: HashMap<String, String>| {
+            let state_clone = display_get_state.clone();
+            async move {
+                let output = match resolve_display_output(query.get("output").map(String::as_str)) {
+                    Ok(output) => output,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+                let Some(display_state) = state_clone.display_outputs.read().await.get(&output).cloned() else {
                     return Ok::<_, warp::Rejection>(json_response(
-                        serde_json::json!({
-                            "error": "emit_failed",
-                            "detail": err.to_string()
-                        }),
-                        StatusCode::INTERNAL_SERVER_ERROR,
+                        serde_json::json!({ "error": format!("display_output_not_found:{}", output) }),
+                        StatusCode::NOT_FOUND,
                     ));
-                }
-
+                };
                 Ok::<_, warp::Rejection>(json_response(
                     serde_json::json!({
-                        "status": "queued",
-                        "reference": reference
+                        "output": output,
+                        "scripture": display_state.scripture,
+                        "slides": display_state.slides,
+                        "settings": display_state.settings,
                     }),
                     StatusCode::OK,
                 ))
             }
         });
 
-    // API v1: Timer start
-    let api_timer_state = state.clone();
-    let api_timer_app = app.clone();
-    let api_timer_route = warp::path("api")
+    let display_outputs_state = state.clone();
+    let api_display_outputs_route = warp::path("api")
         .and(warp::path("v1"))
-        .and(warp::path("timer"))
-        .and(warp::path("start"))
+        .and(warp::path("display"))
+        .and(warp::path("outputs"))
         .and(warp::path::end())
-        .and(warp::post())
-        .and(warp::body::json())
-        .and_then(move |body: ApiTimerStartRequest| {
-            let state_clone = api_timer_state.clone();
-            let app_clone = api_timer_app.clone();
+        .and(warp::get())
+        .and_then(move || {
+            let state_clone = display_outputs_state.clone();
             async move {
-                if !*state_clone.api_enabled.read().await {
-                    return Ok::<_, warp::Rejection>(json_response(
-                        serde_json::json!({ "error": "api_disabled" }),
-                        StatusCode::FORBIDDEN,
-                    ));
-                }
+                let mut outputs: Vec<String> = state_clone.display_outputs.read().await.keys().cloned().collect();
+                outputs.sort_by_key(|id| (id != MAIN_DISPLAY_OUTPUT, id.clone()));
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!({ "outputs": outputs }), StatusCode::OK))
+            }
+        });
 
-                let raw_seconds = body
-                    .seconds
-                    .or_else(|| body.minutes.map(|m| m * 60.0))
-                    .unwrap_or(0.0);
+    // GET /api/v1/display/snapshot.png and snapshot.svg (?output=&width=&height=)
+    let display_snapshot_state = state.clone();
+    let api_display_snapshot_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("display"))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |file: String, query: HashMap<String, String>| {
+            let state_clone = display_snapshot_state.clone();
+            async move {
+                let format = match file.as_str() {
+                    "snapshot.png" => SnapshotFormat::Png,
+                    "snapshot.svg" => SnapshotFormat::Svg,
+                    _ => return Err(warp::reject::not_found()),
+                };
+                let dimension = |key: &str| query.get(key).and_then(|v| v.parse::<u32>().ok());
+                let response = match render_display_snapshot_impl(
+                    &state_clone,
+                    query.get("output").map(String::as_str),
+                    dimension("width"),
+                    dimension("height"),
+                    format,
+                )
+                .await
+                {
+                    Ok(data) => warp::http::Response::builder()
+                        .header("Content-Type", format.mime_type())
+                        .header("Cache-Control", "no-store")
+                        .header("Access-Control-Allow-Origin", "*")
+                        .body(data)
+                        .unwrap(),
+                    Err(err) => {
+                        let status = if err.starts_with("display_output_not_found") {
+                            StatusCode::NOT_FOUND
+                        } else if err.starts_with("invalid_display_output") {
+                            StatusCode::BAD_REQUEST
+                        } else {
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        };
+                        warp::http::Response::builder()
+                            .status(status)
+                            .header("Content-Type", "application/json")
+                            .header("Access-Control-Allow-Origin", "*")
+                            .body(serde_json::json!({ "error": err }).to_string().into_bytes())
+                            .unwrap()
+                    }
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
 
-                if !raw_seconds.is_finite() || raw_seconds <= 0.0 {
-                    return Ok::<_, warp::Rejection>(json_response(
-                        serde_json::json!({ "error": "seconds_required" }),
-                        StatusCode::BAD_REQUEST,
-                    ));
+    // GET /api/v1/display/history?from=&to= (epoch ms, RFC 3339 or YYYY-MM-DD); &format=csv downloads it
+    let display_history_state = state.clone();
+    let api_display_history_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("display"))
+        .and(warp::path("history"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |query: HashMap<String, String>| {
+            let state_clone = display_history_state.clone();
+            async move {
+                let range = display_history::parse_time_range(
+                    query.get("from").map(String::as_str),
+                    query.get("to").map(String::as_str),
+                );
+                let (from, to) = match range {
+                    Ok(range) => range,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+                let entries = match query_display_history(&state_clone, from, to).await {
+                    Ok(entries) => entries,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                        ));
+                    }
+                };
+
+                if query.get("format").is_some_and(|f| f.eq_ignore_ascii_case("csv")) {
+                    let response = warp::http::Response::builder()
+                        .header("Content-Type", "text/csv; charset=utf-8")
+                        .header(
+                            "Content-Disposition",
+                            "attachment; filename=\"display-history.csv\"",
+                        )
+                        .header("Access-Control-Allow-Origin", "*")
+                        .body(warp::hyper::Body::from(display_history::export_csv(&entries)))
+                        .unwrap()
+                        .into_response();
+                    return Ok::<_, warp::Rejection>(response);
                 }
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!({ "entries": entries }), StatusCode::OK))
+            }
+        });
 
-                let seconds = raw_seconds.floor() as i64;
-                if seconds <= 0 {
+    // PUT /api/v1/display?output=: create or update an output (main included)
+    let display_put_state = state.clone();
+    let display_put_app = app.clone();
+    let api_display_put_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("display"))
+        .and(warp::path::end())
+        .and(warp::put())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::body::content_length_limit(16 * 1024 * 1024))
+        .and(warp::body::json())
+        .and_then(move |query: HashMap<String, String>, body: ApiDisplayStateRequest| {
+            let state_clone = display_put_state.clone();
+            let app_clone = display_put_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
                     return Ok::<_, warp::Rejection>(json_response(
-                        serde_json::json!({ "error": "seconds_required" }),
-                        StatusCode::BAD_REQUEST,
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
                     ));
                 }
-
-                if let Err(err) = app_clone.emit(
-                    "api-timer-start",
-                    serde_json::json!({ "seconds": seconds }),
-                ) {
-                    return Ok::<_, warp::Rejection>(json_response(
+                let output = query.get("output").map(String::as_str);
+                let response = match put_display_state_impl(&state_clone, &app_clone, output, body).await {
+                    Ok((output, display_state)) => json_response(
                         serde_json::json!({
-                            "error": "emit_failed",
-                            "detail": err.to_string()
+                            "output": output,
+                            "scripture": display_state.scripture,
+                            "slides": display_state.slides,
+                            "settings": display_state.settings,
                         }),
-                        StatusCode::INTERNAL_SERVER_ERROR,
+                        StatusCode::OK,
+                    ),
+                    Err(err) => json_response(serde_json::json!({ "error": err }), StatusCode::BAD_REQUEST),
+                };
+                Ok::<_, warp::Rejection>(response)
+            }
+        });
+
+    let display_delete_state = state.clone();
+    let display_delete_app = app.clone();
+    let api_display_delete_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("display"))
+        .and(warp::path::end())
+        .and(warp::delete())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |query: HashMap<String, String>| {
+            let state_clone = display_delete_state.clone();
+            let app_clone = display_delete_app.clone();
+            async move {
+                if !*state_clone.api_enabled.read().await {
+                    return Ok::<_, warp::Rejection>(json_response(
+                        serde_json::json!({ "error": "api_disabled" }),
+                        StatusCode::FORBIDDEN,
                     ));
                 }
-
-                Ok::<_, warp::Rejection>(json_response(
-                    serde_json::json!({
-                        "status": "queued",
-                        "seconds": seconds
-                    }),
-                    StatusCode::OK,
-                ))
+                let output = query.get("output").map(String::as_str).unwrap_or_default();
+                let response = match remove_display_output_impl(&state_clone, &app_clone, output).await {
+                    Ok(()) => json_response(serde_json::json!({ "status": "removed" }), StatusCode::OK),
+                    Err(err) => {
+                        let status = if err.starts_with("display_output_not_found") {
+                            StatusCode::NOT_FOUND
+                        } else {
+                            StatusCode::BAD_REQUEST
+                        };
+                        json_response(serde_json::json!({ "error": err }), status)
+                    }
+                };
+                Ok::<_, warp::Rejection>(response)
             }
         });
 
-    // Helper macro to create simple event-emitting API routes
-    macro_rules! create_event_route {
-        ($state:expr, $app:expr, $($segment:expr),+ => $event:expr) => {
-            {
-                let state_clone = $state.clone();
-                let app_clone = $app.clone();
-                let event_name = $event;
-                warp::path("api")
-                    $(.and(warp::path($segment)))+
-                    .and(warp::path::end())
-                    .and(warp::post())
-                    .and_then(move || {
-                        let state_inner = state_clone.clone();
-                        let app_inner = app_clone.clone();
-                        let event = event_name;
-                        async move {
-                            if !*state_inner.api_enabled.read().await {
-                                return Ok::<_, warp::Rejection>(json_response(
-                                    serde_json::json!({ "error": "api_disabled" }),
-                                    StatusCode::FORBIDDEN,
-                                ));
-                            }
-
-                            if let Err(err) = app_inner.emit(event, serde_json::json!({})) {
-                                return Ok::<_, warp::Rejection>(json_response(
-                                    serde_json::json!({
-                                        "error": "emit_failed",
-                                        "detail": err.to_string()
-                                    }),
-                                    StatusCode::INTERNAL_SERVER_ERROR,
-                                ));
-                            }
+    let api_display_routes = api_display_get_route
+        .or(api_display_put_route)
+        .or(api_display_delete_route)
+        .or(api_display_outputs_route)
+        .or(api_display_history_route)
+        .or(api_display_snapshot_route);
 
-                            Ok::<_, warp::Rejection>(json_response(
-                                serde_json::json!({ "status": "queued" }),
-                                StatusCode::OK,
-                            ))
-                        }
-                    })
+    // GET /api/v1/ccli/report?from=&to= (same formats as display history); &format=csv downloads it
+    let ccli_report_state = state.clone();
+    let api_ccli_report_route = warp::path("api")
+        .and(warp::path("v1"))
+        .and(warp::path("ccli"))
+        .and(warp::path("report"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(move |query: HashMap<String, String>| {
+            let state_clone = ccli_report_state.clone();
+            async move {
+                let range = display_history::parse_time_range(
+                    query.get("from").map(String::as_str),
+                    query.get("to").map(String::as_str),
+                );
+                let (from, to) = match range {
+                    Ok(range) => range,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::BAD_REQUEST,
+                        ));
+                    }
+                };
+                let entries = match query_display_history(&state_clone, from, to).await {
+                    Ok(entries) => entries,
+                    Err(err) => {
+                        return Ok::<_, warp::Rejection>(json_response(
+                            serde_json::json!({ "error": err }),
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                        ));
+                    }
+                };
+                let report = ccli_report::build_report(&entries, from, to);
+
+                if query.get("format").is_some_and(|f| f.eq_ignore_ascii_case("csv")) {
+                    let response = warp::http::Response::builder()
+                        .header("Content-Type", "text/csv; charset=utf-8")
+                        .header(
+                            "Content-Disposition",
+                            "attachment; filename=\"ccli-usage-report.csv\"",
+                        )
+                        .header("Access-Control-Allow-Origin", "*")
+                        .body(warp::hyper::Body::from(ccli_report::export_csv(&report)))
+                        .unwrap()
+                        .into_response();
+                    return Ok::<_, warp::Rejection>(response);
+                }
+                Ok::<_, warp::Rejection>(json_response(serde_json::json!(report), StatusCode::OK))
             }
-        };
-    }
-
-    // API v1: Timer stop
-    let api_timer_stop_route = create_event_route!(
-        state,
-        app,
-        "v1", "timer", "stop" => "api-timer-stop"
-    );
+        });
 
     // API v1: Video recording start/stop
     let api_video_recording_start_route = create_event_route!(
@@ -1091,6 +3913,30 @@ async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), Str
             }
         });
 
+    // Overlay routes - transparent pages for OBS/vMix browser sources
+    // (/overlay/scripture, /overlay/speaker, /overlay/timer; themed via query)
+    let overlay_route = warp::path("overlay")
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(|kind: String| async move {
+            if !matches!(kind.as_str(), "scripture" | "speaker" | "timer") {
+                return Err(warp::reject::not_found());
+            }
+            let response = match serve_embedded_file("overlay.html") {
+                Some((content, _)) => warp::http::Response::builder()
+                    .header("Content-Type", "text/html")
+                    .header("Access-Control-Allow-Origin", "*")
+                    .body(content)
+                    .unwrap(),
+                None => warp::http::Response::builder()
+                    .status(404)
+                    .body(b"Overlay page not found".to_vec())
+                    .unwrap(),
+            };
+            Ok::<_, warp::Rejection>(response)
+        });
+
     // Display route - serve display.html for web audience display
     let display_route = warp::path("display")
         .and(warp::path::end())
@@ -1167,18 +4013,29 @@ async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), Str
     // CORS headers for all routes
     let cors = warp::cors()
         .allow_any_origin()
-        .allow_methods(vec!["GET", "POST", "OPTIONS"])
+        .allow_methods(vec!["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"])
         .allow_headers(vec!["Content-Type"]);
     
     // Combine routes: WebSocket first, then APIs, then schedule view, then static files
     let routes = ws_route
         .or(schedule_api_route)
         .or(live_slides_api_route)
+        .or(live_slides_revisions_route)
+        .or(live_slides_revisions_diff_route)
+        .or(live_slides_revisions_restore_route)
+        .or(live_slides_export_route)
+        .or(api_live_slides_sessions_route)
+        .or(api_live_slides_cursor_routes)
         .or(api_playlists_route)
         .or(transcription_pin_route)
         .or(api_scripture_route)
         .or(api_timer_route)
         .or(api_timer_stop_route)
+        .or(api_timer_extra_routes)
+        .or(api_schedule_v1_routes)
+        .or(api_stage_message_routes)
+        .or(api_display_routes)
+        .or(api_ccli_report_route)
         .or(api_video_recording_start_route)
         .or(api_video_recording_stop_route)
         .or(api_audio_recording_start_route)
@@ -1188,6 +4045,7 @@ async fn run_combined_server(port: u16, app: tauri::AppHandle) -> Result<(), Str
         .or(schedule_view_route)
         .or(live_slides_landing_route)
         .or(display_route)
+        .or(overlay_route)
         .or(root_route)
         .or(static_route)
         .with(cors);
@@ -1383,65 +4241,16 @@ fn toggle_devtools(window: tauri::WebviewWindow) -> Result<(), String> {
     Ok(())
 }
 
-// Safe monitor information structure for serialization
-// Note: workArea is not included as it's not available via the Monitor API
-// and was causing serialization errors when it was None
-#[derive(Debug, Clone, Serialize)]
-struct SafeMonitorInfo {
-    name: Option<String>,
-    position: (i32, i32),
-    size: (u32, u32),
-    scale_factor: f64,
-}
-
-// Get available monitors safely (avoids workArea serialization issues)
+// Get available monitors safely (avoids workArea serialization issues), in
+// the order monitor indexes refer to
 #[tauri::command]
 fn get_available_monitors_safe(
     window: tauri::WebviewWindow,
 ) -> Result<Vec<SafeMonitorInfo>, String> {
     match window.available_monitors() {
         Ok(mut monitors) => {
-            monitors.sort_by(|a, b| {
-                let a_pos = a.position();
-                let b_pos = b.position();
-                let a_primary = a_pos.x == 0 && a_pos.y == 0;
-                let b_primary = b_pos.x == 0 && b_pos.y == 0;
-                if a_primary != b_primary {
-                    return if a_primary { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater };
-                }
-                if a_pos.x != b_pos.x {
-                    return a_pos.x.cmp(&b_pos.x);
-                }
-                if a_pos.y != b_pos.y {
-                    return a_pos.y.cmp(&b_pos.y);
-                }
-                let a_size = a.size();
-                let b_size = b.size();
-                if a_size.width != b_size.width {
-                    return a_size.width.cmp(&b_size.width);
-                }
-                if a_size.height != b_size.height {
-                    return a_size.height.cmp(&b_size.height);
-                }
-                let a_name = a.name().map(|s| s.as_str()).unwrap_or("");
-                let b_name = b.name().map(|s| s.as_str()).unwrap_or("");
-                a_name.cmp(&b_name)
-            });
-
-            let mut safe_monitors = Vec::new();
-            for monitor in monitors {
-                let pos = monitor.position();
-                let size = monitor.size();
-                let scale = monitor.scale_factor();
-                
-                safe_monitors.push(SafeMonitorInfo {
-                    name: monitor.name().map(|s| s.clone()),
-                    position: (pos.x, pos.y),
-                    size: (size.width, size.height),
-                    scale_factor: scale,
-                });
-            }
-            Ok(safe_monitors)
+            monitors::sort_monitors(&mut monitors);
+            Ok(monitors.iter().map(SafeMonitorInfo::from_monitor).collect())
         }
         Err(e) => {
             eprintln!("[Display] Failed to get monitors: {:?}", e);
@@ -1450,19 +4259,61 @@ fn get_available_monitors_safe(
     }
 }
 
-// Create or show the audience display window
+// Create or show the window for a display output ("main" when not given).
+// `monitor` (as returned by get_available_monitors_safe) survives monitors
+// being rearranged and takes precedence over `monitor_index`.
 #[tauri::command]
 fn open_audience_display_window(
     app_handle: tauri::AppHandle,
     parent_window: tauri::WebviewWindow,
     monitor_index: Option<usize>,
+    output: Option<String>,
+    monitor: Option<SafeMonitorInfo>,
+) -> Result<(), String> {
+    open_audience_display_window_impl(&app_handle, &parent_window, monitor_index, monitor.as_ref(), output)
+}
+
+fn open_audience_display_window_impl(
+    app_handle: &tauri::AppHandle,
+    parent_window: &tauri::WebviewWindow,
+    monitor_index: Option<usize>,
+    monitor: Option<&SafeMonitorInfo>,
+    output: Option<String>,
 ) -> Result<(), String> {
     use tauri::{Manager, WebviewWindowBuilder};
 
-    const WINDOW_LABEL: &str = "audience-display";
+    let output = resolve_display_output(output.as_deref())?;
+    let window_label = display_output_window_label(&output);
+    let (window_url, window_title) = if output == MAIN_DISPLAY_OUTPUT {
+        ("/audience-display".to_string(), "Audience Display".to_string())
+    } else {
+        (format!("/audience-display?output={}", output), format!("Audience Display ({})", output))
+    };
 
     // Check if window already exists
-    if let Some(existing_window) = app_handle.get_webview_window(WINDOW_LABEL) {
+    if let Some(existing_window) = app_handle.get_webview_window(&window_label) {
+        // Asked for a specific monitor: move the open window there, covering it
+        // the way a newly opened window would.
+        if monitor_index.is_some() || monitor.is_some() {
+            let mut monitors = parent_window.available_monitors().map_err(|e| e.to_string())?;
+            match monitors::select_monitor(&mut monitors, monitor_index, monitor) {
+                Some(target) => {
+                    monitor_watcher::move_window_to(&existing_window, target, true)?;
+                    monitor_watcher::remember_window_monitor(&window_label, target, true);
+                    if let Err(e) = existing_window.set_decorations(false) {
+                        eprintln!("[Display] Failed to remove window decorations: {:?}", e);
+                    }
+                    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+                    {
+                        if let Err(e) = existing_window.maximize() {
+                            eprintln!("[Display] Failed to maximize window: {:?}", e);
+                        }
+                    }
+                }
+                None => eprintln!("[Display] Monitor {:?} not found, leaving window in place", monitor_index),
+            }
+        }
+
         // Window exists, avoid focusing on macOS to prevent Space jumps.
         #[cfg(not(target_os = "macos"))]
         {
@@ -1494,40 +4345,16 @@ fn open_audience_display_window(
         }
     };
 
+    // The hot-plug watcher only restores windows placed on a specific monitor.
+    monitor_watcher::forget_window_monitor(&window_label);
+
     // Determine window position and size
     #[allow(unused_variables)]
-    let (new_x, new_y, width, height, is_fullscreen, physical_x, physical_y, physical_width, physical_height) = if let Some(index) = monitor_index {
-        // Try to find the monitor by index
+    let (new_x, new_y, width, height, is_fullscreen, physical_x, physical_y, physical_width, physical_height) = if monitor_index.is_some() || monitor.is_some() {
+        // Resolve the monitor by fingerprint, or by index into the sorted list
         match parent_window.available_monitors() {
             Ok(mut monitors) => {
-                monitors.sort_by(|a, b| {
-                    let a_pos = a.position();
-                    let b_pos = b.position();
-                    let a_primary = a_pos.x == 0 && a_pos.y == 0;
-                    let b_primary = b_pos.x == 0 && b_pos.y == 0;
-                    if a_primary != b_primary {
-                        return if a_primary { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater };
-                    }
-                    if a_pos.x != b_pos.x {
-                        return a_pos.x.cmp(&b_pos.x);
-                    }
-                    if a_pos.y != b_pos.y {
-                        return a_pos.y.cmp(&b_pos.y);
-                    }
-                    let a_size = a.size();
-                    let b_size = b.size();
-                    if a_size.width != b_size.width {
-                        return a_size.width.cmp(&b_size.width);
-                    }
-                    if a_size.height != b_size.height {
-                        return a_size.height.cmp(&b_size.height);
-                    }
-                    let a_name = a.name().map(|s| s.as_str()).unwrap_or("");
-                    let b_name = b.name().map(|s| s.as_str()).unwrap_or("");
-                    a_name.cmp(&b_name)
-                });
-
-                if let Some(monitor) = monitors.get(index) {
+                if let Some(monitor) = monitors::select_monitor(&mut monitors, monitor_index, monitor) {
                     let pos = monitor.position();
                     let size = monitor.size();
                     let scale = monitor.scale_factor();
@@ -1536,9 +4363,10 @@ fn open_audience_display_window(
                     let logical_width = size.width as f64 / scale;
                     let logical_height = size.height as f64 / scale;
                     println!(
-                        "[Display] Opening on monitor {}: pos=({},{}), size={}x{}, scale={}",
-                        index, pos.x, pos.y, size.width, size.height, scale
+                        "[Display] Opening on monitor {:?}: pos=({},{}), size={}x{}, scale={}",
+                        monitor.name(), pos.x, pos.y, size.width, size.height, scale
                     );
+                    monitor_watcher::remember_window_monitor(&window_label, monitor, true);
                     (
                         logical_x,
                         logical_y,
@@ -1551,7 +4379,7 @@ fn open_audience_display_window(
                         size.height,
                     )
                 } else {
-                    eprintln!("[Display] Monitor index {} not found, falling back to offset", index);
+                    eprintln!("[Display] Monitor {:?} not found, falling back to offset", monitor_index);
                     let (x, y, phys_x, phys_y) = get_offset_position();
                     (x, y, 1200.0, 800.0, false, phys_x, phys_y, 1200, 800)
                 }
@@ -1569,11 +4397,11 @@ fn open_audience_display_window(
 
     let build_window = || {
         let window_builder = WebviewWindowBuilder::new(
-            &app_handle,
-            WINDOW_LABEL,
-            tauri::WebviewUrl::App("/audience-display".into())
+            app_handle,
+            &window_label,
+            tauri::WebviewUrl::App(window_url.clone().into())
         )
-            .title("Audience Display")
+            .title(&window_title)
             .decorations(!is_fullscreen) // No decorations if fullscreen
             .min_inner_size(800.0, 600.0)
             .resizable(true)
@@ -1602,7 +4430,7 @@ fn open_audience_display_window(
         if is_fullscreen {
             window_builder
         } else {
-            match window_builder.parent(&parent_window) {
+            match window_builder.parent(parent_window) {
                 Ok(builder) => builder,
                 Err(e) => {
                     eprintln!("[Display] Warning: Could not set parent window: {:?}", e);
@@ -1681,32 +4509,7 @@ fn show_monitor_identify_window(
         .available_monitors()
         .map_err(|e| format!("Failed to get monitors: {:?}", e))?;
 
-    monitors.sort_by(|a, b| {
-        let a_pos = a.position();
-        let b_pos = b.position();
-        let a_primary = a_pos.x == 0 && a_pos.y == 0;
-        let b_primary = b_pos.x == 0 && b_pos.y == 0;
-        if a_primary != b_primary {
-            return if a_primary { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater };
-        }
-        if a_pos.x != b_pos.x {
-            return a_pos.x.cmp(&b_pos.x);
-        }
-        if a_pos.y != b_pos.y {
-            return a_pos.y.cmp(&b_pos.y);
-        }
-        let a_size = a.size();
-        let b_size = b.size();
-        if a_size.width != b_size.width {
-            return a_size.width.cmp(&b_size.width);
-        }
-        if a_size.height != b_size.height {
-            return a_size.height.cmp(&b_size.height);
-        }
-        let a_name = a.name().map(|s| s.as_str()).unwrap_or("");
-        let b_name = b.name().map(|s| s.as_str()).unwrap_or("");
-        a_name.cmp(&b_name)
-    });
+    monitors::sort_monitors(&mut monitors);
 
     let monitor = monitors
         .get(monitor_index)
@@ -1792,6 +4595,132 @@ fn hide_monitor_identify_window(app_handle: tauri::AppHandle) -> Result<(), Stri
     Ok(())
 }
 
+// ============================================================================
+// Display Layout Profiles (named output-to-monitor arrangements)
+// ============================================================================
+
+fn layout_profiles_path(app_handle: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("app_data_dir_failed:{}", e))?;
+    Ok(dir.join(monitors::LAYOUT_PROFILES_FILE_NAME))
+}
+
+#[tauri::command]
+fn list_display_layout_profiles(app_handle: tauri::AppHandle) -> Result<Vec<DisplayLayoutProfile>, String> {
+    Ok(monitors::LayoutProfileStore::load(&layout_profiles_path(&app_handle)?)?.profiles)
+}
+
+/// Save (or replace, by name) a profile mapping display outputs to monitors.
+#[tauri::command]
+fn save_display_layout_profile(
+    app_handle: tauri::AppHandle,
+    name: String,
+    outputs: Vec<LayoutProfileOutput>,
+) -> Result<DisplayLayoutProfile, String> {
+    let mut normalized: Vec<LayoutProfileOutput> = Vec::with_capacity(outputs.len());
+    for entry in outputs {
+        let output = resolve_display_output(Some(&entry.output))?;
+        if normalized.iter().any(|e| e.output == output) {
+            return Err(format!("duplicate_layout_output:{}", output));
+        }
+        let mut monitor = entry.monitor;
+        monitor.fingerprint = monitors::fingerprint(&monitor);
+        normalized.push(LayoutProfileOutput { output, monitor });
+    }
+
+    let path = layout_profiles_path(&app_handle)?;
+    let mut store = monitors::LayoutProfileStore::load(&path)?;
+    let profile = store.upsert(DisplayLayoutProfile {
+        name,
+        outputs: normalized,
+        updated_at: now_millis(),
+    })?;
+    store.save(&path)?;
+    Ok(profile)
+}
+
+#[tauri::command]
+fn delete_display_layout_profile(app_handle: tauri::AppHandle, name: String) -> Result<(), String> {
+    let path = layout_profiles_path(&app_handle)?;
+    let mut store = monitors::LayoutProfileStore::load(&path)?;
+    store.remove(&name)?;
+    store.save(&path)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LayoutProfileApplyResult {
+    output: String,
+    status: String, // "opened" | "moved" | "unchanged" | "monitor_missing" | "failed"
+    monitor: Option<SafeMonitorInfo>,
+    error: Option<String>,
+}
+
+/// Open or move each output's window onto its monitor from a saved profile.
+/// Outputs whose monitor isn't connected are reported as `monitor_missing`.
+#[tauri::command]
+async fn apply_display_layout_profile(
+    app_handle: tauri::AppHandle,
+    parent_window: tauri::WebviewWindow,
+    name: String,
+) -> Result<Vec<LayoutProfileApplyResult>, String> {
+    let store = monitors::LayoutProfileStore::load(&layout_profiles_path(&app_handle)?)?;
+    let profile = store
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("layout_profile_not_found:{}", name))?;
+
+    {
+        // Outputs that have not received content yet still get a window.
+        let mut outputs = SERVER_STATE.display_outputs.write().await;
+        for entry in &profile.outputs {
+            outputs.entry(entry.output.clone()).or_default();
+        }
+    }
+
+    let mut available = app_handle
+        .available_monitors()
+        .map_err(|e| format!("Failed to get monitors: {:?}", e))?;
+    monitors::sort_monitors(&mut available);
+
+    let mut results = Vec::with_capacity(profile.outputs.len());
+    for entry in &profile.outputs {
+        let result = |status: &str, monitor: Option<SafeMonitorInfo>, error: Option<String>| LayoutProfileApplyResult {
+            output: entry.output.clone(),
+            status: status.to_string(),
+            monitor,
+            error,
+        };
+        let Some(monitor) = monitors::resolve(&available, &entry.monitor) else {
+            results.push(result("monitor_missing", None, None));
+            continue;
+        };
+        let info = SafeMonitorInfo::from_monitor(monitor);
+        let label = display_output_window_label(&entry.output);
+
+        let outcome = match app_handle.get_webview_window(&label) {
+            Some(window) => monitor_watcher::move_window_to(&window, monitor, true).map(|moved| {
+                monitor_watcher::remember_window_monitor(&label, monitor, true);
+                if moved { "moved" } else { "unchanged" }
+            }),
+            None => open_audience_display_window_impl(
+                &app_handle,
+                &parent_window,
+                None,
+                Some(&info),
+                Some(entry.output.clone()),
+            )
+            .map(|_| "opened"),
+        };
+        results.push(match outcome {
+            Ok(status) => result(status, Some(info), None),
+            Err(e) => result("failed", Some(info), Some(e)),
+        });
+    }
+    Ok(results)
+}
+
 // ============================================================================
 // Audience Display Test Window (Fresh Flow)
 // ============================================================================
@@ -1892,12 +4821,22 @@ fn list_native_audio_input_devices() -> Result<Vec<NativeAudioInputDevice>, Stri
 }
 
 // ============================================================================
-// Native Audio Capture -> Frontend Streaming (base64 PCM16LE @ 16kHz mono)
+// Native Audio Capture -> Frontend Streaming (PCM16LE mono, 16kHz by default)
 // ============================================================================
 
+// Legacy event payload, used when no `on_chunk` channel is passed.
 #[derive(Debug, Clone, Serialize)]
 pub struct NativeAudioChunk {
     pub data_b64: String,
+    pub sample_rate: u32,
+}
+
+// Emitted as `native_audio_error` when a running capture stream or
+// recording fails (device unplugged, driver error).
+#[derive(Debug, Clone, Serialize)]
+pub struct NativeAudioError {
+    pub source: String, // "stream" or "recording"
+    pub message: String,
 }
 
 lazy_static::lazy_static! {
@@ -1910,50 +4849,6 @@ fn f32_to_i16_sample(x: f32) -> i16 {
     (clamped * i16::MAX as f32) as i16
 }
 
-struct LinearResampler {
-    step: f64,
-    pos: f64,
-    buf: Vec<f32>,
-}
-
-impl LinearResampler {
-    fn new(in_rate: u32, out_rate: u32) -> Self {
-        let step = (in_rate as f64) / (out_rate as f64);
-        Self {
-            step,
-            pos: 0.0,
-            buf: Vec::new(),
-        }
-    }
-
-    fn push_and_resample(&mut self, input: &[f32], output: &mut Vec<f32>) {
-        self.buf.extend_from_slice(input);
-        // Need at least 2 samples for interpolation
-        while (self.pos + 1.0) < (self.buf.len() as f64) {
-            let i0 = self.pos.floor() as usize;
-            let i1 = i0 + 1;
-            let frac = (self.pos - i0 as f64) as f32;
-            let s0 = self.buf[i0];
-            let s1 = self.buf[i1];
-            output.push(s0 + (s1 - s0) * frac);
-            self.pos += self.step;
-        }
-
-        // Drop consumed samples to keep buffer bounded, preserving fractional position
-        let consumed = self.pos.floor() as usize;
-        if consumed > 0 {
-            if consumed < self.buf.len() {
-                self.buf.drain(0..consumed);
-            } else {
-                // Clear buffer but preserve fractional position for continuity
-                self.buf.clear();
-            }
-            // Always subtract consumed to preserve fractional position
-            self.pos -= consumed as f64;
-        }
-    }
-}
-
 #[tauri::command]
 fn stop_native_audio_stream() -> Result<(), String> {
     if !NATIVE_AUDIO_RUNNING.load(Ordering::SeqCst) {
@@ -1970,50 +4865,49 @@ fn stop_native_audio_stream() -> Result<(), String> {
     Ok(())
 }
 
+/// Streams the input as mono PCM16LE, `chunk_ms` (20-1000, default 250) at a
+/// time at `sample_rate` (8k-48k, default 16k). Chunks go as raw bytes over
+/// `on_chunk` when given, otherwise as base64 `native_audio_chunk` events.
+/// Reports levels every `meter_interval_ms` (50-100, default 100) as
+/// `native_audio_level` events, and as `native_audio_level` messages to /ws
+/// clients that sent `join_audio_levels`.
+/// `channel` is "average" (default), "left", "right" or a 1-based channel number.
+/// `input` picks a supported device config instead of the default one.
+/// Returns once capture is running, or with the error that stopped it. There
+/// is no deadline: opening the device may wait on the microphone permission
+/// prompt (macOS) for as long as the user takes to answer it.
 #[tauri::command]
-fn start_native_audio_stream(app: tauri::AppHandle, device_id: Option<String>) -> Result<(), String> {
+#[allow(clippy::too_many_arguments)]
+async fn start_native_audio_stream(
+    app: tauri::AppHandle,
+    device_id: Option<String>,
+    meter_interval_ms: Option<u32>,
+    channel: Option<String>,
+    sample_rate: Option<u32>,
+    chunk_ms: Option<u32>,
+    input: Option<InputConfigRequest>,
+    on_chunk: Option<tauri::ipc::Channel<tauri::ipc::InvokeResponseBody>>,
+) -> Result<AudioStreamFormat, String> {
+    let channel_selection = ChannelSelection::parse(channel.as_deref().unwrap_or(""))?;
+
     // Stop any existing stream first
     let _ = stop_native_audio_stream();
 
-    let host = cpal::default_host();
-    let default_device = host
-        .default_input_device()
-        .ok_or_else(|| "no_default_input_device".to_string())?;
-
-    let mut selected: cpal::Device = default_device;
-    if let Some(id) = device_id {
-        if let Ok(iter) = host.input_devices() {
-            // Prefer matching by device name (stable), fall back to numeric index if provided.
-            let mut index_match: Option<cpal::Device> = None;
-            let mut name_match: Option<cpal::Device> = None;
-            for (idx, dev) in iter.enumerate() {
-                if let Ok(name) = dev.name() {
-                    if name == id {
-                        name_match = Some(dev);
-                        break;
-                    }
-                }
-                if index_match.is_none() && id.parse::<usize>().ok() == Some(idx) {
-                    index_match = Some(dev);
-                }
-            }
-            if let Some(dev) = name_match.or(index_match) {
-                selected = dev;
-            }
-        }
-    }
-
-    let config = selected
-        .default_input_config()
-        .map_err(|e| format!("default_input_config_failed:{}", e))?;
+    let selected = audio_capture::find_input_device(device_id.as_deref())?;
+    let config = audio_capture::select_input_config(&selected, &input.unwrap_or_default())?;
 
-    let sample_format = config.sample_format();
-    let stream_config: cpal::StreamConfig = config.into();
-    let in_rate = stream_config.sample_rate.0;
-    let channels = stream_config.channels as usize;
-    let out_rate: u32 = 16_000;
-    let chunk_ms: usize = 250;
-    let chunk_samples: usize = (out_rate as usize * chunk_ms) / 1000; // 4000 @ 16kHz
+    let in_rate = config.sample_rate().0;
+    let channels = config.channels() as usize;
+    let format = AudioStreamFormat::new(sample_rate, chunk_ms, on_chunk.is_some());
+    let out_rate = format.sample_rate;
+    let chunk_samples = format.chunk_samples;
+    println!(
+        "[native_audio] capturing {} Hz, {} ch, {} -> {} Hz mono",
+        in_rate,
+        channels,
+        config.sample_format(),
+        out_rate
+    );
 
     let stop_flag = std::sync::Arc::new(AtomicBool::new(false));
     if let Ok(mut guard) = NATIVE_AUDIO_STOP_FLAG.lock() {
@@ -2021,154 +4915,84 @@ fn start_native_audio_stream(app: tauri::AppHandle, device_id: Option<String>) -
     }
     NATIVE_AUDIO_RUNNING.store(true, Ordering::SeqCst);
 
+    // The stream has to live on the capture thread; it reports back whether
+    // it started so the caller gets the error instead of a silent stream.
+    let (started_tx, started_rx) = tokio::sync::oneshot::channel::<Result<(), String>>();
+    let thread_stop_flag = stop_flag.clone();
     std::thread::spawn(move || {
-        let emit_chunk: std::sync::Arc<dyn Fn(&[i16]) + Send + Sync> = {
-            let app_handle = app.clone();
-            std::sync::Arc::new(move |samples: &[i16]| {
-                let mut bytes: Vec<u8> = Vec::with_capacity(samples.len() * 2);
-                for s in samples {
-                    bytes.extend_from_slice(&s.to_le_bytes());
-                }
-                let data_b64 = base64::engine::general_purpose::STANDARD.encode(bytes);
-                let _ = app_handle.emit("native_audio_chunk", NativeAudioChunk { data_b64 });
-            })
+        let stop_flag = thread_stop_flag;
+        let emit_chunk: Box<dyn Fn(&[i16]) + Send> = match on_chunk {
+            Some(channel) => Box::new(move |samples: &[i16]| {
+                let bytes = audio_transport::encode_pcm16le(samples);
+                let _ = channel.send(tauri::ipc::InvokeResponseBody::Raw(bytes));
+            }),
+            None => {
+                let app_handle = app.clone();
+                Box::new(move |samples: &[i16]| {
+                    let data_b64 =
+                        base64::engine::general_purpose::STANDARD.encode(audio_transport::encode_pcm16le(samples));
+                    let _ = app_handle.emit(
+                        "native_audio_chunk",
+                        NativeAudioChunk {
+                            data_b64,
+                            sample_rate: out_rate,
+                        },
+                    );
+                })
+            }
         };
-
-        let stop_flag_loop = stop_flag.clone();
-
-        let build_stream_result = match sample_format {
-            cpal::SampleFormat::F32 => {
-                let stop_flag = stop_flag.clone();
-                let emit_chunk = emit_chunk.clone();
-                let mut resampler = LinearResampler::new(in_rate, out_rate);
-                let mut out_f32: Vec<f32> = Vec::with_capacity(chunk_samples * 2);
-                let mut chunk_i16: Vec<i16> = Vec::with_capacity(chunk_samples * 2);
-
-                selected.build_input_stream(
-                    &stream_config,
-                    move |data: &[f32], _| {
-                        if stop_flag.load(Ordering::SeqCst) {
-                            return;
-                        }
-
-                        // Downmix to mono f32
-                        let mut mono: Vec<f32> = Vec::with_capacity(data.len() / channels.max(1));
-                        if channels <= 1 {
-                            mono.extend_from_slice(data);
-                        } else {
-                            for frame in data.chunks(channels) {
-                                let sum: f32 = frame.iter().copied().sum();
-                                mono.push(sum / channels as f32);
-                            }
-                        }
-
-                        out_f32.clear();
-                        resampler.push_and_resample(&mono, &mut out_f32);
-                        for s in &out_f32 {
-                            chunk_i16.push(f32_to_i16_sample(*s));
-                            if chunk_i16.len() >= chunk_samples {
-                                (emit_chunk)(&chunk_i16[..chunk_samples]);
-                                chunk_i16.drain(0..chunk_samples);
-                            }
-                        }
-                    },
-                    |err| eprintln!("[native_audio] stream error: {}", err),
-                    None,
-                )
+        let level_app = app.clone();
+        let emit_level = move |level: AudioLevel| {
+            let _ = level_app.emit("native_audio_level", &level);
+            if SERVER_STATE.audio_level_tx.receiver_count() > 0 {
+                if let Ok(json) = serde_json::to_string(&WsMessage::NativeAudioLevel { level }) {
+                    let _ = SERVER_STATE.audio_level_tx.send(json);
+                }
             }
-            cpal::SampleFormat::I16 => {
-                let stop_flag = stop_flag.clone();
-                let emit_chunk = emit_chunk.clone();
-                let mut resampler = LinearResampler::new(in_rate, out_rate);
-                let mut out_f32: Vec<f32> = Vec::with_capacity(chunk_samples * 2);
-                let mut chunk_i16: Vec<i16> = Vec::with_capacity(chunk_samples * 2);
-
-                selected.build_input_stream(
-                    &stream_config,
-                    move |data: &[i16], _| {
-                        if stop_flag.load(Ordering::SeqCst) {
-                            return;
-                        }
-
-                        let mut mono: Vec<f32> = Vec::with_capacity(data.len() / channels.max(1));
-                        if channels <= 1 {
-                            mono.extend(data.iter().map(|s| *s as f32 / i16::MAX as f32));
-                        } else {
-                            for frame in data.chunks(channels) {
-                                let sum: f32 = frame
-                                    .iter()
-                                    .map(|s| *s as f32 / i16::MAX as f32)
-                                    .sum();
-                                mono.push(sum / channels as f32);
-                            }
-                        }
+        };
 
-                        out_f32.clear();
-                        resampler.push_and_resample(&mono, &mut out_f32);
-                        for s in &out_f32 {
-                            chunk_i16.push(f32_to_i16_sample(*s));
-                            if chunk_i16.len() >= chunk_samples {
-                                (emit_chunk)(&chunk_i16[..chunk_samples]);
-                                chunk_i16.drain(0..chunk_samples);
-                            }
-                        }
-                    },
-                    |err| eprintln!("[native_audio] stream error: {}", err),
-                    None,
-                )
+        let callback_stop_flag = stop_flag.clone();
+        let mut resampler = SincResampler::new(in_rate, out_rate);
+        let mut meter = LevelMeter::new(in_rate, channels as u16, meter_interval_ms);
+        let mut mono: Vec<f32> = Vec::new();
+        let mut out_f32: Vec<f32> = Vec::with_capacity(chunk_samples * 2);
+        let mut chunk_i16: Vec<i16> = Vec::with_capacity(chunk_samples * 2);
+        let on_data = move |data: &[f32]| {
+            if callback_stop_flag.load(Ordering::SeqCst) {
+                return;
             }
-            cpal::SampleFormat::U16 => {
-                let stop_flag = stop_flag.clone();
-                let emit_chunk = emit_chunk.clone();
-                let mut resampler = LinearResampler::new(in_rate, out_rate);
-                let mut out_f32: Vec<f32> = Vec::with_capacity(chunk_samples * 2);
-                let mut chunk_i16: Vec<i16> = Vec::with_capacity(chunk_samples * 2);
-
-                selected.build_input_stream(
-                    &stream_config,
-                    move |data: &[u16], _| {
-                        if stop_flag.load(Ordering::SeqCst) {
-                            return;
-                        }
 
-                        let mut mono: Vec<f32> = Vec::with_capacity(data.len() / channels.max(1));
-                        if channels <= 1 {
-                            mono.extend(
-                                data.iter()
-                                    .map(|s| (*s as f32 / u16::MAX as f32) * 2.0 - 1.0),
-                            );
-                        } else {
-                            for frame in data.chunks(channels) {
-                                let sum: f32 = frame
-                                    .iter()
-                                    .map(|s| (*s as f32 / u16::MAX as f32) * 2.0 - 1.0)
-                                    .sum();
-                                mono.push(sum / channels as f32);
-                            }
-                        }
+            meter.push(data, &emit_level);
+            select_channel(data, channels, channel_selection, &mut mono);
 
-                        out_f32.clear();
-                        resampler.push_and_resample(&mono, &mut out_f32);
-                        for s in &out_f32 {
-                            chunk_i16.push(f32_to_i16_sample(*s));
-                            if chunk_i16.len() >= chunk_samples {
-                                (emit_chunk)(&chunk_i16[..chunk_samples]);
-                                chunk_i16.drain(0..chunk_samples);
-                            }
-                        }
-                    },
-                    |err| eprintln!("[native_audio] stream error: {}", err),
-                    None,
-                )
+            out_f32.clear();
+            resampler.push_and_resample(&mono, &mut out_f32);
+            for s in &out_f32 {
+                chunk_i16.push(f32_to_i16_sample(*s));
+                if chunk_i16.len() >= chunk_samples {
+                    (emit_chunk)(&chunk_i16[..chunk_samples]);
+                    chunk_i16.drain(0..chunk_samples);
+                }
             }
-            _ => Err(cpal::BuildStreamError::StreamConfigNotSupported),
+        };
+        let error_app = app.clone();
+        let on_error = move |message: String| {
+            eprintln!("[native_audio] stream error: {}", message);
+            let _ = error_app.emit(
+                "native_audio_error",
+                NativeAudioError {
+                    source: "stream".to_string(),
+                    message,
+                },
+            );
         };
 
-        let stream = match build_stream_result {
+        let stream = match audio_capture::build_input_stream(&selected, &config, on_data, on_error) {
             Ok(s) => s,
             Err(e) => {
                 eprintln!("[native_audio] failed to build stream: {}", e);
                 NATIVE_AUDIO_RUNNING.store(false, Ordering::SeqCst);
+                let _ = started_tx.send(Err(e));
                 return;
             }
         };
@@ -2176,10 +5000,12 @@ fn start_native_audio_stream(app: tauri::AppHandle, device_id: Option<String>) -
         if let Err(e) = stream.play() {
             eprintln!("[native_audio] failed to play stream: {}", e);
             NATIVE_AUDIO_RUNNING.store(false, Ordering::SeqCst);
+            let _ = started_tx.send(Err(format!("play_failed:{}", e)));
             return;
         }
+        let _ = started_tx.send(Ok(()));
 
-        while !stop_flag_loop.load(Ordering::SeqCst) {
+        while !stop_flag.load(Ordering::SeqCst) {
             std::thread::sleep(std::time::Duration::from_millis(50));
         }
 
@@ -2188,7 +5014,20 @@ fn start_native_audio_stream(app: tauri::AppHandle, device_id: Option<String>) -
         NATIVE_AUDIO_RUNNING.store(false, Ordering::SeqCst);
     });
 
-    Ok(())
+    match started_rx.await {
+        Ok(Ok(())) => Ok(format),
+        Ok(Err(e)) => Err(e),
+        Err(_) => {
+            NATIVE_AUDIO_RUNNING.store(false, Ordering::SeqCst);
+            Err("capture_start_failed".to_string())
+        }
+    }
+}
+
+#[tauri::command]
+fn list_native_audio_input_configs(device_id: Option<String>) -> Result<Vec<NativeInputConfig>, String> {
+    let device = audio_capture::find_input_device(device_id.as_deref())?;
+    audio_capture::list_input_configs(&device)
 }
 
 // ============================================================================
@@ -2507,6 +5346,17 @@ fn asr_init(
     }
 }
 
+// Same as asr_push_audio with the chunk sent as a raw PCM16LE request body
+// (`invoke("asr_push_audio_raw", bytes)`), skipping the JSON number array.
+#[tauri::command]
+fn asr_push_audio_raw(request: tauri::ipc::Request<'_>) -> Result<(), String> {
+    let pcm_chunk = match request.body() {
+        tauri::ipc::InvokeBody::Raw(bytes) => audio_transport::decode_pcm16le(bytes)?,
+        tauri::ipc::InvokeBody::Json(_) => return Err("expected_raw_body".to_string()),
+    };
+    asr_push_audio(pcm_chunk)
+}
+
 #[tauri::command]
 fn asr_push_audio(pcm_chunk: Vec<i16>) -> Result<(), String> {
     #[cfg(target_os = "macos")]
@@ -2561,6 +5411,7 @@ lazy_static::lazy_static! {
     static ref AUDIO_RECORDING_STOP_FLAG: Mutex<Option<std::sync::Arc<AtomicBool>>> = Mutex::new(None);
     static ref AUDIO_RECORDING_START_TIME: Mutex<Option<std::time::Instant>> = Mutex::new(None);
     static ref AUDIO_RECORDING_FILE_PATH: Mutex<Option<String>> = Mutex::new(None);
+    static ref AUDIO_RECORDING_SAMPLE_RATE: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(48000);
     
     // Video streaming recording state - writes chunks to disk as they arrive
     static ref VIDEO_RECORDING_RUNNING: AtomicBool = AtomicBool::new(false);
@@ -2578,24 +5429,24 @@ lazy_static::lazy_static! {
     static ref WEB_AUDIO_RECORDING_BYTES_WRITTEN: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
 }
 
+/// Records the input to a mono 16-bit WAV at `sample_rate` (default 48k, at
+/// most the device rate). `input` picks a supported device config instead of
+/// the default one. Returns once capture is running, or with the error that
+/// stopped it; later stream errors are emitted as `native_audio_error`.
+// Like start_native_audio_stream, waits (without a deadline) for capture to start.
 #[tauri::command]
-fn start_native_audio_recording(
+async fn start_native_audio_recording(
+    app: tauri::AppHandle,
     device_id: Option<String>,
     file_path: String,
     sample_rate: Option<u32>,
     warmup_ms: Option<u32>,
+    input: Option<InputConfigRequest>,
 ) -> Result<(), String> {
     // Stop any existing recording first
     let _ = stop_native_audio_recording();
 
-    // Normalize file path (expand ~)
-    let mut normalized_path = file_path.trim().to_string();
-    if normalized_path.starts_with("~/") {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        normalized_path = format!("{}/{}", home.trim_end_matches('/'), &normalized_path[2..]);
-    }
+    let normalized_path = normalize_user_path(&file_path)?;
 
     // Create parent directories if needed
     if let Some(parent) = std::path::Path::new(&normalized_path).parent() {
@@ -2604,31 +5455,10 @@ fn start_native_audio_recording(
         }
     }
 
-    let host = cpal::default_host();
-    let default_device = host
-        .default_input_device()
-        .ok_or_else(|| "no_default_input_device".to_string())?;
-
-    let mut selected: cpal::Device = default_device;
-    if let Some(id) = device_id {
-        if let Ok(iter) = host.input_devices() {
-            for dev in iter {
-                if let Ok(name) = dev.name() {
-                    if name == id {
-                        selected = dev;
-                        break;
-                    }
-                }
-            }
-        }
-    }
-
-    // Get supported config - prefer high sample rate for quality
-    let supported_config = selected
-        .default_input_config()
-        .map_err(|e| format!("default_config_failed:{}", e))?;
+    let selected = audio_capture::find_input_device(device_id.as_deref())?;
+    let supported_config = audio_capture::select_input_config(&selected, &input.unwrap_or_default())?;
 
-    let channels = supported_config.channels();
+    let channels = supported_config.channels() as usize;
     let device_sample_rate = supported_config.sample_rate().0;
     let target_sample_rate = sample_rate.unwrap_or(48000).min(device_sample_rate);
     let warmup_ms = warmup_ms.unwrap_or(800) as u64;
@@ -2660,178 +5490,82 @@ fn start_native_audio_recording(
         let mut guard = AUDIO_RECORDING_FILE_PATH.lock().map_err(|_| "lock_failed")?;
         *guard = Some(normalized_path.clone());
     }
+    AUDIO_RECORDING_SAMPLE_RATE.store(target_sample_rate, Ordering::SeqCst);
 
     AUDIO_RECORDING_RUNNING.store(true, Ordering::SeqCst);
 
     // Spawn recording thread
     let wav_writer_clone = wav_writer.clone();
     let stop_flag_clone = stop_flag.clone();
+    let (started_tx, started_rx) = tokio::sync::oneshot::channel::<Result<(), String>>();
 
     std::thread::spawn(move || {
-        let stream_config: cpal::StreamConfig = supported_config.clone().into();
-
-        // Simple linear resampler for matching sample rates
-        let need_resample = device_sample_rate != target_sample_rate;
-        let resample_ratio = target_sample_rate as f64 / device_sample_rate as f64;
-
-        let stream = match supported_config.sample_format() {
-            cpal::SampleFormat::F32 => {
-                let stop_flag = stop_flag_clone.clone();
-                let wav_writer = wav_writer_clone.clone();
-                let mut resample_buffer: Vec<f32> = Vec::new();
-                let mut resample_pos: f64 = 0.0;
-                let mut skipped_frames: usize = 0;
-                // Flush counter for crash safety - flush every ~1 second of audio
-                let mut samples_since_flush: usize = 0;
-                let flush_interval: usize = target_sample_rate as usize; // ~1 second
-
-                selected.build_input_stream(
-                    &stream_config,
-                    move |data: &[f32], _| {
-                        if stop_flag.load(Ordering::SeqCst) {
-                            return;
-                        }
-
-                        // Discard initial frames to avoid device/AGC ramp-in.
-                        let mut start_idx = 0usize;
-                        let frames_in = data.len() / channels as usize;
-                        if skipped_frames < warmup_frames_device {
-                            let remaining = warmup_frames_device - skipped_frames;
-                            let to_skip = remaining.min(frames_in);
-                            skipped_frames += to_skip;
-                            start_idx = to_skip * channels as usize;
-                            if start_idx >= data.len() {
-                                return;
-                            }
-                            // reset resampler position after warmup boundary
-                            if skipped_frames == warmup_frames_device {
-                                resample_pos = 0.0;
-                            }
-                        }
-
-                        if let Ok(mut guard) = wav_writer.lock() {
-                            if let Some(ref mut writer) = *guard {
-                                if need_resample {
-                                    // Simple linear resampling
-                                    resample_buffer.clear();
-                                    let data = &data[start_idx..];
-                                    let samples_per_channel = data.len() / channels as usize;
-                                    while resample_pos < samples_per_channel as f64 {
-                                        let idx = resample_pos as usize;
-                                        let frac = resample_pos - idx as f64;
-                                        // Downmix to mono by averaging channels at each frame
-                                        let mut sum = 0.0f32;
-                                        for ch in 0..channels as usize {
-                                            let pos = idx * channels as usize + ch;
-                                            let next_pos = ((idx + 1) * channels as usize + ch).min(data.len() - 1);
-                                            let sample = data[pos] * (1.0 - frac as f32) + data[next_pos] * frac as f32;
-                                            sum += sample;
-                                        }
-                                        resample_buffer.push(sum / channels as f32);
-                                        resample_pos += 1.0 / resample_ratio;
-                                    }
-                                    resample_pos -= samples_per_channel as f64;
-                                    
-                                    for sample in &resample_buffer {
-                                        let i16_sample = (*sample * i16::MAX as f32).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
-                                        let _ = writer.write_sample(i16_sample);
-                                        samples_since_flush += 1;
-                                    }
-                                } else {
-                                    let data = &data[start_idx..];
-                                    // Downmix interleaved frames to mono
-                                    let mut i = 0usize;
-                                    while i + (channels as usize) <= data.len() {
-                                        let mut sum = 0.0f32;
-                                        for ch in 0..channels as usize {
-                                            sum += data[i + ch];
-                                        }
-                                        let mono = (sum / channels as f32)
-                                            .clamp(-1.0, 1.0);
-                                        let i16_sample = (mono * i16::MAX as f32).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
-                                        let _ = writer.write_sample(i16_sample);
-                                        samples_since_flush += 1;
-                                        i += channels as usize;
-                                    }
-                                }
+        let stop_flag = stop_flag_clone.clone();
+        let wav_writer = wav_writer_clone.clone();
+        let mut resampler = SincResampler::new(device_sample_rate, target_sample_rate);
+        let mut mono: Vec<f32> = Vec::new();
+        let mut resampled: Vec<f32> = Vec::new();
+        let mut skipped_frames: usize = 0;
+        // Flush counter for crash safety - flush every ~1 second of audio
+        let mut samples_since_flush: usize = 0;
+        let flush_interval: usize = target_sample_rate as usize; // ~1 second
+
+        let on_data = move |data: &[f32]| {
+            if stop_flag.load(Ordering::SeqCst) {
+                return;
+            }
 
-                                // Flush to disk periodically for crash safety
-                                // This ensures audio data is on disk even if app crashes
-                                if samples_since_flush >= flush_interval {
-                                    let _ = writer.flush();
-                                    samples_since_flush = 0;
-                                }
-                            }
-                        }
-                    },
-                    |err| eprintln!("[audio_recording] stream error: {}", err),
-                    None,
-                )
+            // Discard initial frames to avoid device/AGC ramp-in.
+            let mut start_idx = 0usize;
+            let frames_in = data.len() / channels;
+            if skipped_frames < warmup_frames_device {
+                let remaining = warmup_frames_device - skipped_frames;
+                let to_skip = remaining.min(frames_in);
+                skipped_frames += to_skip;
+                start_idx = to_skip * channels;
+                if start_idx >= data.len() {
+                    return;
+                }
             }
-            cpal::SampleFormat::I16 => {
-                let stop_flag = stop_flag_clone.clone();
-                let wav_writer = wav_writer_clone.clone();
-                let mut skipped_frames: usize = 0;
-                // Flush counter for crash safety - flush every ~1 second of audio
-                let mut samples_since_flush: usize = 0;
-                let flush_interval: usize = target_sample_rate as usize; // ~1 second
-
-                selected.build_input_stream(
-                    &stream_config,
-                    move |data: &[i16], _| {
-                        if stop_flag.load(Ordering::SeqCst) {
-                            return;
-                        }
 
-                        let mut start_idx = 0usize;
-                        let frames_in = data.len() / channels as usize;
-                        if skipped_frames < warmup_frames_device {
-                            let remaining = warmup_frames_device - skipped_frames;
-                            let to_skip = remaining.min(frames_in);
-                            skipped_frames += to_skip;
-                            start_idx = to_skip * channels as usize;
-                            if start_idx >= data.len() {
-                                return;
-                            }
-                        }
+            // Downmix interleaved frames to mono, then match the WAV rate
+            select_channel(&data[start_idx..], channels, ChannelSelection::Average, &mut mono);
+            resampled.clear();
+            resampler.push_and_resample(&mono, &mut resampled);
 
-                        if let Ok(mut guard) = wav_writer.lock() {
-                            if let Some(ref mut writer) = *guard {
-                                let data = &data[start_idx..];
-                                let mut i = 0usize;
-                                while i + (channels as usize) <= data.len() {
-                                    let mut sum: i32 = 0;
-                                    for ch in 0..channels as usize {
-                                        sum += data[i + ch] as i32;
-                                    }
-                                    let mono = (sum / channels as i32) as i16;
-                                    let _ = writer.write_sample(mono);
-                                    samples_since_flush += 1;
-                                    i += channels as usize;
-                                }
+            if let Ok(mut guard) = wav_writer.lock() {
+                if let Some(ref mut writer) = *guard {
+                    for sample in &resampled {
+                        let _ = writer.write_sample(f32_to_i16_sample(*sample));
+                        samples_since_flush += 1;
+                    }
 
-                                // Flush to disk periodically for crash safety
-                                if samples_since_flush >= flush_interval {
-                                    let _ = writer.flush();
-                                    samples_since_flush = 0;
-                                }
-                            }
-                        }
-                    },
-                    |err| eprintln!("[audio_recording] stream error: {}", err),
-                    None,
-                )
-            }
-            _ => {
-                return;
+                    // Flush to disk periodically for crash safety
+                    // This ensures audio data is on disk even if app crashes
+                    if samples_since_flush >= flush_interval {
+                        let _ = writer.flush();
+                        samples_since_flush = 0;
+                    }
+                }
             }
         };
+        let on_error = move |message: String| {
+            eprintln!("[audio_recording] stream error: {}", message);
+            let _ = app.emit(
+                "native_audio_error",
+                NativeAudioError {
+                    source: "recording".to_string(),
+                    message,
+                },
+            );
+        };
 
-        let stream = match stream {
+        let stream = match audio_capture::build_input_stream(&selected, &supported_config, on_data, on_error) {
             Ok(s) => s,
             Err(e) => {
-                eprintln!("[audio_recording] build_stream_failed: {}", e);
+                eprintln!("[audio_recording] {}", e);
                 AUDIO_RECORDING_RUNNING.store(false, Ordering::SeqCst);
+                let _ = started_tx.send(Err(e));
                 return;
             }
         };
@@ -2839,8 +5573,10 @@ fn start_native_audio_recording(
         if let Err(e) = stream.play() {
             eprintln!("[audio_recording] play_failed: {}", e);
             AUDIO_RECORDING_RUNNING.store(false, Ordering::SeqCst);
+            let _ = started_tx.send(Err(format!("play_failed:{}", e)));
             return;
         }
+        let _ = started_tx.send(Ok(()));
 
         // Wait for stop signal
         while !stop_flag_clone.load(Ordering::SeqCst) {
@@ -2858,6 +5594,26 @@ fn start_native_audio_recording(
         AUDIO_RECORDING_RUNNING.store(false, Ordering::SeqCst);
     });
 
+    // The capture thread only drops the sender without a result if it panicked.
+    let started = started_rx.await.unwrap_or_else(|_| Err("capture_start_failed".to_string()));
+    if let Err(e) = started {
+        // Nothing was recorded; don't leave an empty WAV or stale state behind.
+        if let Ok(mut guard) = wav_writer.lock() {
+            guard.take();
+        }
+        let _ = std::fs::remove_file(&normalized_path);
+        AUDIO_RECORDING_RUNNING.store(false, Ordering::SeqCst);
+        if let Ok(mut guard) = AUDIO_RECORDING_STOP_FLAG.lock() {
+            *guard = None;
+        }
+        if let Ok(mut guard) = AUDIO_RECORDING_START_TIME.lock() {
+            *guard = None;
+        }
+        if let Ok(mut guard) = AUDIO_RECORDING_FILE_PATH.lock() {
+            *guard = None;
+        }
+        return Err(e);
+    }
     Ok(())
 }
 
@@ -2904,8 +5660,8 @@ fn stop_native_audio_recording() -> Result<RecordingInfo, String> {
     Ok(RecordingInfo {
         file_path,
         duration_seconds,
-        sample_rate: 48000,
-        channels: 2,
+        sample_rate: AUDIO_RECORDING_SAMPLE_RATE.load(Ordering::SeqCst),
+        channels: 1, // recordings are downmixed to mono
     })
 }
 
@@ -2956,14 +5712,7 @@ fn start_streaming_video_recording(file_path: String) -> Result<String, String>
     // Stop any existing recording first
     let _ = finalize_streaming_video_recording();
 
-    // Normalize file path (expand ~)
-    let mut normalized_path = file_path.trim().to_string();
-    if normalized_path.starts_with("~/") {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        normalized_path = format!("{}/{}", home.trim_end_matches('/'), &normalized_path[2..]);
-    }
+    let normalized_path = normalize_user_path(&file_path)?;
 
     // Create parent directories if needed
     if let Some(parent) = Path::new(&normalized_path).parent() {
@@ -3177,14 +5926,7 @@ fn start_streaming_web_audio_recording(file_path: String) -> Result<String, Stri
     // Stop any existing recording first
     let _ = finalize_streaming_web_audio_recording();
 
-    // Normalize file path (expand ~)
-    let mut normalized_path = file_path.trim().to_string();
-    if normalized_path.starts_with("~/") {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        normalized_path = format!("{}/{}", home.trim_end_matches('/'), &normalized_path[2..]);
-    }
+    let normalized_path = normalize_user_path(&file_path)?;
 
     // Create parent directories if needed
     if let Some(parent) = Path::new(&normalized_path).parent() {
@@ -3377,14 +6119,7 @@ fn read_file_as_base64(file_path: String) -> Result<String, String> {
     use std::io::Read;
     use std::path::Path;
 
-    // Normalize file path (expand ~)
-    let mut normalized_path = file_path.trim().to_string();
-    if normalized_path.starts_with("~/") {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        normalized_path = format!("{}/{}", home.trim_end_matches('/'), &normalized_path[2..]);
-    }
+    let normalized_path = normalize_user_path(&file_path)?;
 
     if !Path::new(&normalized_path).exists() {
         return Err(format!("file_not_found:{}", normalized_path));
@@ -3403,14 +6138,7 @@ fn read_file_as_base64(file_path: String) -> Result<String, String> {
 /// Delete a file (used to clean up temp WebM files after MP3 conversion)
 #[tauri::command]
 fn delete_file(file_path: String) -> Result<(), String> {
-    // Normalize file path (expand ~)
-    let mut normalized_path = file_path.trim().to_string();
-    if normalized_path.starts_with("~/") {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        normalized_path = format!("{}/{}", home.trim_end_matches('/'), &normalized_path[2..]);
-    }
+    let normalized_path = normalize_user_path(&file_path)?;
 
     std::fs::remove_file(&normalized_path)
         .map_err(|e| format!("delete_failed:{}", e))?;
@@ -3489,20 +6217,7 @@ fn write_text_to_file(file_path: String, content: String) -> Result<(), String>
     use std::io::Write;
     use std::path::Path;
 
-    // Normalize path:
-    // - trim whitespace (common when users paste paths)
-    // - expand "~/" to HOME (common expectation on macOS/Linux)
-    let mut normalized = file_path.trim().to_string();
-    if normalized.starts_with("~/") || normalized == "~" {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        if normalized == "~" {
-            normalized = home;
-        } else {
-            normalized = format!("{}/{}", home.trim_end_matches('/'), &normalized[2..]);
-        }
-    }
+    let normalized = normalize_user_path(&file_path)?;
 
     let path = Path::new(&normalized);
     if let Some(parent_dir) = path.parent() {
@@ -3525,18 +6240,7 @@ fn write_binary_to_file(file_path: String, data_base64: String) -> Result<(), St
     use std::io::Write;
     use std::path::Path;
 
-    // Normalize path (same as write_text_to_file)
-    let mut normalized = file_path.trim().to_string();
-    if normalized.starts_with("~/") || normalized == "~" {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        if normalized == "~" {
-            normalized = home;
-        } else {
-            normalized = format!("{}/{}", home.trim_end_matches('/'), &normalized[2..]);
-        }
-    }
+    let normalized = normalize_user_path(&file_path)?;
 
     // Decode base64 data
     let data = base64::engine::general_purpose::STANDARD
@@ -3564,17 +6268,7 @@ fn ensure_output_folder(path: String) -> Result<(), String> {
     use std::fs::create_dir_all;
     use std::path::Path;
 
-    let mut normalized = path.trim().to_string();
-    if normalized.starts_with("~/") || normalized == "~" {
-        let home = std::env::var("HOME")
-            .or_else(|_| std::env::var("USERPROFILE"))
-            .map_err(|_| "home_dir_unavailable".to_string())?;
-        if normalized == "~" {
-            normalized = home;
-        } else {
-            normalized = format!("{}/{}", home.trim_end_matches('/'), &normalized[2..]);
-        }
-    }
+    let normalized = normalize_user_path(&path)?;
 
     let dir_path = Path::new(&normalized);
     if !dir_path.exists() {
@@ -3635,29 +6329,7 @@ async fn stop_live_slides_server() -> Result<(), String> {
 
 #[tauri::command]
 async fn create_live_slide_session(name: String) -> Result<LiveSlideSession, String> {
-    let state = SERVER_STATE.clone();
-    
-    let session_id = uuid::Uuid::new_v4().to_string();
-    let session = LiveSlideSession {
-        id: session_id.clone(),
-        name,
-        slides: Vec::new(),
-        raw_text: String::new(),
-        created_at: std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs(),
-    };
-    
-    state.sessions.write().await.insert(session_id, session.clone());
-    
-    // Broadcast session creation
-    let msg = WsMessage::SessionCreated { session: session.clone() };
-    if let Ok(json) = serde_json::to_string(&msg) {
-        let _ = state.broadcast_tx.send(json);
-    }
-    
-    Ok(session)
+    Ok(create_live_slide_session_impl(&SERVER_STATE, name).await)
 }
 
 #[tauri::command]
@@ -3665,66 +6337,63 @@ async fn upsert_live_slide_session(
     session_id: String,
     name: String,
     raw_text: String,
+    client_id: Option<String>,
 ) -> Result<LiveSlideSession, String> {
-    let state = SERVER_STATE.clone();
-
-    let slides = parse_notepad_text(&raw_text);
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-
-    let mut sessions = state.sessions.write().await;
-    let created_at = sessions
-        .get(&session_id)
-        .map(|s| s.created_at)
-        .unwrap_or(now);
-    let is_new = !sessions.contains_key(&session_id);
-
-    let session = LiveSlideSession {
-        id: session_id.clone(),
-        name,
-        slides: slides.clone(),
-        raw_text: raw_text.clone(),
-        created_at,
-    };
-
-    sessions.insert(session_id.clone(), session.clone());
-    drop(sessions);
-
-    if is_new {
-        let msg = WsMessage::SessionCreated {
-            session: session.clone(),
-        };
-        if let Ok(json) = serde_json::to_string(&msg) {
-            let _ = state.broadcast_tx.send(json);
-        }
-    }
+    Ok(upsert_live_slide_session_impl(&SERVER_STATE, session_id, name, raw_text, None, client_id, "upsert").await)
+}
 
-    let update = WsMessage::SlidesUpdate {
-        session_id,
-        slides,
-        raw_text,
+/// Import an OpenLyrics, ChordPro, CCLI SongSelect or Word (.docx) file as a
+/// Live Slides session. `format` is inferred from the file extension when omitted.
+/// Passing `session_id` replaces that session's text instead of creating a new one.
+#[tauri::command]
+async fn import_live_slide_session(
+    file_path: String,
+    format: Option<String>,
+    session_id: Option<String>,
+    name: Option<String>,
+    client_id: Option<String>,
+) -> Result<LiveSlideImportResult, String> {
+    let normalized = normalize_user_path(&file_path)?;
+    let path = std::path::PathBuf::from(&normalized);
+
+    let format = match format {
+        Some(format) => ImportFormat::parse(&format)?,
+        None => ImportFormat::from_path(&path).ok_or_else(|| "unknown_import_format".to_string())?,
     };
-    if let Ok(json) = serde_json::to_string(&update) {
-        let _ = state.broadcast_tx.send(json);
+    let data = std::fs::read(&path).map_err(|e| format!("read_failed:{}", e))?;
+    let imported = live_slides_import::import_bytes(&data, format)?;
+    if imported.raw_text.trim().is_empty() {
+        return Err("import_empty".to_string());
     }
 
-    Ok(session)
+    let name = name
+        .filter(|n| !n.trim().is_empty())
+        .or_else(|| imported.title.clone())
+        .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported".to_string());
+    let session_id = session_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let session = upsert_live_slide_session_impl(
+        &SERVER_STATE,
+        session_id,
+        name,
+        imported.raw_text,
+        imported.ccli_number.clone().map(Some),
+        client_id,
+        "import",
+    )
+    .await;
+
+    Ok(LiveSlideImportResult {
+        session,
+        ccli_number: imported.ccli_number,
+        warnings: imported.warnings,
+    })
 }
 
 #[tauri::command]
 async fn delete_live_slide_session(session_id: String) -> Result<(), String> {
-    let state = SERVER_STATE.clone();
-    
-    state.sessions.write().await.remove(&session_id);
-    
-    // Broadcast session deletion
-    let msg = WsMessage::SessionDeleted { session_id };
-    if let Ok(json) = serde_json::to_string(&msg) {
-        let _ = state.broadcast_tx.send(json);
-    }
-    
+    delete_live_slide_session_impl(&SERVER_STATE, session_id).await;
     Ok(())
 }
 
@@ -3735,6 +6404,48 @@ async fn get_live_slide_sessions() -> Result<Vec<LiveSlideSession>, String> {
     Ok(sessions.values().cloned().collect())
 }
 
+#[tauri::command]
+async fn list_live_slide_revisions(session_id: String) -> Result<Vec<LiveSlideRevision>, String> {
+    list_live_slide_revisions_impl(&SERVER_STATE, &session_id).await
+}
+
+#[tauri::command]
+async fn diff_live_slide_revisions(
+    session_id: String,
+    from_revision: u64,
+    to_revision: u64,
+) -> Result<LiveSlideRevisionDiff, String> {
+    diff_live_slide_revisions_impl(&SERVER_STATE, &session_id, from_revision, to_revision).await
+}
+
+#[tauri::command]
+async fn restore_live_slide_revision(
+    session_id: String,
+    revision: u64,
+    client_id: Option<String>,
+) -> Result<LiveSlideSession, String> {
+    restore_live_slide_revision_impl(&SERVER_STATE, &session_id, revision, client_id).await
+}
+
+#[tauri::command]
+async fn get_live_slide_cursor(session_id: String) -> Result<LiveSlideCursor, String> {
+    get_live_slide_cursor_impl(&SERVER_STATE, &session_id).await
+}
+
+/// Move a session's live cursor: `action` is "next", "prev", "goto" (with `index`) or "clear".
+#[tauri::command]
+async fn control_live_slide(
+    app: tauri::AppHandle,
+    session_id: String,
+    action: String,
+    index: Option<usize>,
+) -> Result<LiveSlideCursor, String> {
+    let action = LiveSlideAction::parse(&action, index)?;
+    let cursor = move_live_slide_cursor(&SERVER_STATE, &session_id, action).await?;
+    emit_live_slide_changed(&app, &cursor);
+    Ok(cursor)
+}
+
 #[tauri::command]
 async fn get_live_slides_server_info() -> Result<LiveSlidesState, String> {
     let state = SERVER_STATE.clone();
@@ -3755,6 +6466,45 @@ async fn get_live_slides_server_info() -> Result<LiveSlidesState, String> {
     })
 }
 
+/// Export a Live Slides session to `file_path`.
+/// For the "txt" format `file_path` is a folder that receives one file per slide
+/// (ProPresenter linked text); other formats write a single file.
+#[tauri::command]
+async fn export_live_slide_session(
+    session_id: String,
+    format: String,
+    file_path: String,
+) -> Result<Vec<String>, String> {
+    let format = ExportFormat::parse(&format)?;
+    let session = SERVER_STATE
+        .sessions
+        .read()
+        .await
+        .get(&session_id)
+        .cloned()
+        .ok_or_else(|| "session_not_found".to_string())?;
+
+    let normalized = normalize_user_path(&file_path)?;
+    let target = std::path::PathBuf::from(&normalized);
+
+    let files: Vec<(std::path::PathBuf, Vec<u8>)> = match format {
+        ExportFormat::Text => live_slides_export::export_text_slides(&session)
+            .into_iter()
+            .map(|(name, content)| (target.join(name), content.into_bytes()))
+            .collect(),
+        _ => {
+            let file = live_slides_export::export_session(&session, format)?;
+            vec![(target, file.data)]
+        }
+    };
+
+    let mut written = Vec::with_capacity(files.len());
+    for (path, data) in files {
+        written.push(write_user_file(path, data).await?);
+    }
+    Ok(written)
+}
+
 #[tauri::command]
 async fn set_api_enabled(enabled: bool) -> Result<(), String> {
     let state = SERVER_STATE.clone();
@@ -3769,34 +6519,82 @@ fn get_local_ip() -> String {
         .unwrap_or_else(|_| "localhost".to_string())
 }
 
+/// Store the schedule for remote viewers and report parse/validation issues.
+/// Invalid schedules are still stored, but auto-advance skips them.
 #[tauri::command]
 async fn update_schedule(
     schedule: Vec<ScheduleItem>,
     current_session_index: Option<usize>,
-) -> Result<(), String> {
-    let state = SERVER_STATE.clone();
-    
-    // Update schedule state
-    {
-        let mut schedule_state = state.schedule.write().await;
-        schedule_state.schedule = schedule.clone();
-        schedule_state.current_session_index = current_session_index;
-    }
-    
-    // Broadcast schedule update to all connected clients
-    let update = WsMessage::ScheduleUpdate {
-        schedule,
-        current_session_index,
+) -> Result<ScheduleValidation, String> {
+    Ok(update_schedule_impl(&SERVER_STATE, schedule, current_session_index).await)
+}
+
+/// Read a run-sheet file and convert it to schedule items without applying it.
+/// `format` is "csv", "ics", "pco" (Planning Center CSV) or "pco_json"; it
+/// defaults to the file extension.
+#[tauri::command]
+fn import_schedule(
+    file_path: String,
+    format: Option<String>,
+    options: Option<ScheduleImportOptions>,
+) -> Result<ScheduleImportResult, String> {
+    let normalized = normalize_user_path(&file_path)?;
+    let path = std::path::PathBuf::from(&normalized);
+
+    let format = match format {
+        Some(format) => ScheduleImportFormat::parse(&format)?,
+        None => ScheduleImportFormat::from_path(&path).ok_or_else(|| "unknown_import_format".to_string())?,
     };
-    if let Ok(json) = serde_json::to_string(&update) {
-        let _ = state.broadcast_tx.send(json);
-    }
-    
+    let data = std::fs::read(&path).map_err(|e| format!("read_failed:{}", e))?;
+    schedule_io::import_schedule(&data, format, &options.unwrap_or_default())
+}
+
+/// Write the current schedule as CSV, iCalendar (events on `date`, default
+/// today) or Planning Center CSV.
+#[tauri::command]
+async fn export_schedule(file_path: String, format: String, date: Option<String>) -> Result<String, String> {
+    let format = ScheduleExportFormat::parse(&format)?;
+    let date = match date.filter(|d| !d.trim().is_empty()) {
+        Some(date) => chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+            .map_err(|_| format!("invalid_date:{}", date))?,
+        None => chrono::Local::now().date_naive(),
+    };
+
+    let normalized = normalize_user_path(&file_path)?;
+    let path = std::path::PathBuf::from(&normalized);
+
+    let schedule = SERVER_STATE.schedule.read().await.schedule.clone();
+    let file = schedule_io::export_schedule(&schedule, format, date, &format!("schedule-{}", date));
+    write_user_file(path, file.data).await
+}
+
+/// Planned versus actual start/end for every session run since the log was cleared.
+#[tauri::command]
+async fn get_schedule_report() -> Result<ScheduleReport, String> {
+    Ok(SERVER_STATE.schedule_runs.read().await.report(chrono::Local::now()))
+}
+
+#[tauri::command]
+async fn clear_schedule_report() -> Result<(), String> {
+    SERVER_STATE.schedule_runs.write().await.clear();
     Ok(())
 }
 
+#[tauri::command]
+fn validate_schedule(schedule: Vec<ScheduleItem>) -> ScheduleValidation {
+    schedule_engine::validate_schedule(&schedule)
+}
+
+/// Turn automatic session advance on or off. When on, the current session
+/// follows the schedule's start times and each session's countdown starts itself.
+#[tauri::command]
+async fn set_schedule_auto_advance(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    set_schedule_auto_advance_impl(&SERVER_STATE, &app, enabled).await
+}
+
 #[tauri::command]
 async fn update_timer_state(
+    app: tauri::AppHandle,
     is_running: bool,
     time_left: i32,
     session_name: Option<String>,
@@ -3804,58 +6602,278 @@ async fn update_timer_state(
     is_overrun: bool,
 ) -> Result<(), String> {
     let state = SERVER_STATE.clone();
-    
-    // Update timer state
-    {
-        let mut timer_state = state.timer_state.write().await;
-        timer_state.is_running = is_running;
-        timer_state.time_left = time_left;
-        timer_state.session_name = session_name.clone();
-        timer_state.end_time = end_time.clone();
-        timer_state.is_overrun = is_overrun;
+
+    // The server timer engine owns the clock while it runs; a pushed state
+    // would fight its ticks, so it is only accepted once the engine is stopped.
+    if state.timer_engine.read().await.is_active() {
+        return Err("timer_engine_active".to_string());
     }
-    
-    // Broadcast timer update to all connected clients
-    let update = WsMessage::TimerUpdate {
-        timer_state: TimerState {
-            is_running,
-            time_left,
-            session_name,
-            end_time,
-            is_overrun,
-        },
+
+    let timer_state = TimerState {
+        is_running,
+        time_left,
+        session_name,
+        end_time,
+        is_overrun,
+        mode: TimerMode::Countdown,
+        deadline: None,
+        server_time: None,
+    };
+    publish_timer_state(&state, &app, timer_state).await;
+    Ok(())
+}
+
+/// Start the server timer: a countdown of `seconds`, a countdown to the local
+/// time `end_time` (e.g. "11:45 AM") when no duration is given, or a stopwatch
+/// when `mode` is "count_up".
+#[tauri::command]
+async fn start_timer(
+    app: tauri::AppHandle,
+    seconds: Option<f64>,
+    mode: Option<String>,
+    end_time: Option<String>,
+    session_name: Option<String>,
+) -> Result<TimerState, String> {
+    let control = timer_start_control(seconds, mode.as_deref(), end_time, session_name)?;
+    apply_timer_control(&SERVER_STATE, &app, control).await
+}
+
+#[tauri::command]
+async fn pause_timer(app: tauri::AppHandle) -> Result<TimerState, String> {
+    apply_timer_control(&SERVER_STATE, &app, TimerControl::Pause).await
+}
+
+#[tauri::command]
+async fn resume_timer(app: tauri::AppHandle) -> Result<TimerState, String> {
+    apply_timer_control(&SERVER_STATE, &app, TimerControl::Resume).await
+}
+
+#[tauri::command]
+async fn reset_timer(app: tauri::AppHandle) -> Result<TimerState, String> {
+    apply_timer_control(&SERVER_STATE, &app, TimerControl::Reset).await
+}
+
+#[tauri::command]
+async fn stop_timer(app: tauri::AppHandle) -> Result<TimerState, String> {
+    apply_timer_control(&SERVER_STATE, &app, TimerControl::Stop).await
+}
+
+/// Extend (positive) or shorten (negative) the running countdown.
+#[tauri::command]
+async fn add_timer_time(app: tauri::AppHandle, seconds: i64) -> Result<TimerState, String> {
+    apply_timer_control(&SERVER_STATE, &app, TimerControl::AddTime(seconds)).await
+}
+
+/// Show a message to the speaker on stage clients (not the audience display).
+/// `duration` is in seconds; omit it to keep the message until cleared.
+#[tauri::command]
+async fn send_stage_message(
+    app: tauri::AppHandle,
+    text: String,
+    duration: Option<u64>,
+    flash: Option<bool>,
+    priority: Option<StageMessagePriority>,
+) -> Result<StageMessage, String> {
+    let request = ApiStageMessageRequest {
+        text,
+        duration,
+        flash: flash.unwrap_or(false),
+        priority: priority.unwrap_or_default(),
+    };
+    send_stage_message_impl(&SERVER_STATE, &app, request).await
+}
+
+#[tauri::command]
+async fn clear_stage_message(app: tauri::AppHandle) -> Result<(), String> {
+    clear_stage_message_impl(&SERVER_STATE, &app).await;
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_stage_message() -> Result<Option<StageMessage>, String> {
+    Ok(active_stage_message(&*SERVER_STATE.stage_message.read().await))
+}
+
+#[tauri::command]
+async fn get_timer_alert_rules() -> Result<Vec<TimerAlertRule>, String> {
+    Ok(SERVER_STATE.timer_alert_rules.read().await.clone())
+}
+
+/// Replace the countdown alert rules (e.g. "2:00 remaining", "every minute
+/// overrun"). Each rule broadcasts `timer_alert` and can send a MIDI note or
+/// POST to a webhook.
+#[tauri::command]
+async fn set_timer_alert_rules(app: tauri::AppHandle, rules: Vec<TimerAlertRule>) -> Result<(), String> {
+    set_timer_alert_rules_impl(&SERVER_STATE, &app, rules).await
+}
+
+#[tauri::command]
+async fn get_timer_state() -> Result<TimerState, String> {
+    Ok(current_timer_state(&SERVER_STATE).await)
+}
+
+// Stores an output's new state and pushes it to web clients (display_update)
+// and desktop output windows ("display-output-updated").
+async fn set_display_state_impl(state: &ServerState, app: &tauri::AppHandle, output: String, display_state: DisplayState) {
+    state
+        .display_outputs
+        .write()
+        .await
+        .insert(output.clone(), display_state.clone());
+
+    let has_content = !display_state.slides.is_empty()
+        || !display_state.scripture.verse_text.trim().is_empty()
+        || !display_state.scripture.reference.trim().is_empty();
+    if has_content {
+        let mut entry = DisplayHistoryEntry::new(DisplayHistoryKind::DisplayUpdate, now_millis());
+        entry.output = Some(output.clone());
+        if display_state.slides.is_empty() {
+            entry.reference = Some(display_state.scripture.reference.clone()).filter(|r| !r.is_empty());
+            entry.translation = display_state.scripture.translation_short_name.clone();
+            entry.text = display_state.scripture.verse_text.clone();
+        } else {
+            entry.text = display_state.slides.join("\n");
+            if let Some(song) = find_displayed_song(state, &display_state.slides).await {
+                entry.ccli_number = Some(song.ccli_number);
+                entry.song_title = Some(song.title).filter(|t| !t.is_empty());
+            }
+        }
+        record_display_history(state, entry).await;
+    } else {
+        let mut cleared = DisplayHistoryEntry::new(DisplayHistoryKind::DisplayUpdate, now_millis());
+        cleared.output = Some(output.clone());
+        if let Err(e) = state.display_history.screen_cleared(&cleared) {
+            eprintln!("[Display] Failed to record display history: {}", e);
+        }
+    }
+
+    let _ = app.emit(
+        "display-output-updated",
+        serde_json::json!({ "output": output, "state": display_state }),
+    );
+    let update = WsMessage::DisplayUpdate {
+        output,
+        scripture: display_state.scripture,
+        slides: display_state.slides,
+        settings: Box::new(display_state.settings),
     };
     if let Ok(json) = serde_json::to_string(&update) {
         let _ = state.broadcast_tx.send(json);
     }
-    
-    Ok(())
+}
+
+// Recognises the song behind slide lines sent to a display: the playlist item
+// (published for the REST API) or Live Slides session that contains them.
+async fn find_displayed_song(state: &ServerState, lines: &[String]) -> Option<CcliSong> {
+    if let Some(playlists) = state.api_playlists.read().await.as_ref() {
+        if let Some(song) = ccli_report::find_playlist_song(playlists, lines) {
+            return Some(song);
+        }
+    }
+    let wanted: Vec<&str> = lines.iter().map(|l| l.trim()).filter(|l| !l.is_empty()).collect();
+    let sessions = state.sessions.read().await;
+    sessions
+        .values()
+        .find(|session| {
+            session.slides.iter().any(|slide| {
+                slide.items.iter().map(|item| item.text.trim()).filter(|t| !t.is_empty()).eq(wanted.iter().copied())
+            })
+        })
+        .and_then(|session| ccli_report::detect_song(&session.name, &session.raw_text, session.ccli_number.as_deref()))
+}
+
+// Stamps the entry with the active schedule session and appends it to the
+// persisted display history. Logging never blocks what goes on screen.
+async fn record_display_history(state: &ServerState, mut entry: DisplayHistoryEntry) {
+    {
+        let schedule = state.schedule.read().await;
+        if let Some(index) = schedule.current_session_index {
+            if let Some(item) = schedule.schedule.get(index) {
+                entry.schedule_index = Some(index);
+                entry.schedule_session = Some(item.session.clone());
+                entry.schedule_minister = item.minister.clone().filter(|m| !m.trim().is_empty());
+            }
+        }
+    }
+    if let Err(e) = state.display_history.record(entry) {
+        eprintln!("[Display] Failed to record display history: {}", e);
+    }
+}
+
+// The history file can be large; read it off the async runtime.
+async fn query_display_history(
+    state: &Arc<ServerState>,
+    from: Option<u64>,
+    to: Option<u64>,
+) -> Result<Vec<DisplayHistoryEntry>, String> {
+    let state = state.clone();
+    tokio::task::spawn_blocking(move || state.display_history.query(from, to))
+        .await
+        .map_err(|e| format!("query_failed:{}", e))?
+}
+
+/// What went live on the displays between `from` and `to` (epoch ms, RFC 3339 or YYYY-MM-DD).
+#[tauri::command]
+async fn get_display_history(from: Option<String>, to: Option<String>) -> Result<Vec<DisplayHistoryEntry>, String> {
+    let (from, to) = display_history::parse_time_range(from.as_deref(), to.as_deref())?;
+    query_display_history(&SERVER_STATE, from, to).await
+}
+
+#[tauri::command]
+async fn export_display_history(file_path: String, from: Option<String>, to: Option<String>) -> Result<String, String> {
+    let (from, to) = display_history::parse_time_range(from.as_deref(), to.as_deref())?;
+
+    let normalized = normalize_user_path(&file_path)?;
+    let path = std::path::PathBuf::from(&normalized);
+
+    let entries = query_display_history(&SERVER_STATE, from, to).await?;
+    write_user_file(path, display_history::export_csv(&entries).into_bytes()).await
+}
+
+#[tauri::command]
+async fn clear_display_history() -> Result<(), String> {
+    let state = SERVER_STATE.clone();
+    tokio::task::spawn_blocking(move || state.display_history.clear())
+        .await
+        .map_err(|e| format!("clear_failed:{}", e))?
+}
+
+/// CCLI song usage (distinct projections per service) from the display history.
+#[tauri::command]
+async fn get_ccli_report(from: Option<String>, to: Option<String>) -> Result<CcliReport, String> {
+    let (from, to) = display_history::parse_time_range(from.as_deref(), to.as_deref())?;
+    let entries = query_display_history(&SERVER_STATE, from, to).await?;
+    Ok(ccli_report::build_report(&entries, from, to))
+}
+
+#[tauri::command]
+async fn export_ccli_report(file_path: String, from: Option<String>, to: Option<String>) -> Result<String, String> {
+    let (from, to) = display_history::parse_time_range(from.as_deref(), to.as_deref())?;
+
+    let normalized = normalize_user_path(&file_path)?;
+    let path = std::path::PathBuf::from(&normalized);
+
+    let entries = query_display_history(&SERVER_STATE, from, to).await?;
+    let report = ccli_report::build_report(&entries, from, to);
+    write_user_file(path, ccli_report::export_csv(&report).into_bytes()).await
 }
 
 #[tauri::command]
 async fn update_display_state(
+    app: tauri::AppHandle,
+    output: Option<String>,
     verse_text: String,
     reference: String,
     translation_short_name: Option<String>,
     slides: Vec<String>,
     settings: serde_json::Value,
 ) -> Result<(), String> {
-    let state = SERVER_STATE.clone();
-    
-    // Update display state
-    {
-        let mut display_state = state.display_state.write().await;
-        display_state.scripture = DisplayScripture {
-            verse_text: verse_text.clone(),
-            reference: reference.clone(),
-            translation_short_name: translation_short_name.clone(),
-        };
-        display_state.slides = slides.clone();
-        display_state.settings = settings.clone();
-    }
-    
-    // Broadcast display update to all connected clients
-    let update = WsMessage::DisplayUpdate {
+    let output = resolve_display_output(output.as_deref())?;
+    // Accepts settings from older app versions and rejects invalid values
+    // before anything reaches the display clients.
+    let settings = DisplaySettings::from_json(settings)?;
+
+    let display_state = DisplayState {
         scripture: DisplayScripture {
             verse_text,
             reference,
@@ -3864,13 +6882,163 @@ async fn update_display_state(
         slides,
         settings,
     };
-    if let Ok(json) = serde_json::to_string(&update) {
-        let _ = state.broadcast_tx.send(json);
+    set_display_state_impl(&SERVER_STATE, &app, output, display_state).await;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SnapshotFormat {
+    Png,
+    Svg,
+}
+
+impl SnapshotFormat {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "png" => Ok(SnapshotFormat::Png),
+            "svg" => Ok(SnapshotFormat::Svg),
+            other => Err(format!("unsupported_snapshot_format:{}", other)),
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            SnapshotFormat::Png => "image/png",
+            SnapshotFormat::Svg => "image/svg+xml",
+        }
+    }
+}
+
+// Renders what an output is showing without a webview. Rendering is CPU work,
+// so it runs on the blocking pool.
+async fn render_display_snapshot_impl(
+    state: &ServerState,
+    output: Option<&str>,
+    width: Option<u32>,
+    height: Option<u32>,
+    format: SnapshotFormat,
+) -> Result<Vec<u8>, String> {
+    let output = resolve_display_output(output)?;
+    let display_state = state
+        .display_outputs
+        .read()
+        .await
+        .get(&output)
+        .cloned()
+        .ok_or_else(|| format!("display_output_not_found:{}", output))?;
+    let (width, height) = display_render::snapshot_size(width, height);
+    tokio::task::spawn_blocking(move || match format {
+        SnapshotFormat::Png => display_render::render_png(&display_state, width, height),
+        SnapshotFormat::Svg => Ok(display_render::render_svg(&display_state, width, height).into_bytes()),
+    })
+    .await
+    .map_err(|e| format!("snapshot_render_failed:{}", e))?
+}
+
+/// Render an output to PNG (default) or SVG and return it as a data: URL.
+#[tauri::command]
+async fn render_display_snapshot(
+    output: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    format: Option<String>,
+) -> Result<String, String> {
+    let format = SnapshotFormat::parse(format.as_deref().unwrap_or("png"))?;
+    let data = render_display_snapshot_impl(&SERVER_STATE, output.as_deref(), width, height, format).await?;
+    Ok(format!(
+        "data:{};base64,{}",
+        format.mime_type(),
+        base64::engine::general_purpose::STANDARD.encode(data)
+    ))
+}
+
+/// Render an output to a .png or .svg file (format from the extension).
+#[tauri::command]
+async fn save_display_snapshot(
+    file_path: String,
+    output: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+) -> Result<String, String> {
+    let normalized = normalize_user_path(&file_path)?;
+    let path = std::path::PathBuf::from(&normalized);
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("png");
+    let format = SnapshotFormat::parse(extension)?;
+
+    let data = render_display_snapshot_impl(&SERVER_STATE, output.as_deref(), width, height, format).await?;
+    write_user_file(path, data).await
+}
+
+#[tauri::command]
+async fn get_display_state(output: Option<String>) -> Result<DisplayState, String> {
+    let output = resolve_display_output(output.as_deref())?;
+    SERVER_STATE
+        .display_outputs
+        .read()
+        .await
+        .get(&output)
+        .cloned()
+        .ok_or_else(|| format!("display_output_not_found:{}", output))
+}
+
+#[tauri::command]
+async fn list_display_outputs() -> Result<Vec<String>, String> {
+    let mut outputs: Vec<String> = SERVER_STATE.display_outputs.read().await.keys().cloned().collect();
+    outputs.sort_by_key(|id| (id != MAIN_DISPLAY_OUTPUT, id.clone()));
+    Ok(outputs)
+}
+
+#[tauri::command]
+async fn remove_display_output(app: tauri::AppHandle, output: String) -> Result<(), String> {
+    remove_display_output_impl(&SERVER_STATE, &app, &output).await
+}
+
+async fn remove_display_output_impl(state: &ServerState, app: &tauri::AppHandle, output: &str) -> Result<(), String> {
+    let output = resolve_display_output(Some(output))?;
+    if output == MAIN_DISPLAY_OUTPUT {
+        return Err("cannot_remove_main_output".to_string());
+    }
+    if state.display_outputs.write().await.remove(&output).is_none() {
+        return Err(format!("display_output_not_found:{}", output));
+    }
+    if let Some(window) = app.get_webview_window(&display_output_window_label(&output)) {
+        let _ = window.close();
     }
-    
     Ok(())
 }
 
+// Applies a REST update to one output and returns its resolved id and state.
+async fn put_display_state_impl(
+    state: &ServerState,
+    app: &tauri::AppHandle,
+    output: Option<&str>,
+    request: ApiDisplayStateRequest,
+) -> Result<(String, DisplayState), String> {
+    let output = resolve_display_output(output)?;
+    let settings = request.settings.map(DisplaySettings::from_json).transpose()?;
+    let mut display_state = {
+        let outputs = state.display_outputs.read().await;
+        match outputs.get(&output) {
+            Some(current) => current.clone(),
+            None => DisplayState {
+                settings: outputs.get(MAIN_DISPLAY_OUTPUT).map(|main| main.settings.clone()).unwrap_or_default(),
+                ..DisplayState::default()
+            },
+        }
+    };
+    if let Some(scripture) = request.scripture {
+        display_state.scripture = scripture;
+    }
+    if let Some(slides) = request.slides {
+        display_state.slides = slides;
+    }
+    if let Some(settings) = settings {
+        display_state.settings = settings;
+    }
+    set_display_state_impl(state, app, output.clone(), display_state.clone()).await;
+    Ok((output, display_state))
+}
+
 // ============================================================================
 // Network Sync Tauri Commands
 // ============================================================================
@@ -4060,6 +7228,11 @@ fn send_midi_note(
     note: u8,
     velocity: u8,
 ) -> Result<(), String> {
+    send_midi_note_impl(&device_id, channel, note, velocity)
+}
+
+// Shared by the command and timer alert rules.
+fn send_midi_note_impl(device_id: &str, channel: u8, note: u8, velocity: u8) -> Result<(), String> {
     use midir::MidiOutput;
     
     // Validate channel (0-15, but we'll use 1-16 for user input)
@@ -4135,6 +7308,21 @@ pub fn run() {
 
     builder
         .setup(|app| {
+            match app.handle().path().app_data_dir() {
+                Ok(dir) => SERVER_STATE
+                    .display_history
+                    .set_path(dir.join(display_history::DISPLAY_HISTORY_FILE_NAME)),
+                Err(e) => eprintln!("[Display] History disabled, no app data dir: {}", e),
+            }
+            match timer_alerts_path(app.handle()).and_then(|path| timer_alerts::load_rules(&path)) {
+                Ok(rules) => {
+                    tauri::async_runtime::spawn(async move {
+                        *SERVER_STATE.timer_alert_rules.write().await = rules;
+                    });
+                }
+                Err(e) => eprintln!("[Timer] Using default alert rules: {}", e),
+            }
+            monitor_watcher::start_monitor_watcher(app.handle());
             #[cfg(desktop)]
             app.handle().plugin(tauri_plugin_updater::Builder::new().build())?;
             Ok(())
@@ -4146,17 +7334,23 @@ pub fn run() {
             open_audience_display_window,
             show_monitor_identify_window,
             hide_monitor_identify_window,
+            list_display_layout_profiles,
+            save_display_layout_profile,
+            delete_display_layout_profile,
+            apply_display_layout_profile,
             open_dialog,
             close_dialog,
             get_available_system_fonts,
             list_native_audio_input_devices,
             start_native_audio_stream,
+            list_native_audio_input_configs,
             stop_native_audio_stream,
             // Mac native Whisper (Metal)
             download_native_whisper_model,
             native_whisper_model_exists,
             asr_init,
             asr_push_audio,
+            asr_push_audio_raw,
             asr_poll,
             asr_reset,
             // Native audio recording commands
@@ -4193,12 +7387,47 @@ pub fn run() {
             upsert_live_slide_session,
             delete_live_slide_session,
             get_live_slide_sessions,
+            list_live_slide_revisions,
+            diff_live_slide_revisions,
+            restore_live_slide_revision,
+            export_live_slide_session,
+            import_live_slide_session,
+            get_live_slide_cursor,
+            control_live_slide,
             get_live_slides_server_info,
             set_api_enabled,
             get_local_ip,
             update_schedule,
+            validate_schedule,
+            get_schedule_report,
+            clear_schedule_report,
+            import_schedule,
+            export_schedule,
+            set_schedule_auto_advance,
             update_timer_state,
+            start_timer,
+            pause_timer,
+            resume_timer,
+            reset_timer,
+            stop_timer,
+            add_timer_time,
+            get_timer_state,
+            get_timer_alert_rules,
+            send_stage_message,
+            clear_stage_message,
+            get_stage_message,
+            set_timer_alert_rules,
             update_display_state,
+            get_display_state,
+            list_display_outputs,
+            get_display_history,
+            export_display_history,
+            clear_display_history,
+            get_ccli_report,
+            export_ccli_report,
+            remove_display_output,
+            render_display_snapshot,
+            save_display_snapshot,
             // Network Sync commands
             start_sync_server,
             stop_sync_server,
@@ -0,0 +1,564 @@
+//live_slides_export.rs turns Live Slides sessions into presentation files
+use crate::{LiveSlide, LiveSlideSession};
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Pptx,
+    OpenLyrics,
+    Text, // one .txt per slide for ProPresenter linked text
+}
+
+impl ExportFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "pptx" | "powerpoint" => Ok(ExportFormat::Pptx),
+            "openlyrics" | "openlp" | "xml" => Ok(ExportFormat::OpenLyrics),
+            "txt" | "text" | "propresenter" => Ok(ExportFormat::Text),
+            other => Err(format!("unsupported_export_format:{}", other)),
+        }
+    }
+}
+
+pub struct ExportedFile {
+    pub file_name: String,
+    pub content_type: &'static str,
+    pub data: Vec<u8>,
+}
+
+pub fn export_session(session: &LiveSlideSession, format: ExportFormat) -> Result<ExportedFile, String> {
+    let stem = safe_file_stem(&session.name);
+    match format {
+        ExportFormat::Pptx => Ok(ExportedFile {
+            file_name: format!("{}.pptx", stem),
+            content_type: "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+            data: export_pptx(session)?,
+        }),
+        ExportFormat::OpenLyrics => Ok(ExportedFile {
+            file_name: format!("{}.xml", stem),
+            content_type: "application/xml",
+            data: export_openlyrics(session).into_bytes(),
+        }),
+        ExportFormat::Text => {
+            // Over HTTP the per-slide files are bundled into a single zip.
+            let files = export_text_slides(session);
+            let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+            for (name, content) in files {
+                zip.start_file(name, stored())
+                    .map_err(|e| format!("zip_failed:{}", e))?;
+                zip.write_all(content.as_bytes())
+                    .map_err(|e| format!("zip_failed:{}", e))?;
+            }
+            let data = zip
+                .finish()
+                .map_err(|e| format!("zip_failed:{}", e))?
+                .into_inner();
+            Ok(ExportedFile {
+                file_name: format!("{}-text.zip", stem),
+                content_type: "application/zip",
+                data,
+            })
+        }
+    }
+}
+
+// ============================================================================
+// Shared helpers
+// ============================================================================
+
+pub fn safe_file_stem(name: &str) -> String {
+    let cleaned: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' || c == ' ' { c } else { '_' })
+        .collect();
+    let cleaned = cleaned.trim().to_string();
+    if cleaned.is_empty() {
+        "live-slides".to_string()
+    } else {
+        cleaned
+    }
+}
+
+pub fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn slide_lines(slide: &LiveSlide) -> Vec<&str> {
+    slide.items.iter().map(|item| item.text.as_str()).collect()
+}
+
+/// Map a section label such as "Verse 2", "Chorus" or "Pre-Chorus:" to an
+/// OpenLyrics verse name ("v2", "c1", "p1"). Returns None for ordinary text.
+pub fn section_code(label: &str) -> Option<String> {
+    let cleaned = label.trim().trim_end_matches(':').trim().to_lowercase();
+    let split = cleaned
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(cleaned.len());
+    let (word, number) = cleaned.split_at(split);
+    let word = word.trim().replace(['-', ' '], "");
+    let number = number.trim();
+    if !number.is_empty() && !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let prefix = match word.as_str() {
+        "verse" | "v" => "v",
+        "chorus" | "refrain" | "c" => "c",
+        "prechorus" | "p" => "p",
+        "bridge" | "b" => "b",
+        "intro" | "i" => "i",
+        "ending" | "outro" | "end" | "coda" | "e" => "e",
+        "tag" | "other" | "o" => "o",
+        _ => return None,
+    };
+    Some(format!("{}{}", prefix, if number.is_empty() { "1" } else { number }))
+}
+
+// A parent line followed by its "parent + child" slides, as produced by
+// `parse_notepad_text` for indented notepad blocks.
+enum SlideGroup<'a> {
+    Parent { parent: &'a str, children: Vec<&'a str> },
+    Single(&'a LiveSlide),
+}
+
+fn group_slides(slides: &[LiveSlide]) -> Vec<SlideGroup<'_>> {
+    let mut groups = Vec::new();
+    let mut i = 0;
+    while i < slides.len() {
+        let slide = &slides[i];
+        if slide.items.len() == 1 && !slide.items[0].is_sub_item {
+            let parent = slide.items[0].text.as_str();
+            let mut children = Vec::new();
+            let mut j = i + 1;
+            while j < slides.len() {
+                let next = &slides[j];
+                if next.items.len() == 2 && next.items[0].text == parent && next.items[1].is_sub_item {
+                    children.push(next.items[1].text.as_str());
+                    j += 1;
+                } else {
+                    break;
+                }
+            }
+            if !children.is_empty() {
+                groups.push(SlideGroup::Parent { parent, children });
+                i = j;
+                continue;
+            }
+        }
+        groups.push(SlideGroup::Single(slide));
+        i += 1;
+    }
+    groups
+}
+
+// ============================================================================
+// OpenLyrics (OpenLP)
+// ============================================================================
+
+// "v1" or a duplicate of it ("v1a", "v1b", ...), but not "v10".
+fn is_variant_of(name: &str, base_name: &str) -> bool {
+    match name.strip_prefix(base_name) {
+        Some("") => true,
+        Some(suffix) => suffix.len() == 1 && suffix.bytes().all(|b| b.is_ascii_lowercase()),
+        None => false,
+    }
+}
+
+pub fn export_openlyrics(session: &LiveSlideSession) -> String {
+    // Each verse is a list of <lines> parts; OpenLP shows each part as a slide.
+    let mut verses: Vec<(String, Vec<Vec<&str>>)> = Vec::new();
+    let mut order: Vec<String> = Vec::new();
+    let next_generic = |verses: &Vec<(String, Vec<Vec<&str>>)>| {
+        (1..)
+            .map(|n| format!("v{}", n))
+            .find(|name| !verses.iter().any(|(existing, _)| existing == name))
+            .unwrap_or_default()
+    };
+
+    for group in group_slides(&session.slides) {
+        let (base_name, parts): (String, Vec<Vec<&str>>) = match group {
+            SlideGroup::Parent { parent, children } => match section_code(parent) {
+                Some(code) => (code, children.into_iter().map(|c| vec![c]).collect()),
+                None => {
+                    let mut parts = vec![vec![parent]];
+                    parts.extend(children.into_iter().map(|c| vec![parent, c]));
+                    (next_generic(&verses), parts)
+                }
+            },
+            SlideGroup::Single(slide) => (next_generic(&verses), vec![slide_lines(slide)]),
+        };
+
+        // A repeated section with identical lyrics (e.g. the chorus sung twice)
+        // is only written once and repeated through verseOrder.
+        if let Some((name, _)) = verses
+            .iter()
+            .find(|(name, existing)| is_variant_of(name, &base_name) && existing == &parts)
+        {
+            order.push(name.clone());
+            continue;
+        }
+
+        let mut name = base_name.clone();
+        let mut suffix = b'a';
+        while verses.iter().any(|(existing, _)| existing == &name) && suffix <= b'z' {
+            name = format!("{}{}", base_name, suffix as char);
+            suffix += 1;
+        }
+        order.push(name.clone());
+        verses.push((name, parts));
+    }
+
+    let modified = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<song xmlns=\"http://openlyrics.info/namespace/2009/song\" version=\"0.9\" createdIn=\"SmartVerses\" modifiedIn=\"SmartVerses\" modifiedDate=\"{}\">\n",
+        modified
+    ));
+    xml.push_str("  <properties>\n    <titles>\n");
+    xml.push_str(&format!("      <title>{}</title>\n", xml_escape(&session.name)));
+    xml.push_str("    </titles>\n");
+    if !order.is_empty() {
+        xml.push_str(&format!("    <verseOrder>{}</verseOrder>\n", order.join(" ")));
+    }
+    xml.push_str("  </properties>\n  <lyrics>\n");
+    for (name, parts) in &verses {
+        xml.push_str(&format!("    <verse name=\"{}\">\n", name));
+        for lines in parts {
+            let escaped: Vec<String> = lines.iter().map(|l| xml_escape(l)).collect();
+            xml.push_str(&format!("      <lines>{}</lines>\n", escaped.join("<br/>")));
+        }
+        xml.push_str("    </verse>\n");
+    }
+    xml.push_str("  </lyrics>\n</song>\n");
+    xml
+}
+
+// ============================================================================
+// Plain text (ProPresenter linked text files)
+// ============================================================================
+
+pub fn export_text_slides(session: &LiveSlideSession) -> Vec<(String, String)> {
+    let stem = safe_file_stem(&session.name);
+    session
+        .slides
+        .iter()
+        .enumerate()
+        .map(|(index, slide)| {
+            (
+                format!("{}-{:03}.txt", stem, index + 1),
+                format!("{}\n", slide_lines(slide).join("\n")),
+            )
+        })
+        .collect()
+}
+
+// ============================================================================
+// PowerPoint (.pptx, OOXML written directly)
+// ============================================================================
+
+const SLIDE_WIDTH_EMU: u64 = 12_192_000; // 16:9
+const SLIDE_HEIGHT_EMU: u64 = 6_858_000;
+
+const NS_A: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
+const NS_R: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const NS_P: &str = "http://schemas.openxmlformats.org/presentationml/2006/main";
+const NS_REL: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+const REL_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+const XML_DECL: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n";
+
+const EMPTY_SP_TREE: &str = "<p:nvGrpSpPr><p:cNvPr id=\"1\" name=\"\"/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr><p:grpSpPr><a:xfrm><a:off x=\"0\" y=\"0\"/><a:ext cx=\"0\" cy=\"0\"/><a:chOff x=\"0\" y=\"0\"/><a:chExt cx=\"0\" cy=\"0\"/></a:xfrm></p:grpSpPr>";
+
+fn stored() -> zip::write::SimpleFileOptions {
+    zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored)
+}
+
+fn relationships(rels: &[(String, String, String)]) -> String {
+    let mut xml = format!("{}<Relationships xmlns=\"{}\">", XML_DECL, NS_REL);
+    for (id, rel_type, target) in rels {
+        xml.push_str(&format!(
+            "<Relationship Id=\"{}\" Type=\"{}\" Target=\"{}\"/>",
+            id, rel_type, target
+        ));
+    }
+    xml.push_str("</Relationships>");
+    xml
+}
+
+fn pptx_content_types(slide_count: usize) -> String {
+    let mut xml = format!(
+        "{}<Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+<Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+<Default Extension=\"xml\" ContentType=\"application/xml\"/>\
+<Override PartName=\"/ppt/presentation.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml\"/>\
+<Override PartName=\"/ppt/slideMasters/slideMaster1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.slideMaster+xml\"/>\
+<Override PartName=\"/ppt/slideLayouts/slideLayout1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.slideLayout+xml\"/>\
+<Override PartName=\"/ppt/theme/theme1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.theme+xml\"/>\
+<Override PartName=\"/docProps/core.xml\" ContentType=\"application/vnd.openxmlformats-package.core-properties+xml\"/>\
+<Override PartName=\"/docProps/app.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.extended-properties+xml\"/>",
+        XML_DECL
+    );
+    for n in 1..=slide_count {
+        xml.push_str(&format!(
+            "<Override PartName=\"/ppt/slides/slide{}.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.slide+xml\"/>",
+            n
+        ));
+    }
+    xml.push_str("</Types>");
+    xml
+}
+
+fn pptx_presentation(slide_count: usize) -> String {
+    let mut xml = format!(
+        "{}<p:presentation xmlns:a=\"{}\" xmlns:r=\"{}\" xmlns:p=\"{}\">\
+<p:sldMasterIdLst><p:sldMasterId id=\"2147483648\" r:id=\"rId1\"/></p:sldMasterIdLst>",
+        XML_DECL, NS_A, NS_R, NS_P
+    );
+    if slide_count > 0 {
+        xml.push_str("<p:sldIdLst>");
+        for n in 0..slide_count {
+            xml.push_str(&format!("<p:sldId id=\"{}\" r:id=\"rId{}\"/>", 256 + n, n + 3));
+        }
+        xml.push_str("</p:sldIdLst>");
+    }
+    xml.push_str(&format!(
+        "<p:sldSz cx=\"{}\" cy=\"{}\"/><p:notesSz cx=\"6858000\" cy=\"9144000\"/></p:presentation>",
+        SLIDE_WIDTH_EMU, SLIDE_HEIGHT_EMU
+    ));
+    xml
+}
+
+fn pptx_slide_master() -> String {
+    format!(
+        "{}<p:sldMaster xmlns:a=\"{}\" xmlns:r=\"{}\" xmlns:p=\"{}\">\
+<p:cSld><p:bg><p:bgPr><a:solidFill><a:srgbClr val=\"000000\"/></a:solidFill><a:effectLst/></p:bgPr></p:bg><p:spTree>{}</p:spTree></p:cSld>\
+<p:clrMap bg1=\"lt1\" tx1=\"dk1\" bg2=\"lt2\" tx2=\"dk2\" accent1=\"accent1\" accent2=\"accent2\" accent3=\"accent3\" accent4=\"accent4\" accent5=\"accent5\" accent6=\"accent6\" hlink=\"hlink\" folHlink=\"folHlink\"/>\
+<p:sldLayoutIdLst><p:sldLayoutId id=\"2147483649\" r:id=\"rId1\"/></p:sldLayoutIdLst>\
+</p:sldMaster>",
+        XML_DECL, NS_A, NS_R, NS_P, EMPTY_SP_TREE
+    )
+}
+
+fn pptx_slide_layout() -> String {
+    format!(
+        "{}<p:sldLayout xmlns:a=\"{}\" xmlns:r=\"{}\" xmlns:p=\"{}\" type=\"blank\" preserve=\"1\">\
+<p:cSld name=\"Blank\"><p:spTree>{}</p:spTree></p:cSld>\
+<p:clrMapOvr><a:masterClrMapping/></p:clrMapOvr>\
+</p:sldLayout>",
+        XML_DECL, NS_A, NS_R, NS_P, EMPTY_SP_TREE
+    )
+}
+
+fn pptx_theme() -> String {
+    let colors = [
+        ("dk1", "000000"),
+        ("lt1", "FFFFFF"),
+        ("dk2", "1F2937"),
+        ("lt2", "E5E7EB"),
+        ("accent1", "3B82F6"),
+        ("accent2", "F59E0B"),
+        ("accent3", "EC4899"),
+        ("accent4", "10B981"),
+        ("accent5", "8B5CF6"),
+        ("accent6", "EF4444"),
+        ("hlink", "06B6D4"),
+        ("folHlink", "F97316"),
+    ];
+    let clr_scheme: String = colors
+        .iter()
+        .map(|(name, hex)| format!("<a:{0}><a:srgbClr val=\"{1}\"/></a:{0}>", name, hex))
+        .collect();
+    let solid = "<a:solidFill><a:schemeClr val=\"phClr\"/></a:solidFill>";
+    let line = format!("<a:ln w=\"9525\">{}</a:ln>", solid);
+    let effect = "<a:effectStyle><a:effectLst/></a:effectStyle>";
+    let font = "<a:latin typeface=\"Arial\"/><a:ea typeface=\"\"/><a:cs typeface=\"\"/>";
+
+    format!(
+        "{}<a:theme xmlns:a=\"{}\" name=\"SmartVerses\"><a:themeElements>\
+<a:clrScheme name=\"SmartVerses\">{}</a:clrScheme>\
+<a:fontScheme name=\"SmartVerses\"><a:majorFont>{}</a:majorFont><a:minorFont>{}</a:minorFont></a:fontScheme>\
+<a:fmtScheme name=\"SmartVerses\">\
+<a:fillStyleLst>{}</a:fillStyleLst>\
+<a:lnStyleLst>{}</a:lnStyleLst>\
+<a:effectStyleLst>{}</a:effectStyleLst>\
+<a:bgFillStyleLst>{}</a:bgFillStyleLst>\
+</a:fmtScheme></a:themeElements><a:objectDefaults/><a:extraClrSchemeLst/></a:theme>",
+        XML_DECL,
+        NS_A,
+        clr_scheme,
+        font,
+        font,
+        solid.repeat(3),
+        line.repeat(3),
+        effect.repeat(3),
+        solid.repeat(3)
+    )
+}
+
+fn pptx_slide(slide: &LiveSlide) -> String {
+    let has_sub_item = slide.items.iter().any(|item| item.is_sub_item);
+    let mut paragraphs = String::new();
+    for item in &slide.items {
+        // Parent lines of a parent/child slide render as a smaller heading.
+        let (size, bold) = if item.is_sub_item {
+            (4400, false)
+        } else if has_sub_item {
+            (2800, true)
+        } else {
+            (4000, false)
+        };
+        paragraphs.push_str(&format!(
+            "<a:p><a:pPr algn=\"ctr\"/><a:r><a:rPr lang=\"en-US\" sz=\"{}\" b=\"{}\" dirty=\"0\"><a:solidFill><a:srgbClr val=\"FFFFFF\"/></a:solidFill></a:rPr><a:t>{}</a:t></a:r></a:p>",
+            size,
+            if bold { 1 } else { 0 },
+            xml_escape(&item.text)
+        ));
+    }
+    if paragraphs.is_empty() {
+        paragraphs.push_str("<a:p><a:endParaRPr lang=\"en-US\"/></a:p>");
+    }
+
+    let margin = 457_200; // 0.5in
+    format!(
+        "{}<p:sld xmlns:a=\"{}\" xmlns:r=\"{}\" xmlns:p=\"{}\"><p:cSld><p:spTree>{}\
+<p:sp><p:nvSpPr><p:cNvPr id=\"2\" name=\"Slide Text\"/><p:cNvSpPr txBox=\"1\"/><p:nvPr/></p:nvSpPr>\
+<p:spPr><a:xfrm><a:off x=\"{}\" y=\"{}\"/><a:ext cx=\"{}\" cy=\"{}\"/></a:xfrm><a:prstGeom prst=\"rect\"><a:avLst/></a:prstGeom><a:noFill/></p:spPr>\
+<p:txBody><a:bodyPr wrap=\"square\" anchor=\"ctr\"><a:normAutofit/></a:bodyPr><a:lstStyle/>{}</p:txBody></p:sp>\
+</p:spTree></p:cSld><p:clrMapOvr><a:masterClrMapping/></p:clrMapOvr></p:sld>",
+        XML_DECL,
+        NS_A,
+        NS_R,
+        NS_P,
+        EMPTY_SP_TREE,
+        margin,
+        margin,
+        SLIDE_WIDTH_EMU - 2 * margin,
+        SLIDE_HEIGHT_EMU - 2 * margin,
+        paragraphs
+    )
+}
+
+pub fn export_pptx(session: &LiveSlideSession) -> Result<Vec<u8>, String> {
+    let slide_count = session.slides.len();
+    let created = chrono::DateTime::from_timestamp(session.created_at as i64, 0)
+        .unwrap_or_else(chrono::Utc::now)
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    let mut parts: Vec<(String, String)> = vec![
+        ("[Content_Types].xml".to_string(), pptx_content_types(slide_count)),
+        (
+            "_rels/.rels".to_string(),
+            relationships(&[
+                ("rId1".to_string(), format!("{}/officeDocument", REL_TYPE), "ppt/presentation.xml".to_string()),
+                (
+                    "rId2".to_string(),
+                    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties".to_string(),
+                    "docProps/core.xml".to_string(),
+                ),
+                ("rId3".to_string(), format!("{}/extended-properties", REL_TYPE), "docProps/app.xml".to_string()),
+            ]),
+        ),
+        (
+            "docProps/core.xml".to_string(),
+            format!(
+                "{}<cp:coreProperties xmlns:cp=\"http://schemas.openxmlformats.org/package/2006/metadata/core-properties\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\" xmlns:dcterms=\"http://purl.org/dc/terms/\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\">\
+<dc:title>{}</dc:title><dc:creator>SmartVerses</dc:creator><dcterms:created xsi:type=\"dcterms:W3CDTF\">{}</dcterms:created>\
+</cp:coreProperties>",
+                XML_DECL,
+                xml_escape(&session.name),
+                created
+            ),
+        ),
+        (
+            "docProps/app.xml".to_string(),
+            format!(
+                "{}<Properties xmlns=\"http://schemas.openxmlformats.org/officeDocument/2006/extended-properties\"><Application>SmartVerses</Application><Slides>{}</Slides></Properties>",
+                XML_DECL, slide_count
+            ),
+        ),
+        ("ppt/presentation.xml".to_string(), pptx_presentation(slide_count)),
+        ("ppt/slideMasters/slideMaster1.xml".to_string(), pptx_slide_master()),
+        (
+            "ppt/slideMasters/_rels/slideMaster1.xml.rels".to_string(),
+            relationships(&[
+                ("rId1".to_string(), format!("{}/slideLayout", REL_TYPE), "../slideLayouts/slideLayout1.xml".to_string()),
+                ("rId2".to_string(), format!("{}/theme", REL_TYPE), "../theme/theme1.xml".to_string()),
+            ]),
+        ),
+        ("ppt/slideLayouts/slideLayout1.xml".to_string(), pptx_slide_layout()),
+        (
+            "ppt/slideLayouts/_rels/slideLayout1.xml.rels".to_string(),
+            relationships(&[(
+                "rId1".to_string(),
+                format!("{}/slideMaster", REL_TYPE),
+                "../slideMasters/slideMaster1.xml".to_string(),
+            )]),
+        ),
+        ("ppt/theme/theme1.xml".to_string(), pptx_theme()),
+    ];
+
+    let mut presentation_rels = vec![
+        ("rId1".to_string(), format!("{}/slideMaster", REL_TYPE), "slideMasters/slideMaster1.xml".to_string()),
+        ("rId2".to_string(), format!("{}/theme", REL_TYPE), "theme/theme1.xml".to_string()),
+    ];
+    for (index, slide) in session.slides.iter().enumerate() {
+        let n = index + 1;
+        presentation_rels.push((
+            format!("rId{}", n + 2),
+            format!("{}/slide", REL_TYPE),
+            format!("slides/slide{}.xml", n),
+        ));
+        parts.push((format!("ppt/slides/slide{}.xml", n), pptx_slide(slide)));
+        parts.push((
+            format!("ppt/slides/_rels/slide{}.xml.rels", n),
+            relationships(&[(
+                "rId1".to_string(),
+                format!("{}/slideLayout", REL_TYPE),
+                "../slideLayouts/slideLayout1.xml".to_string(),
+            )]),
+        ));
+    }
+    parts.push((
+        "ppt/_rels/presentation.xml.rels".to_string(),
+        relationships(&presentation_rels),
+    ));
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, content) in parts {
+        zip.start_file(name, stored())
+            .map_err(|e| format!("pptx_write_failed:{}", e))?;
+        zip.write_all(content.as_bytes())
+            .map_err(|e| format!("pptx_write_failed:{}", e))?;
+    }
+    let cursor = zip
+        .finish()
+        .map_err(|e| format!("pptx_write_failed:{}", e))?;
+    Ok(cursor.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verse_variants_match_only_their_own_base() {
+        assert!(is_variant_of("v1", "v1"));
+        assert!(is_variant_of("v1a", "v1"));
+        assert!(!is_variant_of("v10", "v1"));
+        assert!(!is_variant_of("v10a", "v1"));
+        assert!(!is_variant_of("c1", "v1"));
+    }
+}
@@ -0,0 +1,219 @@
+//live_slides_history.rs keeps a bounded revision log per Live Slides session
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+// Oldest revisions are dropped once a session has more than this many.
+pub const MAX_REVISIONS_PER_SESSION: usize = 200;
+
+// Consecutive edits from the same author within this window are folded into
+// a single revision so per-keystroke `text_update`s don't flood the log.
+const COALESCE_WINDOW_MS: u64 = 2000;
+// ...but a revision never spans more than this, so continuous typing still
+// leaves restore points behind.
+const MAX_COALESCE_SPAN_MS: u64 = 30_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveSlideRevision {
+    pub revision: u64,
+    pub session_id: String,
+    pub name: String,
+    pub raw_text: String,
+    pub author: Option<String>,
+    pub source: String, // "create" | "text_update" | "upsert" | "import" | "restore"
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveSlideRevisionDiffLine {
+    pub op: String, // "equal" | "insert" | "delete"
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveSlideRevisionDiff {
+    pub session_id: String,
+    pub from_revision: u64,
+    pub to_revision: u64,
+    pub name_changed: bool,
+    pub lines: Vec<LiveSlideRevisionDiffLine>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SessionHistory {
+    revisions: VecDeque<LiveSlideRevision>,
+    next_revision: u64,
+    // When the first edit folded into the last revision was made.
+    span_start: u64,
+}
+
+impl SessionHistory {
+    pub fn record(
+        &mut self,
+        session_id: &str,
+        name: &str,
+        raw_text: &str,
+        author: Option<String>,
+        source: &str,
+        timestamp: u64,
+    ) -> Option<&LiveSlideRevision> {
+        if let Some(last) = self.revisions.back_mut() {
+            if last.raw_text == raw_text && last.name == name {
+                return None;
+            }
+
+            // Never fold a destructive edit (select-all + delete, or removing a
+            // line) into the previous revision, otherwise the text it removed
+            // would be lost.
+            let destructive = raw_text.len() < last.raw_text.len() / 2
+                || raw_text.lines().count() < last.raw_text.lines().count();
+            let coalesce = source == "text_update"
+                && last.source == "text_update"
+                && last.author == author
+                && last.name == name
+                && timestamp.saturating_sub(last.timestamp) <= COALESCE_WINDOW_MS
+                && timestamp.saturating_sub(self.span_start) <= MAX_COALESCE_SPAN_MS
+                && !destructive;
+            if coalesce {
+                last.raw_text = raw_text.to_string();
+                last.timestamp = timestamp;
+                return self.revisions.back();
+            }
+        }
+
+        self.next_revision += 1;
+        self.span_start = timestamp;
+        self.revisions.push_back(LiveSlideRevision {
+            revision: self.next_revision,
+            session_id: session_id.to_string(),
+            name: name.to_string(),
+            raw_text: raw_text.to_string(),
+            author,
+            source: source.to_string(),
+            timestamp,
+        });
+        while self.revisions.len() > MAX_REVISIONS_PER_SESSION {
+            self.revisions.pop_front();
+        }
+        self.revisions.back()
+    }
+
+    pub fn list(&self) -> Vec<LiveSlideRevision> {
+        self.revisions.iter().cloned().collect()
+    }
+
+    pub fn get(&self, revision: u64) -> Option<&LiveSlideRevision> {
+        self.revisions.iter().find(|r| r.revision == revision)
+    }
+
+    pub fn diff(&self, from_revision: u64, to_revision: u64) -> Result<LiveSlideRevisionDiff, String> {
+        let from = self
+            .get(from_revision)
+            .ok_or_else(|| format!("revision_not_found:{}", from_revision))?;
+        let to = self
+            .get(to_revision)
+            .ok_or_else(|| format!("revision_not_found:{}", to_revision))?;
+
+        Ok(LiveSlideRevisionDiff {
+            session_id: to.session_id.clone(),
+            from_revision,
+            to_revision,
+            name_changed: from.name != to.name,
+            lines: diff_lines(&from.raw_text, &to.raw_text),
+        })
+    }
+}
+
+// Line-based LCS diff. Notepad sessions are a few hundred lines at most, so the
+// quadratic table is fine here.
+fn diff_lines(old: &str, new: &str) -> Vec<LiveSlideRevisionDiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let n = a.len();
+    let m = b.len();
+
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |op: &str, text: &str| LiveSlideRevisionDiffLine {
+        op: op.to_string(),
+        text: text.to_string(),
+    };
+
+    let mut lines = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            lines.push(line("equal", a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(line("delete", a[i]));
+            i += 1;
+        } else {
+            lines.push(line("insert", b[j]));
+            j += 1;
+        }
+    }
+    lines.extend(a[i..].iter().map(|t| line("delete", t)));
+    lines.extend(b[j..].iter().map(|t| line("insert", t)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_update(history: &mut SessionHistory, raw_text: &str, timestamp: u64) {
+        history.record("s1", "Song", raw_text, Some("alice".to_string()), "text_update", timestamp);
+    }
+
+    #[test]
+    fn keystrokes_within_the_window_are_coalesced() {
+        let mut history = SessionHistory::default();
+        text_update(&mut history, "A", 0);
+        text_update(&mut history, "Am", 500);
+        text_update(&mut history, "Ama", 1000);
+        let revisions = history.list();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].raw_text, "Ama");
+    }
+
+    #[test]
+    fn continuous_typing_is_split_into_bounded_revisions() {
+        let mut history = SessionHistory::default();
+        let mut text = String::new();
+        for i in 0..120u64 {
+            text.push('a');
+            text_update(&mut history, &text, i * 1000);
+        }
+        let revisions = history.list();
+        assert!(revisions.len() >= 4, "got {} revisions", revisions.len());
+        assert!(revisions
+            .windows(2)
+            .all(|pair| pair[1].timestamp - pair[0].timestamp <= MAX_COALESCE_SPAN_MS + 1000));
+    }
+
+    #[test]
+    fn text_deleted_line_by_line_stays_restorable() {
+        let mut history = SessionHistory::default();
+        let lines = ["Amazing grace", "How sweet the sound", "That saved a wretch", "Like me"];
+        let full = lines.join("\n");
+        for n in 1..=lines.len() {
+            text_update(&mut history, &lines[..n].join("\n"), n as u64 * 300);
+        }
+        for n in (0..lines.len()).rev() {
+            text_update(&mut history, &lines[..n].join("\n"), 2000 + (lines.len() - n) as u64 * 300);
+        }
+        let revisions = history.list();
+        assert!(revisions.iter().any(|r| r.raw_text == full));
+        assert_eq!(revisions.last().map(|r| r.raw_text.as_str()), Some(""));
+    }
+}
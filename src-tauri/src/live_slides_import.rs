@@ -0,0 +1,710 @@
+//live_slides_import.rs converts song and outline files into Live Slides notepad text
+use serde::Serialize;
+use std::io::Read;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    OpenLyrics,
+    ChordPro,
+    SongSelect, // CCLI SongSelect .usr or plain text export
+    Docx,
+}
+
+impl ImportFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "openlyrics" | "openlp" | "xml" => Ok(ImportFormat::OpenLyrics),
+            "chordpro" | "cho" | "crd" | "chopro" | "pro" => Ok(ImportFormat::ChordPro),
+            "songselect" | "ccli" | "usr" | "txt" | "text" => Ok(ImportFormat::SongSelect),
+            "docx" | "word" => Ok(ImportFormat::Docx),
+            other => Err(format!("unsupported_import_format:{}", other)),
+        }
+    }
+
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        ImportFormat::parse(extension).ok()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportWarning {
+    pub code: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedContent {
+    pub title: Option<String>,
+    pub ccli_number: Option<String>,
+    pub raw_text: String,
+    pub warnings: Vec<ImportWarning>,
+}
+
+pub fn import_bytes(data: &[u8], format: ImportFormat) -> Result<ImportedContent, String> {
+    match format {
+        ImportFormat::Docx => import_docx(data),
+        _ => {
+            let text = String::from_utf8_lossy(data);
+            // Strip a UTF-8 BOM, common in files exported on Windows.
+            let text = text.trim_start_matches('\u{feff}');
+            match format {
+                ImportFormat::OpenLyrics => import_openlyrics(text),
+                ImportFormat::ChordPro => Ok(import_chordpro(text)),
+                _ => Ok(import_songselect(text)),
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Notepad text builder
+// ============================================================================
+
+// Builds notepad text: a label as parent line, its lines as tab-indented
+// children and a blank line between sections.
+#[derive(Default)]
+struct NotepadBuilder {
+    sections: Vec<(Option<String>, Vec<String>)>,
+}
+
+impl NotepadBuilder {
+    fn push_section(&mut self, label: Option<String>, lines: Vec<String>) {
+        let lines: Vec<String> = lines
+            .into_iter()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect();
+        // A label without lines (e.g. a ChordPro comment label at the end) has no slides.
+        if lines.is_empty() {
+            return;
+        }
+        self.sections.push((label, lines));
+    }
+
+    fn build(self) -> String {
+        let blocks: Vec<String> = self
+            .sections
+            .into_iter()
+            .map(|(label, lines)| match label {
+                Some(label) => {
+                    let mut block = label;
+                    for line in lines {
+                        block.push_str("\n\t");
+                        block.push_str(&line);
+                    }
+                    block
+                }
+                None => lines.join("\n"),
+            })
+            .collect();
+        blocks.join("\n\n")
+    }
+}
+
+fn warning(code: &str, detail: impl Into<String>) -> ImportWarning {
+    ImportWarning {
+        code: code.to_string(),
+        detail: detail.into(),
+    }
+}
+
+// "v1" -> "Verse 1", "c" -> "Chorus", "p2" -> "Pre-Chorus 2".
+fn label_from_verse_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    let mut chars = name.chars();
+    let prefix = chars.next()?.to_ascii_lowercase();
+    let rest: String = chars.collect();
+    let word = match prefix {
+        'v' => "Verse",
+        'c' => "Chorus",
+        'p' => "Pre-Chorus",
+        'b' => "Bridge",
+        'i' => "Intro",
+        'e' => "Ending",
+        'o' => "Other",
+        _ => return None,
+    };
+    if !rest.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    if rest.is_empty() {
+        Some(word.to_string())
+    } else {
+        Some(format!("{} {}", word, rest))
+    }
+}
+
+// Section label words. Abbreviations such as "V" or "C" and short words
+// such as "End" are left out: on a line of their own they are usually lyrics.
+const SECTION_WORDS: &[&str] = &[
+    "verse", "chorus", "refrain", "prechorus", "bridge", "intro", "ending", "outro", "coda", "tag",
+    "misc", "interlude", "instrumental", "vamp", "turnaround",
+];
+
+// Returns the label when `line` is a section heading: a label word followed by
+// a number or colon ("Verse 2", "Chorus:") or in brackets ("[Bridge]"). With
+// `bare_words` a lone label word ("Chorus") counts too, as SongSelect writes them.
+fn section_label(line: &str, bare_words: bool) -> Option<String> {
+    let line = line.trim();
+    let (inner, marked) = match line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+        Some(inner) => (inner.trim(), true),
+        None => match line.strip_suffix(':') {
+            Some(inner) => (inner.trim(), true),
+            None => (line, false),
+        },
+    };
+    let split = inner.find(|c: char| c.is_ascii_digit()).unwrap_or(inner.len());
+    let (word, number) = inner.split_at(split);
+    let number = number.trim();
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let word = word.trim().to_lowercase().replace(['-', ' '], "");
+    if !SECTION_WORDS.contains(&word.as_str()) {
+        return None;
+    }
+    (marked || !number.is_empty() || bare_words).then(|| inner.to_string())
+}
+
+// ============================================================================
+// OpenLyrics XML
+// ============================================================================
+
+fn openlyrics_lines(node: roxmltree::Node, current: &mut String, lines: &mut Vec<String>, warnings: &mut Vec<ImportWarning>) {
+    for child in node.children() {
+        if child.is_text() {
+            current.push_str(child.text().unwrap_or(""));
+            continue;
+        }
+        if !child.is_element() {
+            continue;
+        }
+        match child.tag_name().name() {
+            "br" => lines.push(std::mem::take(current)),
+            // Chords may wrap the syllable they sit on (OpenLyrics 0.9), tags wrap formatted text.
+            "chord" | "tag" => openlyrics_lines(child, current, lines, warnings),
+            "comment" => {}
+            other => warnings.push(warning("unsupported_element", format!("<{}> inside <lines>", other))),
+        }
+    }
+}
+
+pub fn import_openlyrics(text: &str) -> Result<ImportedContent, String> {
+    let doc = roxmltree::Document::parse(text).map_err(|e| format!("xml_parse_failed:{}", e))?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "song" {
+        return Err("not_openlyrics:missing <song> root".to_string());
+    }
+
+    let mut warnings = Vec::new();
+    let properties = root.children().find(|n| n.has_tag_name("properties"));
+    let property = |tag: &str| -> Option<roxmltree::Node> {
+        properties.and_then(|p| p.descendants().find(|n| n.has_tag_name(tag)))
+    };
+    let title = property("title").and_then(|n| n.text()).map(|t| t.trim().to_string());
+    let ccli_number = property("ccliNo").and_then(|n| n.text()).map(|t| t.trim().to_string());
+    let verse_order: Vec<String> = property("verseOrder")
+        .and_then(|n| n.text())
+        .map(|t| t.split_whitespace().map(|s| s.to_string()).collect())
+        .unwrap_or_default();
+
+    let lyrics = root
+        .children()
+        .find(|n| n.has_tag_name("lyrics"))
+        .ok_or_else(|| "not_openlyrics:missing <lyrics>".to_string())?;
+
+    let mut verses: Vec<(String, Option<String>, Vec<String>)> = Vec::new();
+    for node in lyrics.children().filter(|n| n.is_element()) {
+        if !node.has_tag_name("verse") {
+            warnings.push(warning("unsupported_element", format!("<{}> inside <lyrics>", node.tag_name().name())));
+            continue;
+        }
+        let name = node.attribute("name").unwrap_or("").to_string();
+        let label = label_from_verse_name(&name);
+        if label.is_none() {
+            warnings.push(warning("unknown_verse_name", name.clone()));
+        }
+
+        let mut lines = Vec::new();
+        for lines_node in node.children().filter(|n| n.is_element()) {
+            if !lines_node.has_tag_name("lines") {
+                warnings.push(warning("unsupported_element", format!("<{}> inside <verse>", lines_node.tag_name().name())));
+                continue;
+            }
+            let mut current = String::new();
+            openlyrics_lines(lines_node, &mut current, &mut lines, &mut warnings);
+            lines.push(current);
+        }
+        verses.push((name, label, lines));
+    }
+
+    let mut builder = NotepadBuilder::default();
+    let section_for = |verse: &(String, Option<String>, Vec<String>)| {
+        (Some(verse.1.clone().unwrap_or_else(|| verse.0.clone())), verse.2.clone())
+    };
+    if verse_order.is_empty() {
+        for verse in &verses {
+            let (label, lines) = section_for(verse);
+            builder.push_section(label, lines);
+        }
+    } else {
+        for name in &verse_order {
+            match verses.iter().find(|v| v.0.eq_ignore_ascii_case(name)) {
+                Some(verse) => {
+                    let (label, lines) = section_for(verse);
+                    builder.push_section(label, lines);
+                }
+                None => warnings.push(warning("verse_order_missing_verse", name.clone())),
+            }
+        }
+    }
+
+    Ok(ImportedContent {
+        title,
+        ccli_number,
+        raw_text: builder.build(),
+        warnings,
+    })
+}
+
+// ============================================================================
+// ChordPro
+// ============================================================================
+
+fn strip_chords(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_chord = false;
+    for c in line.chars() {
+        match c {
+            '[' => in_chord = true,
+            ']' if in_chord => in_chord = false,
+            _ if !in_chord => out.push(c),
+            _ => {}
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn import_chordpro(text: &str) -> ImportedContent {
+    let mut warnings = Vec::new();
+    let mut builder = NotepadBuilder::default();
+    let mut title = None;
+    let mut ccli_number = None;
+
+    let mut label: Option<String> = None;
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_environment = false; // inside {start_of_*} ... {end_of_*}
+    let mut skipping = false; // inside tab/grid blocks, which have no lyrics
+    let mut verse_count = 0;
+
+    // A label stays pending until lines follow it; a newer label replaces it.
+    let mut flush = |label: &mut Option<String>, lines: &mut Vec<String>, builder: &mut NotepadBuilder| {
+        if lines.is_empty() {
+            return;
+        }
+        // Number unlabeled stanzas after any explicitly labeled verses.
+        let section = match label.take() {
+            Some(label) => {
+                if label.to_lowercase().starts_with("verse") {
+                    verse_count += 1;
+                }
+                label
+            }
+            None => {
+                verse_count += 1;
+                format!("Verse {}", verse_count)
+            }
+        };
+        builder.push_section(Some(section), std::mem::take(lines));
+    };
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim();
+        let line_no = index + 1;
+
+        if line.starts_with('{') && line.ends_with('}') {
+            let inner = &line[1..line.len() - 1];
+            let (directive, value) = match inner.find(':') {
+                Some(pos) => (inner[..pos].trim().to_lowercase(), inner[pos + 1..].trim().to_string()),
+                None => (inner.trim().to_lowercase(), String::new()),
+            };
+            let value_or = |fallback: &str| if value.is_empty() { fallback.to_string() } else { value.clone() };
+
+            match directive.as_str() {
+                "title" | "t" => title = Some(value),
+                "subtitle" | "st" | "artist" | "composer" | "lyricist" | "copyright" | "key" | "tempo"
+                | "time" | "capo" | "album" | "year" | "duration" | "meta" => {}
+                "ccli" | "ccli_number" | "ccli_song" => ccli_number = Some(value),
+                "start_of_chorus" | "soc" => {
+                    flush(&mut label, &mut lines, &mut builder);
+                    label = Some(value_or("Chorus"));
+                    in_environment = true;
+                }
+                "start_of_verse" | "sov" => {
+                    flush(&mut label, &mut lines, &mut builder);
+                    label = if value.is_empty() { None } else { Some(value) };
+                    in_environment = true;
+                }
+                "start_of_bridge" | "sob" => {
+                    flush(&mut label, &mut lines, &mut builder);
+                    label = Some(value_or("Bridge"));
+                    in_environment = true;
+                }
+                "end_of_chorus" | "eoc" | "end_of_verse" | "eov" | "end_of_bridge" | "eob" => {
+                    flush(&mut label, &mut lines, &mut builder);
+                    in_environment = false;
+                }
+                "chorus" => {
+                    // Repeat-chorus marker: repeat the most recent chorus.
+                    flush(&mut label, &mut lines, &mut builder);
+                    let chorus = builder
+                        .sections
+                        .iter()
+                        .rev()
+                        .find(|(l, _)| l.as_deref().is_some_and(|l| l.to_lowercase().starts_with("chorus")))
+                        .cloned();
+                    match chorus {
+                        Some((chorus_label, chorus_lines)) => {
+                            builder.push_section(Some(if value.is_empty() { chorus_label.unwrap_or_default() } else { value.clone() }), chorus_lines)
+                        }
+                        None => warnings.push(warning("chorus_not_found", format!("line {}", line_no))),
+                    }
+                }
+                "comment" | "c" | "comment_italic" | "ci" | "comment_box" | "cb" | "highlight" => {
+                    // Comments are commonly used as section labels ({c: Verse 2}).
+                    flush(&mut label, &mut lines, &mut builder);
+                    if let Some(value) = section_label(&value, true) {
+                        label = Some(value);
+                    } else {
+                        warnings.push(warning("comment_skipped", format!("line {}: {}", line_no, value)));
+                    }
+                }
+                "start_of_tab" | "sot" | "start_of_grid" | "sog" => {
+                    skipping = true;
+                    warnings.push(warning("block_skipped", format!("line {}: {}", line_no, directive)));
+                }
+                "end_of_tab" | "eot" | "end_of_grid" | "eog" => skipping = false,
+                "new_page" | "np" | "new_physical_page" | "npp" | "column_break" | "colb" => {
+                    flush(&mut label, &mut lines, &mut builder);
+                }
+                other => warnings.push(warning("unknown_directive", format!("line {}: {}", line_no, other))),
+            }
+            continue;
+        }
+
+        if skipping || line.starts_with('#') {
+            continue;
+        }
+
+        if line.is_empty() {
+            if !in_environment {
+                flush(&mut label, &mut lines, &mut builder);
+            }
+            continue;
+        }
+
+        let lyric = strip_chords(line);
+        if lyric.is_empty() {
+            continue; // chord-only line
+        }
+        if lines.is_empty() && !in_environment {
+            if let Some(section) = section_label(&lyric, false) {
+                label = Some(section);
+                continue;
+            }
+        }
+        lines.push(lyric);
+    }
+    flush(&mut label, &mut lines, &mut builder);
+
+    ImportedContent {
+        title,
+        ccli_number,
+        raw_text: builder.build(),
+        warnings,
+    }
+}
+
+// ============================================================================
+// CCLI SongSelect (.usr and text export)
+// ============================================================================
+
+pub fn import_songselect(text: &str) -> ImportedContent {
+    if text.lines().any(|l| l.trim_start().starts_with("Words=")) {
+        import_songselect_usr(text)
+    } else {
+        import_songselect_text(text)
+    }
+}
+
+fn import_songselect_usr(text: &str) -> ImportedContent {
+    let mut warnings = Vec::new();
+    let mut title = None;
+    let mut ccli_number = None;
+    let mut fields: Vec<String> = Vec::new();
+    let mut words: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        // Song header: "[S A12345]", the number after "A" is the CCLI song number.
+        if let Some(header) = line.strip_prefix("[S ").and_then(|h| h.strip_suffix(']')) {
+            let digits: String = header.chars().filter(|c| c.is_ascii_digit()).collect();
+            if !digits.is_empty() {
+                ccli_number = Some(digits);
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key.trim() {
+            "Title" => title = Some(value.trim().to_string()),
+            "Fields" => fields = value.split("/t").map(|s| s.trim().to_string()).collect(),
+            "Words" => words = value.split("/t").map(|s| s.to_string()).collect(),
+            _ => {}
+        }
+    }
+
+    if fields.len() != words.len() {
+        warnings.push(warning(
+            "field_count_mismatch",
+            format!("{} fields, {} word blocks", fields.len(), words.len()),
+        ));
+    }
+
+    let mut builder = NotepadBuilder::default();
+    for (index, block) in words.iter().enumerate() {
+        let label = fields
+            .get(index)
+            .filter(|f| !f.is_empty())
+            .cloned()
+            .unwrap_or_else(|| format!("Verse {}", index + 1));
+        let lines = block.split("/n").map(|l| l.to_string()).collect();
+        builder.push_section(Some(label), lines);
+    }
+
+    ImportedContent {
+        title,
+        ccli_number,
+        raw_text: builder.build(),
+        warnings,
+    }
+}
+
+fn import_songselect_text(text: &str) -> ImportedContent {
+    let mut warnings = Vec::new();
+    let mut builder = NotepadBuilder::default();
+    let mut title = None;
+    let mut ccli_number = None;
+
+    let mut label: Option<String> = None;
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_footer = false;
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim();
+
+        // Footer: "CCLI Song # 22025", author, copyright and license lines.
+        if let Some(rest) = line.strip_prefix("CCLI Song #") {
+            ccli_number = Some(rest.trim().to_string());
+            in_footer = true;
+            continue;
+        }
+        if in_footer || line.starts_with("CCLI License") || line.starts_with("For use solely") {
+            continue;
+        }
+
+        if line.is_empty() {
+            if !lines.is_empty() {
+                builder.push_section(label.take(), std::mem::take(&mut lines));
+            }
+            continue;
+        }
+
+        if title.is_none() && label.is_none() && lines.is_empty() && builder.sections.is_empty() {
+            title = Some(line.to_string());
+            continue;
+        }
+
+        if lines.is_empty() {
+            if let Some(section) = section_label(line, true) {
+                label = Some(section);
+                continue;
+            }
+        }
+
+        if label.is_none() && lines.is_empty() {
+            warnings.push(warning("unlabeled_section", format!("line {}: {}", index + 1, line)));
+        }
+        lines.push(line.to_string());
+    }
+    builder.push_section(label, lines);
+
+    ImportedContent {
+        title,
+        ccli_number,
+        raw_text: builder.build(),
+        warnings,
+    }
+}
+
+// ============================================================================
+// Word (.docx) sermon notes
+// ============================================================================
+
+fn read_zip_entry(archive: &mut zip::ZipArchive<std::io::Cursor<&[u8]>>, name: &str) -> Option<String> {
+    let mut file = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+pub fn import_docx(data: &[u8]) -> Result<ImportedContent, String> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data))
+        .map_err(|e| format!("docx_open_failed:{}", e))?;
+    let document = read_zip_entry(&mut archive, "word/document.xml")
+        .ok_or_else(|| "docx_missing_document".to_string())?;
+    let core = read_zip_entry(&mut archive, "docProps/core.xml");
+
+    let doc = roxmltree::Document::parse(&document).map_err(|e| format!("xml_parse_failed:{}", e))?;
+    let mut warnings = Vec::new();
+
+    let mut title = core.as_deref().and_then(|core| {
+        let doc = roxmltree::Document::parse(core).ok()?;
+        let title = doc.descendants().find(|n| n.has_tag_name("title"))?.text()?.trim().to_string();
+        if title.is_empty() {
+            None
+        } else {
+            Some(title)
+        }
+    });
+
+    let body = doc
+        .descendants()
+        .find(|n| n.has_tag_name("body"))
+        .ok_or_else(|| "docx_missing_body".to_string())?;
+
+    // Headings and plain paragraphs become parent lines; list items become
+    // their children. Nested list levels are flattened to one level.
+    let mut builder = NotepadBuilder::default();
+    let mut parent: Option<String> = None;
+    let mut children: Vec<String> = Vec::new();
+    let mut flattened_nested = false;
+
+    for block in body.children().filter(|n| n.is_element()) {
+        match block.tag_name().name() {
+            "p" => {}
+            "sectPr" | "bookmarkStart" | "bookmarkEnd" => continue,
+            "tbl" => {
+                warnings.push(warning("table_skipped", "tables are not imported"));
+                continue;
+            }
+            other => {
+                warnings.push(warning("unsupported_element", format!("<w:{}>", other)));
+                continue;
+            }
+        }
+
+        let text: String = block
+            .descendants()
+            .filter(|n| n.has_tag_name("t") || n.has_tag_name("tab"))
+            .map(|n| if n.has_tag_name("tab") { " " } else { n.text().unwrap_or("") })
+            .collect();
+        let text = text.trim().to_string();
+        if block.descendants().any(|n| n.has_tag_name("drawing") || n.has_tag_name("pict")) {
+            warnings.push(warning("image_skipped", if text.is_empty() { "image" } else { text.as_str() }));
+        }
+        if text.is_empty() {
+            continue;
+        }
+
+        let properties = block.children().find(|n| n.has_tag_name("pPr"));
+        let style = properties
+            .and_then(|p| p.children().find(|n| n.has_tag_name("pStyle")))
+            .and_then(|s| s.attributes().find(|a| a.name() == "val").map(|a| a.value().to_lowercase()))
+            .unwrap_or_default();
+        let list_level = properties
+            .and_then(|p| p.descendants().find(|n| n.has_tag_name("ilvl")))
+            .and_then(|l| l.attributes().find(|a| a.name() == "val").and_then(|a| a.value().parse::<u32>().ok()));
+        let is_list = list_level.is_some() || style.starts_with("listparagraph") || style.starts_with("listbullet");
+
+        if style == "title" && title.is_none() {
+            title = Some(text);
+            continue;
+        }
+
+        if is_list {
+            if list_level.unwrap_or(0) > 0 && !flattened_nested {
+                warnings.push(warning("nested_list_flattened", text.clone()));
+                flattened_nested = true;
+            }
+            if parent.is_none() && children.is_empty() {
+                warnings.push(warning("list_without_heading", text.clone()));
+            }
+            children.push(text);
+        } else {
+            if parent.is_some() || !children.is_empty() {
+                builder.push_section(parent.take(), std::mem::take(&mut children));
+            }
+            parent = Some(text);
+        }
+    }
+    if parent.is_some() || !children.is_empty() {
+        builder.push_section(parent, children);
+    }
+
+    Ok(ImportedContent {
+        title,
+        ccli_number: None,
+        raw_text: builder.build(),
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_word_lyric_lines_are_not_labels() {
+        let text = "{title: Hymn}\nVerse 1:\nO come let us adore Him\n\nO\nI\nEnd\nTag\n";
+        let imported = import_chordpro(text);
+        assert_eq!(
+            imported.raw_text,
+            "Verse 1\n\tO come let us adore Him\n\nVerse 2\n\tO\n\tI\n\tEnd\n\tTag"
+        );
+    }
+
+    #[test]
+    fn marked_labels_are_recognised() {
+        assert_eq!(section_label("Verse 2", false).as_deref(), Some("Verse 2"));
+        assert_eq!(section_label("Chorus:", false).as_deref(), Some("Chorus"));
+        assert_eq!(section_label("[Pre-Chorus]", false).as_deref(), Some("Pre-Chorus"));
+        assert_eq!(section_label("Chorus", false), None);
+        assert_eq!(section_label("Chorus", true).as_deref(), Some("Chorus"));
+        assert_eq!(section_label("O", true), None);
+        assert_eq!(section_label("I", true), None);
+        assert_eq!(section_label("End:", true), None);
+    }
+
+    #[test]
+    fn labels_without_lines_are_skipped() {
+        let text = "{c: Chorus}\n\n{c: Verse 1}\nAmazing grace\n\n{c: Bridge}\n";
+        let imported = import_chordpro(text);
+        assert_eq!(imported.raw_text, "Verse 1\n\tAmazing grace");
+    }
+
+    #[test]
+    fn songselect_text_keeps_lyric_lines_starting_a_section() {
+        let text = "Hymn\n\nVerse 1\nO\nI love You Lord\n\nChorus\nI\nWill sing\n\nCCLI Song # 12345\n";
+        let imported = import_songselect(text);
+        assert_eq!(imported.title.as_deref(), Some("Hymn"));
+        assert_eq!(imported.ccli_number.as_deref(), Some("12345"));
+        assert_eq!(
+            imported.raw_text,
+            "Verse 1\n\tO\n\tI love You Lord\n\nChorus\n\tI\n\tWill sing"
+        );
+    }
+}
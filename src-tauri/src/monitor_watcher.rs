@@ -0,0 +1,190 @@
+//monitor_watcher.rs detects monitor hot-plug and moves display windows back to their monitor
+use crate::monitors::{self, SafeMonitorInfo};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Monitor};
+
+// Neither tauri nor tao report monitor changes, so the list is polled.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// The monitor a window was opened on. Kept as a fingerprint because the
+// sorted index shifts whenever a monitor is added, removed or rearranged.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MonitorTarget {
+    pub monitor: SafeMonitorInfo,
+    pub fullscreen: bool, // cover the whole monitor rather than centre on it
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorsChanged {
+    pub monitors: Vec<SafeMonitorInfo>,
+    pub added: Vec<SafeMonitorInfo>,
+    pub removed: Vec<SafeMonitorInfo>,
+    pub restored_windows: Vec<String>,
+}
+
+lazy_static::lazy_static! {
+    static ref WINDOW_TARGETS: Mutex<HashMap<String, MonitorTarget>> = Mutex::new(HashMap::new());
+    static ref MONITOR_WATCHER_STARTED: AtomicBool = AtomicBool::new(false);
+}
+
+/// Records the monitor a window was placed on so it can be restored there.
+pub fn remember_window_monitor(label: &str, monitor: &Monitor, fullscreen: bool) {
+    let target = MonitorTarget {
+        monitor: SafeMonitorInfo::from_monitor(monitor),
+        fullscreen,
+    };
+    if let Ok(mut targets) = WINDOW_TARGETS.lock() {
+        targets.insert(label.to_string(), target);
+    }
+}
+
+pub fn forget_window_monitor(label: &str) {
+    if let Ok(mut targets) = WINDOW_TARGETS.lock() {
+        targets.remove(label);
+    }
+}
+
+/// Puts a window on `monitor`, covering it when `fullscreen`, otherwise centred.
+/// Returns false when the window is already there.
+pub fn move_window_to(window: &tauri::WebviewWindow, monitor: &Monitor, fullscreen: bool) -> Result<bool, String> {
+    let pos = *monitor.position();
+    let size = *monitor.size();
+    let current = window.outer_position().ok();
+    if fullscreen {
+        if current == Some(pos) && window.outer_size().ok() == Some(size) {
+            return Ok(false);
+        }
+        // Leaving fullscreen first; most platforms ignore moves while fullscreen.
+        let was_fullscreen = window.is_fullscreen().unwrap_or(false);
+        if was_fullscreen {
+            window.set_fullscreen(false).map_err(|e| e.to_string())?;
+        }
+        window
+            .set_position(tauri::Position::Physical(pos))
+            .map_err(|e| e.to_string())?;
+        window
+            .set_size(tauri::Size::Physical(size))
+            .map_err(|e| e.to_string())?;
+        if was_fullscreen {
+            window.set_fullscreen(true).map_err(|e| e.to_string())?;
+        }
+        return Ok(true);
+    }
+
+    // Windowed dialogs are re-centred on the monitor when they are off it.
+    let outer = window.outer_size().map_err(|e| e.to_string())?;
+    if let Some(current) = current {
+        let inside = current.x >= pos.x
+            && current.y >= pos.y
+            && current.x < pos.x + size.width as i32
+            && current.y < pos.y + size.height as i32;
+        if inside {
+            return Ok(false);
+        }
+    }
+    let x = pos.x + (size.width as i32 - outer.width as i32) / 2;
+    let y = pos.y + (size.height as i32 - outer.height as i32) / 2;
+    window
+        .set_position(tauri::Position::Physical(tauri::PhysicalPosition::new(x, y)))
+        .map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// Moves every tracked window whose monitor is connected back onto it.
+/// Returns the labels of the windows that were moved.
+pub fn restore_windows(app: &AppHandle, monitors: &[Monitor]) -> Vec<String> {
+    let targets: Vec<(String, MonitorTarget)> = match WINDOW_TARGETS.lock() {
+        Ok(mut targets) => {
+            // Windows closed since they were opened no longer need restoring.
+            targets.retain(|label, _| app.get_webview_window(label).is_some());
+            targets.iter().map(|(l, t)| (l.clone(), t.clone())).collect()
+        }
+        Err(_) => return Vec::new(),
+    };
+
+    let mut restored = Vec::new();
+    for (label, target) in targets {
+        let Some(window) = app.get_webview_window(&label) else {
+            continue;
+        };
+        let Some(monitor) = monitors::resolve(monitors, &target.monitor) else {
+            continue;
+        };
+        match move_window_to(&window, monitor, target.fullscreen) {
+            Ok(true) => {
+                println!(
+                    "[Display] Restored {} to monitor {:?} ({}x{})",
+                    label,
+                    monitor.name(),
+                    monitor.size().width,
+                    monitor.size().height
+                );
+                restored.push(label);
+            }
+            Ok(false) => {}
+            Err(e) => eprintln!("[Display] Failed to restore {}: {}", label, e),
+        }
+    }
+    restored
+}
+
+/// Starts the background watcher once. On any add/remove/resolution change it
+/// restores tracked windows and emits `monitors_changed`.
+pub fn start_monitor_watcher(app: &AppHandle) {
+    if MONITOR_WATCHER_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let mut last: Option<Vec<SafeMonitorInfo>> = None;
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let mut monitors = match app.available_monitors() {
+                Ok(monitors) => monitors,
+                Err(e) => {
+                    eprintln!("[Display] Failed to poll monitors: {:?}", e);
+                    continue;
+                }
+            };
+            // An empty list is transient (e.g. display sleep); keep the last layout.
+            if monitors.is_empty() {
+                continue;
+            }
+            monitors::sort_monitors(&mut monitors);
+            let current: Vec<SafeMonitorInfo> = monitors.iter().map(SafeMonitorInfo::from_monitor).collect();
+            let Some(previous) = last.replace(current.clone()) else {
+                continue;
+            };
+
+            let added: Vec<SafeMonitorInfo> = current
+                .iter()
+                .filter(|m| !previous.contains(m))
+                .cloned()
+                .collect();
+            let removed: Vec<SafeMonitorInfo> = previous
+                .iter()
+                .filter(|p| !current.contains(p))
+                .cloned()
+                .collect();
+            if added.is_empty() && removed.is_empty() {
+                continue;
+            }
+
+            println!("[Display] Monitors changed: {} added, {} removed", added.len(), removed.len());
+            let restored_windows = restore_windows(&app, &monitors);
+            let _ = app.emit(
+                "monitors_changed",
+                MonitorsChanged {
+                    monitors: current,
+                    added,
+                    removed,
+                    restored_windows,
+                },
+            );
+        }
+    });
+}
@@ -0,0 +1,195 @@
+//monitors.rs orders and resolves monitors by a stable fingerprint and stores named display layout profiles
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::Monitor;
+
+pub const LAYOUT_PROFILES_FILE_NAME: &str = "display-layouts.json";
+const MAX_PROFILE_NAME_LEN: usize = 64;
+
+// Safe monitor information structure for serialization
+// Note: workArea is not included as it's not available via the Monitor API
+// and was causing serialization errors when it was None
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafeMonitorInfo {
+    pub name: Option<String>,
+    pub position: (i32, i32),
+    pub size: (u32, u32),
+    pub scale_factor: f64,
+    // "name@WxH*scale+x+y"; informational, matching uses the fields above.
+    #[serde(default)]
+    pub fingerprint: String,
+}
+
+impl SafeMonitorInfo {
+    pub fn from_monitor(monitor: &Monitor) -> Self {
+        let pos = monitor.position();
+        let size = monitor.size();
+        let mut info = SafeMonitorInfo {
+            name: monitor.name().cloned(),
+            position: (pos.x, pos.y),
+            size: (size.width, size.height),
+            scale_factor: monitor.scale_factor(),
+            fingerprint: String::new(),
+        };
+        info.fingerprint = fingerprint(&info);
+        info
+    }
+}
+
+pub fn fingerprint(info: &SafeMonitorInfo) -> String {
+    format!(
+        "{}@{}x{}*{}+{}+{}",
+        info.name.as_deref().unwrap_or(""),
+        info.size.0,
+        info.size.1,
+        info.scale_factor,
+        info.position.0,
+        info.position.1
+    )
+}
+
+// Primary (at the origin) first, then left-to-right, top-to-bottom. This is
+// the order monitor indexes in settings refer to.
+pub fn sort_monitors(monitors: &mut [Monitor]) {
+    monitors.sort_by_cached_key(|monitor| {
+        let info = SafeMonitorInfo::from_monitor(monitor);
+        (
+            info.position != (0, 0),
+            info.position.0,
+            info.position.1,
+            info.size.0,
+            info.size.1,
+            info.name.unwrap_or_default(),
+        )
+    });
+}
+
+// How well `candidate` matches a saved fingerprint; None when it can't be the
+// same physical monitor. The name is required when one was recorded (the size
+// may change with the resolution); unnamed monitors must match in size.
+// Position only breaks ties between identical monitors.
+fn match_score(candidate: &SafeMonitorInfo, wanted: &SafeMonitorInfo) -> Option<u32> {
+    let mut score = 0;
+    match wanted.name.as_deref() {
+        Some(name) if candidate.name.as_deref() == Some(name) => score += 8,
+        Some(_) => return None,
+        None if candidate.size != wanted.size => return None,
+        None => {}
+    }
+    if candidate.size == wanted.size {
+        score += 4;
+    }
+    if (candidate.scale_factor - wanted.scale_factor).abs() < 0.01 {
+        score += 2;
+    }
+    if candidate.position == wanted.position {
+        score += 1;
+    }
+    Some(score)
+}
+
+/// The connected monitor that best matches a saved fingerprint.
+pub fn resolve<'a>(monitors: &'a [Monitor], wanted: &SafeMonitorInfo) -> Option<&'a Monitor> {
+    let mut best: Option<(u32, &Monitor)> = None;
+    for monitor in monitors {
+        if let Some(score) = match_score(&SafeMonitorInfo::from_monitor(monitor), wanted) {
+            if best.is_none_or(|(best_score, _)| score > best_score) {
+                best = Some((score, monitor));
+            }
+        }
+    }
+    best.map(|(_, monitor)| monitor)
+}
+
+/// Picks a monitor by fingerprint when given and connected, otherwise by
+/// index into the sorted list. Sorts `monitors` in place.
+pub fn select_monitor<'a>(
+    monitors: &'a mut [Monitor],
+    index: Option<usize>,
+    wanted: Option<&SafeMonitorInfo>,
+) -> Option<&'a Monitor> {
+    sort_monitors(monitors);
+    let monitors: &'a [Monitor] = monitors;
+    wanted
+        .and_then(|wanted| resolve(monitors, wanted))
+        .or_else(|| index.and_then(|i| monitors.get(i)))
+}
+
+// ============================================================================
+// Display layout profiles
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutProfileOutput {
+    pub output: String,
+    pub monitor: SafeMonitorInfo,
+}
+
+// A named arrangement ("Sunday main hall") of display outputs on monitors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayLayoutProfile {
+    pub name: String,
+    pub outputs: Vec<LayoutProfileOutput>,
+    pub updated_at: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LayoutProfileStore {
+    #[serde(default)]
+    pub profiles: Vec<DisplayLayoutProfile>,
+}
+
+impl LayoutProfileStore {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(data) => serde_json::from_str(&data).map_err(|e| format!("invalid_layout_profiles:{}", e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("read_failed:{}", e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("create_dir_failed:{}", e))?;
+        }
+        let data = serde_json::to_string_pretty(self).map_err(|e| format!("serialize_failed:{}", e))?;
+        std::fs::write(path, data).map_err(|e| format!("write_failed:{}", e))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DisplayLayoutProfile> {
+        let name = name.trim();
+        self.profiles.iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds or replaces (by case-insensitive name) a profile.
+    pub fn upsert(&mut self, profile: DisplayLayoutProfile) -> Result<DisplayLayoutProfile, String> {
+        let name = profile.name.trim();
+        if name.is_empty() || name.chars().count() > MAX_PROFILE_NAME_LEN {
+            return Err(format!("invalid_layout_profile_name:{}", profile.name));
+        }
+        if profile.outputs.is_empty() {
+            return Err("layout_profile_empty".to_string());
+        }
+        let profile = DisplayLayoutProfile {
+            name: name.to_string(),
+            ..profile
+        };
+        match self.profiles.iter_mut().find(|p| p.name.eq_ignore_ascii_case(name)) {
+            Some(existing) => *existing = profile.clone(),
+            None => self.profiles.push(profile.clone()),
+        }
+        self.profiles.sort_by_key(|p| p.name.to_lowercase());
+        Ok(profile)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<(), String> {
+        let before = self.profiles.len();
+        self.profiles.retain(|p| !p.name.eq_ignore_ascii_case(name.trim()));
+        if self.profiles.len() == before {
+            return Err(format!("layout_profile_not_found:{}", name));
+        }
+        Ok(())
+    }
+}
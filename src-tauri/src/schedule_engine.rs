@@ -0,0 +1,471 @@
+//schedule_engine.rs parses schedule times/durations and decides when to auto-advance
+use crate::timer_engine::parse_time_of_day;
+use crate::ScheduleItem;
+use chrono::{DateTime, Local, NaiveTime};
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub struct ParsedScheduleItem {
+    pub index: usize,
+    pub id: i32,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub duration_secs: i64,
+    pub has_end_time: bool, // false when the end was derived from the duration
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleIssue {
+    pub index: usize,
+    pub item_id: i32,
+    pub code: String, // "missing_start_time" | "invalid_start_time" | "invalid_end_time" | "invalid_duration" | "end_before_start" | "duration_mismatch" | "crosses_midnight" | "overlap"
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleItemTimes {
+    pub index: usize,
+    pub id: i32,
+    pub start: String, // "HH:MM:SS"
+    pub end: String,
+    pub duration_secs: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleValidation {
+    pub valid: bool,
+    pub items: Vec<ScheduleItemTimes>,
+    pub issues: Vec<ScheduleIssue>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleAutoAdvance {
+    pub enabled: bool,
+    // Index the engine last advanced to; it only acts again once another
+    // start time is crossed, so manual navigation is not overridden.
+    pub last_index: Option<usize>,
+}
+
+// Accepts "5", "5 min", "5 mins", "1h 30m", "1 hr 30 min", "90s", "1:30" (m:ss)
+// and "1:30:00" (h:mm:ss). Bare numbers are minutes, as in the frontend.
+pub fn parse_duration(value: &str) -> Result<i64, String> {
+    let trimmed = value.trim().to_lowercase();
+    let invalid = || format!("invalid_duration:{}", value);
+    if trimmed.is_empty() {
+        return Err(invalid());
+    }
+
+    if trimmed.contains(':') {
+        let parts: Vec<i64> = trimmed
+            .split(':')
+            .map(|p| p.trim().parse::<i64>().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        let seconds = match parts.as_slice() {
+            [m, s] if *s < 60 => m.checked_mul(60).and_then(|m| m.checked_add(*s)),
+            [h, m, s] if *m < 60 && *s < 60 => h
+                .checked_mul(3600)
+                .and_then(|h| h.checked_add(m.checked_mul(60)?))
+                .and_then(|t| t.checked_add(*s)),
+            _ => None,
+        };
+        return seconds.ok_or_else(invalid);
+    }
+
+    let mut total = 0i64;
+    let mut rest = trimmed.as_str();
+    let mut matched_any = false;
+    while !rest.is_empty() {
+        let rest_trimmed = rest.trim_start();
+        let digits_end = rest_trimmed
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest_trimmed.len());
+        if digits_end == 0 {
+            return Err(invalid());
+        }
+        let number: f64 = rest_trimmed[..digits_end].parse().map_err(|_| invalid())?;
+        let after = rest_trimmed[digits_end..].trim_start();
+        let unit_end = after.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(after.len());
+        let multiplier = match &after[..unit_end] {
+            "" | "m" | "min" | "mins" | "minute" | "minutes" => 60.0,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3600.0,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1.0,
+            _ => return Err(invalid()),
+        };
+        // `as i64` saturates, so out-of-range values are rejected first.
+        let seconds = (number * multiplier).round();
+        if !(0.0..i64::MAX as f64).contains(&seconds) {
+            return Err(invalid());
+        }
+        total = total.checked_add(seconds as i64).ok_or_else(invalid)?;
+        matched_any = true;
+        rest = after[unit_end..].trim_start_matches([',', ' ']);
+    }
+
+    if matched_any {
+        Ok(total)
+    } else {
+        Err(invalid())
+    }
+}
+
+fn seconds_between(start: NaiveTime, end: NaiveTime) -> i64 {
+    (end - start).num_seconds()
+}
+
+// Parses every item, then checks that items don't overlap in schedule order.
+// Start times are required; the end comes from `end_time` or start + duration.
+pub fn parse_schedule(items: &[ScheduleItem]) -> Result<Vec<ParsedScheduleItem>, Vec<ScheduleIssue>> {
+    let mut parsed = Vec::with_capacity(items.len());
+    let mut issues = Vec::new();
+    let mut issue = |index: usize, item: &ScheduleItem, code: &str, message: String| {
+        issues.push(ScheduleIssue {
+            index,
+            item_id: item.id,
+            code: code.to_string(),
+            message,
+        });
+    };
+
+    for (index, item) in items.iter().enumerate() {
+        let label = if item.session.trim().is_empty() {
+            format!("Item {}", index + 1)
+        } else {
+            format!("\"{}\"", item.session.trim())
+        };
+
+        if item.start_time.trim().is_empty() {
+            issue(index, item, "missing_start_time", format!("{} has no start time", label));
+            continue;
+        }
+        let start = match parse_time_of_day(&item.start_time) {
+            Ok(start) => start,
+            Err(_) => {
+                issue(index, item, "invalid_start_time", format!("{}: cannot read start time \"{}\"", label, item.start_time));
+                continue;
+            }
+        };
+
+        let end = if item.end_time.trim().is_empty() {
+            None
+        } else {
+            match parse_time_of_day(&item.end_time) {
+                Ok(end) => Some(end),
+                Err(_) => {
+                    issue(index, item, "invalid_end_time", format!("{}: cannot read end time \"{}\"", label, item.end_time));
+                    continue;
+                }
+            }
+        };
+
+        let duration = if item.duration.trim().is_empty() {
+            None
+        } else {
+            match parse_duration(&item.duration) {
+                Ok(duration) => Some(duration),
+                Err(_) => {
+                    issue(index, item, "invalid_duration", format!("{}: cannot read duration \"{}\"", label, item.duration));
+                    continue;
+                }
+            }
+        };
+
+        let (end, duration_secs, has_end_time) = match (end, duration) {
+            (Some(end), Some(duration)) => (end, duration, true),
+            (Some(end), None) => (end, seconds_between(start, end), true),
+            (None, Some(duration)) => match chrono::TimeDelta::try_seconds(duration) {
+                // Times are compared within one day, so a session can't run past midnight.
+                Some(delta) => match start.overflowing_add_signed(delta) {
+                    (end, 0) => (end, duration, false),
+                    _ => {
+                        issue(
+                            index,
+                            item,
+                            "crosses_midnight",
+                            format!("{}: starting at {} for {} runs past midnight", label, item.start_time.trim(), item.duration.trim()),
+                        );
+                        continue;
+                    }
+                },
+                None => {
+                    issue(index, item, "invalid_duration", format!("{}: duration \"{}\" is too long", label, item.duration));
+                    continue;
+                }
+            },
+            (None, None) => {
+                issue(index, item, "invalid_end_time", format!("{} needs an end time or a duration", label));
+                continue;
+            }
+        };
+
+        if has_end_time && end < start {
+            issue(
+                index,
+                item,
+                "end_before_start",
+                format!("{} ends ({}) before it starts ({})", label, item.end_time.trim(), item.start_time.trim()),
+            );
+            continue;
+        }
+        // Durations are kept in whole minutes, so smaller differences are rounding.
+        if has_end_time && (duration_secs - seconds_between(start, end)).abs() >= 60 {
+            issue(
+                index,
+                item,
+                "duration_mismatch",
+                format!(
+                    "{} runs {} to {}, which doesn't match its duration \"{}\"",
+                    label,
+                    item.start_time.trim(),
+                    item.end_time.trim(),
+                    item.duration.trim()
+                ),
+            );
+            continue;
+        }
+
+        parsed.push(ParsedScheduleItem {
+            index,
+            id: item.id,
+            start,
+            end,
+            duration_secs,
+            has_end_time,
+        });
+    }
+
+    for pair in parsed.windows(2) {
+        let (previous, current) = (&pair[0], &pair[1]);
+        if current.start < previous.end {
+            let item = &items[current.index];
+            issue(
+                current.index,
+                item,
+                "overlap",
+                format!(
+                    "\"{}\" starts at {} before \"{}\" ends at {}",
+                    item.session.trim(),
+                    current.start.format("%H:%M"),
+                    items[previous.index].session.trim(),
+                    previous.end.format("%H:%M")
+                ),
+            );
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(issues)
+    }
+}
+
+pub fn validate_schedule(items: &[ScheduleItem]) -> ScheduleValidation {
+    match parse_schedule(items) {
+        Ok(parsed) => ScheduleValidation {
+            valid: true,
+            items: parsed
+                .iter()
+                .map(|p| ScheduleItemTimes {
+                    index: p.index,
+                    id: p.id,
+                    start: p.start.format("%H:%M:%S").to_string(),
+                    end: p.end.format("%H:%M:%S").to_string(),
+                    duration_secs: p.duration_secs,
+                })
+                .collect(),
+            issues: Vec::new(),
+        },
+        Err(issues) => ScheduleValidation {
+            valid: false,
+            items: Vec::new(),
+            issues,
+        },
+    }
+}
+
+// The item whose start time has most recently passed, if any.
+pub fn due_item(parsed: &[ParsedScheduleItem], now: NaiveTime) -> Option<&ParsedScheduleItem> {
+    parsed.iter().rev().find(|p| p.start <= now)
+}
+
+// ============================================================================
+// Run log (planned vs actual)
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct SessionRun {
+    index: usize,
+    item_id: i32,
+    session: String,
+    minister: Option<String>,
+    planned: Option<ParsedScheduleItem>,
+    actual_start: DateTime<Local>,
+    actual_end: Option<DateTime<Local>>,
+}
+
+// Records when each session actually started and ended as the current
+// session changes, for the after-service report.
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleRunLog {
+    runs: Vec<SessionRun>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleReportEntry {
+    pub index: usize,
+    pub item_id: i32,
+    pub session: String,
+    pub minister: Option<String>,
+    pub planned_start: Option<String>, // RFC 3339, on the day the session ran
+    pub planned_end: Option<String>,
+    pub actual_start: String,
+    pub actual_end: Option<String>, // None while the session is still current
+    pub planned_duration_secs: Option<i64>,
+    pub actual_duration_secs: i64,
+    pub start_delay_secs: Option<i64>, // negative when it started early
+    pub overrun_secs: Option<i64>,     // seconds past the planned end, 0 if on time
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleReport {
+    pub generated_at: String,
+    pub entries: Vec<ScheduleReportEntry>,
+    pub total_overrun_secs: i64,
+}
+
+impl ScheduleRunLog {
+    // Closes the running session and opens `next` (None ends the service).
+    // Re-selecting the current session is not a new run.
+    pub fn transition(&mut self, next: Option<(usize, &ScheduleItem)>, now: DateTime<Local>) {
+        if let (Some(open), Some((index, item))) = (self.runs.last(), next) {
+            if open.actual_end.is_none() && open.index == index && open.item_id == item.id {
+                return;
+            }
+        }
+        if let Some(open) = self.runs.last_mut().filter(|r| r.actual_end.is_none()) {
+            open.actual_end = Some(now);
+        }
+        if let Some((index, item)) = next {
+            let planned = parse_schedule(std::slice::from_ref(item))
+                .ok()
+                .and_then(|mut parsed| parsed.pop())
+                .map(|p| ParsedScheduleItem { index, ..p });
+            self.runs.push(SessionRun {
+                index,
+                item_id: item.id,
+                session: item.session.clone(),
+                minister: item.minister.clone(),
+                planned,
+                actual_start: now,
+                actual_end: None,
+            });
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.runs.clear();
+    }
+
+    pub fn report(&self, now: DateTime<Local>) -> ScheduleReport {
+        let entries: Vec<ScheduleReportEntry> = self
+            .runs
+            .iter()
+            .map(|run| {
+                let actual_end = run.actual_end.unwrap_or(now);
+                let day = run.actual_start.date_naive();
+                let on_day = |time: NaiveTime| day.and_time(time).and_local_timezone(Local).earliest();
+                let planned_start = run.planned.as_ref().and_then(|p| on_day(p.start));
+                let planned_end = run.planned.as_ref().and_then(|p| on_day(p.end));
+                ScheduleReportEntry {
+                    index: run.index,
+                    item_id: run.item_id,
+                    session: run.session.clone(),
+                    minister: run.minister.clone(),
+                    planned_start: planned_start.map(|t| t.to_rfc3339()),
+                    planned_end: planned_end.map(|t| t.to_rfc3339()),
+                    actual_start: run.actual_start.to_rfc3339(),
+                    actual_end: run.actual_end.map(|t| t.to_rfc3339()),
+                    planned_duration_secs: run.planned.as_ref().map(|p| p.duration_secs),
+                    actual_duration_secs: (actual_end - run.actual_start).num_seconds(),
+                    start_delay_secs: planned_start.map(|p| (run.actual_start - p).num_seconds()),
+                    overrun_secs: planned_end.map(|p| (actual_end - p).num_seconds().max(0)),
+                }
+            })
+            .collect();
+        ScheduleReport {
+            generated_at: now.to_rfc3339(),
+            total_overrun_secs: entries.iter().filter_map(|e| e.overrun_secs).sum(),
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: i32, session: &str, start: &str, end: &str, duration: &str) -> ScheduleItem {
+        ScheduleItem {
+            id,
+            session: session.to_string(),
+            start_time: start.to_string(),
+            end_time: end.to_string(),
+            duration: duration.to_string(),
+            minister: None,
+        }
+    }
+
+    fn codes(items: &[ScheduleItem]) -> Vec<String> {
+        parse_schedule(items).err().unwrap_or_default().into_iter().map(|i| i.code).collect()
+    }
+
+    #[test]
+    fn parses_duration_formats() {
+        assert_eq!(parse_duration("5"), Ok(300));
+        assert_eq!(parse_duration("05mins"), Ok(300));
+        assert_eq!(parse_duration("1h 30m"), Ok(5400));
+        assert_eq!(parse_duration("1 hr, 30 min"), Ok(5400));
+        assert_eq!(parse_duration("90s"), Ok(90));
+        assert_eq!(parse_duration("1.5 hours"), Ok(5400));
+        assert_eq!(parse_duration("1:30"), Ok(90));
+        assert_eq!(parse_duration("1:30:00"), Ok(5400));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("1:75").is_err());
+        assert!(parse_duration("5 fortnights").is_err());
+        assert!(parse_duration("99999999999999999999 hours").is_err());
+    }
+
+    #[test]
+    fn derives_end_from_duration() {
+        let parsed = parse_schedule(&[item(1, "Worship", "10:00 AM", "", "25 mins")]).unwrap();
+        assert_eq!(parsed[0].end, NaiveTime::from_hms_opt(10, 25, 0).unwrap());
+        assert!(!parsed[0].has_end_time);
+    }
+
+    #[test]
+    fn end_time_must_match_duration() {
+        assert_eq!(codes(&[item(1, "Worship", "10:00 AM", "10:30 AM", "20mins")]), vec!["duration_mismatch"]);
+        assert!(parse_schedule(&[item(1, "Worship", "10:00 AM", "10:30 AM", "30mins")]).is_ok());
+        assert_eq!(codes(&[item(1, "Worship", "10:30 AM", "10:00 AM", "")]), vec!["end_before_start"]);
+    }
+
+    #[test]
+    fn derived_end_past_midnight_is_reported() {
+        assert_eq!(codes(&[item(1, "Vigil", "11:30 PM", "", "1h")]), vec!["crosses_midnight"]);
+        assert!(parse_schedule(&[item(1, "Vigil", "11:00 PM", "", "1h")]).is_err());
+        assert!(parse_schedule(&[item(1, "Vigil", "10:30 PM", "", "1h")]).is_ok());
+    }
+
+    #[test]
+    fn overlapping_items_are_reported() {
+        let items = [
+            item(1, "Worship", "10:00 AM", "", "30mins"),
+            item(2, "Sermon", "10:20 AM", "11:00 AM", ""),
+            item(3, "Closing", "11:00 AM", "", "5mins"),
+        ];
+        let issues = parse_schedule(&items).unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "overlap");
+        assert_eq!(issues[0].item_id, 2);
+    }
+}
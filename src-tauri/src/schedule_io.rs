@@ -0,0 +1,941 @@
+//schedule_io.rs converts run-sheets (CSV, iCalendar, Planning Center) to and from ScheduleItems
+use crate::csv_util::csv_line;
+use crate::live_slides_export::{safe_file_stem, ExportedFile};
+use crate::live_slides_import::ImportWarning;
+use crate::schedule_engine::parse_duration;
+use crate::timer_engine::{format_time_of_day, parse_time_of_day};
+use crate::ScheduleItem;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleImportFormat {
+    Csv,
+    ICalendar,
+    PlanningCenterCsv,
+    PlanningCenterJson, // Services API plan document with included items
+}
+
+impl ScheduleImportFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "csv" => Ok(ScheduleImportFormat::Csv),
+            "ics" | "ical" | "icalendar" => Ok(ScheduleImportFormat::ICalendar),
+            "pco" | "pco_csv" | "planning_center" | "planning_center_csv" => Ok(ScheduleImportFormat::PlanningCenterCsv),
+            "json" | "pco_json" | "planning_center_json" => Ok(ScheduleImportFormat::PlanningCenterJson),
+            other => Err(format!("unsupported_import_format:{}", other)),
+        }
+    }
+
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        ScheduleImportFormat::parse(extension).ok()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScheduleExportFormat {
+    Csv,
+    ICalendar,
+    PlanningCenterCsv,
+}
+
+impl ScheduleExportFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match ScheduleImportFormat::parse(value)? {
+            ScheduleImportFormat::Csv => Ok(ScheduleExportFormat::Csv),
+            ScheduleImportFormat::ICalendar => Ok(ScheduleExportFormat::ICalendar),
+            ScheduleImportFormat::PlanningCenterCsv => Ok(ScheduleExportFormat::PlanningCenterCsv),
+            ScheduleImportFormat::PlanningCenterJson => Err(format!("unsupported_export_format:{}", value.trim())),
+        }
+    }
+}
+
+// Which CSV column holds each field, by header name (case-insensitive) or
+// 1-based column number. Unset fields are guessed from the header row.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScheduleColumnMapping {
+    pub session: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub duration: Option<String>,
+    pub minister: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScheduleImportOptions {
+    pub columns: ScheduleColumnMapping,
+    pub date: Option<String>,       // "YYYY-MM-DD": which day to take from a calendar
+    pub start_time: Option<String>, // first start when the source only has lengths
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleImportResult {
+    pub schedule: Vec<ScheduleItem>,
+    pub warnings: Vec<ImportWarning>,
+}
+
+pub fn import_schedule(
+    data: &[u8],
+    format: ScheduleImportFormat,
+    options: &ScheduleImportOptions,
+) -> Result<ScheduleImportResult, String> {
+    let text = String::from_utf8_lossy(data);
+    // Strip a UTF-8 BOM, common in spreadsheet exports.
+    let text = text.trim_start_matches('\u{feff}');
+    let date = match options.date.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        Some(date) => Some(NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("invalid_date:{}", date))?),
+        None => None,
+    };
+    let base_start = match options.start_time.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        Some(start) => Some(parse_time_of_day(start)?),
+        None => None,
+    };
+
+    let mut warnings = Vec::new();
+    let rows = match format {
+        ScheduleImportFormat::Csv => import_csv(text, &options.columns, false, &mut warnings)?,
+        ScheduleImportFormat::PlanningCenterCsv => import_csv(text, &options.columns, true, &mut warnings)?,
+        ScheduleImportFormat::ICalendar => import_ics(text, date, &mut warnings)?,
+        ScheduleImportFormat::PlanningCenterJson => import_planning_center_json(text, &mut warnings)?,
+    };
+    if rows.is_empty() {
+        return Err("import_empty".to_string());
+    }
+
+    Ok(ScheduleImportResult {
+        schedule: finish_rows(rows, base_start, &mut warnings),
+        warnings,
+    })
+}
+
+fn warn(warnings: &mut Vec<ImportWarning>, code: &str, detail: impl Into<String>) {
+    warnings.push(ImportWarning {
+        code: code.to_string(),
+        detail: detail.into(),
+    });
+}
+
+// ============================================================================
+// Row normalization
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct ImportedRow {
+    session: String,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+    duration_secs: Option<i64>,
+    minister: Option<String>,
+}
+
+// "06.00" (as in the old CSV loader) is read as "06:00".
+fn read_time(value: &str, row_label: &str, warnings: &mut Vec<ImportWarning>) -> Option<NaiveTime> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    let normalized = match value.split_once('.') {
+        Some((h, m)) if h.chars().all(|c| c.is_ascii_digit()) && m.starts_with(|c: char| c.is_ascii_digit()) => {
+            format!("{}:{}", h, m)
+        }
+        _ => value.to_string(),
+    };
+    match parse_time_of_day(&normalized) {
+        Ok(time) => Some(time),
+        Err(_) => {
+            warn(warnings, "invalid_time", format!("{}: \"{}\"", row_label, value));
+            None
+        }
+    }
+}
+
+fn read_duration(value: &str, row_label: &str, warnings: &mut Vec<ImportWarning>) -> Option<i64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    match parse_duration(value) {
+        Ok(seconds) => Some(seconds),
+        Err(_) => {
+            warn(warnings, "invalid_duration", format!("{}: \"{}\"", row_label, value));
+            None
+        }
+    }
+}
+
+// Durations are stored the way the schedule editor writes them ("05mins").
+fn format_duration_minutes(seconds: i64) -> String {
+    let minutes = ((seconds as f64) / 60.0).round().max(0.0) as i64;
+    format!("{:02}mins", minutes)
+}
+
+// Fills whatever each row is missing from the others: end from start +
+// duration, duration from end - start, and a missing start from the previous
+// item's end (or `base_start` for the first item).
+fn finish_rows(rows: Vec<ImportedRow>, base_start: Option<NaiveTime>, warnings: &mut Vec<ImportWarning>) -> Vec<ScheduleItem> {
+    let mut previous_end = base_start;
+    let mut items = Vec::with_capacity(rows.len());
+    for (index, row) in rows.into_iter().enumerate() {
+        let start = row.start.or(previous_end);
+        // Lengths come straight from the file, so they may not fit a TimeDelta.
+        let duration = row.duration_secs.and_then(|secs| match TimeDelta::try_seconds(secs) {
+            Some(delta) => Some((secs, delta)),
+            None => {
+                warn(warnings, "invalid_duration", format!("\"{}\": duration is too long", row.session));
+                None
+            }
+        });
+        let end = match (row.end, start, duration) {
+            (Some(end), _, _) => Some(end),
+            (None, Some(start), Some((_, delta))) => Some(start.overflowing_add_signed(delta).0),
+            _ => None,
+        };
+        let duration_secs = match (duration, start, end) {
+            (Some((duration, _)), _, _) => Some(duration),
+            (None, Some(start), Some(end)) => Some((end - start).num_seconds()),
+            _ => None,
+        };
+        if start.is_none() {
+            warn(warnings, "missing_start_time", format!("\"{}\" has no start time", row.session));
+        }
+
+        items.push(ScheduleItem {
+            id: index as i32 + 1,
+            session: row.session,
+            start_time: start.map(format_time_of_day).unwrap_or_default(),
+            end_time: end.map(format_time_of_day).unwrap_or_default(),
+            duration: duration_secs.map(format_duration_minutes).unwrap_or_default(),
+            minister: row.minister.filter(|m| !m.trim().is_empty()),
+        });
+        previous_end = end;
+    }
+    items
+}
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+// RFC 4180 records; the delimiter (comma, semicolon or tab) is taken from
+// whichever appears most in the first line.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let first_line = text.lines().next().unwrap_or("");
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| first_line.matches(*d).count())
+        .unwrap_or(',');
+
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+        .into_iter()
+        .filter(|r| r.iter().any(|f| !f.trim().is_empty()))
+        .collect()
+}
+
+fn find_column(header: &[String], mapped: Option<&str>, guesses: &[&str]) -> Result<Option<usize>, String> {
+    let normalize = |s: &str| s.trim().to_lowercase().replace(['_', '-'], " ");
+    if let Some(mapped) = mapped.map(str::trim).filter(|m| !m.is_empty()) {
+        if let Ok(number) = mapped.parse::<usize>() {
+            return if (1..=header.len()).contains(&number) {
+                Ok(Some(number - 1))
+            } else {
+                Err(format!("column_not_found:{}", mapped))
+            };
+        }
+        return header
+            .iter()
+            .position(|h| normalize(h) == normalize(mapped))
+            .map(Some)
+            .ok_or_else(|| format!("column_not_found:{}", mapped));
+    }
+    Ok(guesses
+        .iter()
+        .find_map(|guess| header.iter().position(|h| normalize(h) == *guess)))
+}
+
+const SESSION_COLUMNS: &[&str] = &["session", "title", "item title", "item", "name", "event", "activity", "program", "subject", "summary"];
+const START_COLUMNS: &[&str] = &["start time", "start", "starts", "starts at", "begin", "time", "time range"];
+const END_COLUMNS: &[&str] = &["end time", "end", "ends", "ends at", "finish"];
+const DURATION_COLUMNS: &[&str] = &["duration", "item length", "length", "minutes", "mins"];
+const MINISTER_COLUMNS: &[&str] = &["minister", "speaker", "person", "leader", "preacher", "host", "responsible", "assigned to"];
+const TYPE_COLUMNS: &[&str] = &["item type", "type"];
+
+fn import_csv(
+    text: &str,
+    mapping: &ScheduleColumnMapping,
+    planning_center: bool,
+    warnings: &mut Vec<ImportWarning>,
+) -> Result<Vec<ImportedRow>, String> {
+    let mut records = parse_csv(text).into_iter();
+    let header = records.next().ok_or_else(|| "import_empty".to_string())?;
+
+    let session = find_column(&header, mapping.session.as_deref(), SESSION_COLUMNS)?
+        .ok_or_else(|| "column_not_found:session".to_string())?;
+    let start = find_column(&header, mapping.start_time.as_deref(), START_COLUMNS)?;
+    let end = find_column(&header, mapping.end_time.as_deref(), END_COLUMNS)?;
+    let duration = find_column(&header, mapping.duration.as_deref(), DURATION_COLUMNS)?;
+    let minister = find_column(&header, mapping.minister.as_deref(), MINISTER_COLUMNS)?;
+    let item_type = if planning_center { find_column(&header, None, TYPE_COLUMNS)? } else { None };
+    if start.is_none() && duration.is_none() {
+        warn(warnings, "no_time_columns", "no start time or duration column found");
+    }
+
+    let mut rows = Vec::new();
+    for (line, record) in records.enumerate() {
+        let cell = |index: Option<usize>| index.and_then(|i| record.get(i)).map(|v| v.trim()).unwrap_or("");
+        // Planning Center section headers are labels, not timed items.
+        if item_type.is_some() && cell(item_type).eq_ignore_ascii_case("header") {
+            continue;
+        }
+        let title = cell(Some(session));
+        if title.is_empty() {
+            warn(warnings, "missing_session", format!("row {} has no session name", line + 2));
+            continue;
+        }
+        let label = format!("\"{}\"", title);
+
+        // A single "10:30 AM - 10:45 AM" cell fills both start and end.
+        let (start_value, end_value) = match (cell(start).split_once(" - ").or_else(|| cell(start).split_once('-')), cell(end)) {
+            (Some((from, to)), "") => (from, to),
+            _ => (cell(start), cell(end)),
+        };
+        rows.push(ImportedRow {
+            session: title.to_string(),
+            start: read_time(start_value, &label, warnings),
+            end: read_time(end_value, &label, warnings),
+            duration_secs: read_duration(cell(duration), &label, warnings),
+            minister: Some(cell(minister).to_string()),
+        });
+    }
+    Ok(rows)
+}
+
+// ============================================================================
+// iCalendar
+// ============================================================================
+
+struct IcsProperty {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+fn ics_unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => {}
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn ics_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// Unfolds continuation lines and splits "NAME;PARAM=x:value".
+fn ics_properties(text: &str) -> Vec<IcsProperty> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.split('\n') {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(previous)) => previous.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    lines
+        .into_iter()
+        .filter_map(|line| {
+            let (head, value) = line.split_once(':')?;
+            let mut parts = head.split(';');
+            let name = parts.next()?.trim().to_uppercase();
+            let params = parts
+                .filter_map(|p| p.split_once('='))
+                .map(|(k, v)| (k.trim().to_uppercase(), v.trim_matches('"').to_string()))
+                .collect();
+            Some(IcsProperty {
+                name,
+                params,
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+// DATE-TIME values; UTC ("Z") is converted to local time, TZID and floating
+// times are taken as local. All-day DATE values return None.
+fn ics_datetime(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    match value.strip_suffix('Z') {
+        Some(utc) => {
+            let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+            Some(chrono::Utc.from_utc_datetime(&naive).with_timezone(&chrono::Local).naive_local())
+        }
+        None => NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok(),
+    }
+}
+
+// RFC 5545 durations such as "PT1H30M" or "P1DT2H".
+fn ics_duration(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut rest = value.strip_prefix('P')?;
+    let mut total = 0i64;
+    let mut number = String::new();
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                let unit = match c {
+                    'W' => 7 * 86400,
+                    'D' => 86400,
+                    'H' => 3600,
+                    'M' => 60,
+                    _ => 1,
+                };
+                total = total.checked_add(n.checked_mul(unit)?)?;
+            }
+            _ => return None,
+        }
+    }
+    Some(sign * total)
+}
+
+fn import_ics(text: &str, date: Option<NaiveDate>, warnings: &mut Vec<ImportWarning>) -> Result<Vec<ImportedRow>, String> {
+    let properties = ics_properties(text);
+    if !properties.iter().any(|p| p.name == "BEGIN" && p.value.eq_ignore_ascii_case("VCALENDAR")) {
+        return Err("invalid_icalendar".to_string());
+    }
+
+    let mut events: Vec<(NaiveDateTime, ImportedRow)> = Vec::new();
+    let mut current: Option<Vec<&IcsProperty>> = None;
+    for property in &properties {
+        match (property.name.as_str(), property.value.trim().to_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(event) = current.take() {
+                    if let Some(parsed) = ics_event(&event, warnings) {
+                        events.push(parsed);
+                    }
+                }
+            }
+            _ => {
+                if let Some(event) = current.as_mut() {
+                    event.push(property);
+                }
+            }
+        }
+    }
+
+    events.sort_by_key(|(start, _)| *start);
+    let day = date.or_else(|| events.first().map(|(start, _)| start.date()));
+    let total = events.len();
+    let rows: Vec<ImportedRow> = events
+        .into_iter()
+        .filter(|(start, _)| Some(start.date()) == day)
+        .map(|(_, row)| row)
+        .collect();
+    if rows.len() < total {
+        warn(
+            warnings,
+            "other_dates_skipped",
+            format!("{} event(s) not on {} were skipped", total - rows.len(), day.map(|d| d.to_string()).unwrap_or_default()),
+        );
+    }
+    Ok(rows)
+}
+
+fn ics_event(event: &[&IcsProperty], warnings: &mut Vec<ImportWarning>) -> Option<(NaiveDateTime, ImportedRow)> {
+    let get = |name: &str| event.iter().find(|p| p.name == name);
+    let summary = get("SUMMARY").map(|p| ics_unescape(&p.value).trim().to_string()).unwrap_or_default();
+    let label = if summary.is_empty() { "(untitled event)".to_string() } else { format!("\"{}\"", summary) };
+
+    let Some(start) = get("DTSTART").and_then(|p| ics_datetime(&p.value)) else {
+        warn(warnings, "all_day_event_skipped", label);
+        return None;
+    };
+    if get("RRULE").is_some() {
+        warn(warnings, "recurrence_ignored", format!("{}: only the first occurrence is imported", label));
+    }
+
+    let end = get("DTEND").and_then(|p| ics_datetime(&p.value));
+    let duration_secs = match end {
+        Some(end) => Some((end - start).num_seconds()),
+        None => get("DURATION").and_then(|p| {
+            let duration = ics_duration(&p.value);
+            if duration.is_none() {
+                warn(warnings, "invalid_duration", format!("{}: \"{}\"", label, p.value));
+            }
+            duration
+        }),
+    };
+
+    // Our own exports carry X-PROASSIST-MINISTER; otherwise use the organizer's name.
+    let minister = get("X-PROASSIST-MINISTER")
+        .map(|p| ics_unescape(&p.value))
+        .or_else(|| {
+            get("ORGANIZER").and_then(|p| p.params.iter().find(|(k, _)| k == "CN").map(|(_, v)| v.clone()))
+        });
+
+    Some((
+        start,
+        ImportedRow {
+            session: summary,
+            start: Some(start.time()),
+            end: end.map(|e| e.time()),
+            duration_secs,
+            minister,
+        },
+    ))
+}
+
+// ============================================================================
+// Planning Center Services JSON
+// ============================================================================
+
+// Takes a Services API plan document (`/plans/{id}?include=items,plan_times`
+// or `/plans/{id}/items?include=item_notes`): `Item` resources become schedule
+// items in sequence order, timed from the first service `PlanTime` and their
+// `length`; item notes in a person-like category become the minister.
+fn import_planning_center_json(text: &str, warnings: &mut Vec<ImportWarning>) -> Result<Vec<ImportedRow>, String> {
+    let document: serde_json::Value = serde_json::from_str(text).map_err(|e| format!("invalid_json:{}", e))?;
+    let mut resources: Vec<&serde_json::Value> = Vec::new();
+    for key in ["data", "included"] {
+        match document.get(key) {
+            Some(serde_json::Value::Array(values)) => resources.extend(values),
+            Some(value @ serde_json::Value::Object(_)) => resources.push(value),
+            _ => {}
+        }
+    }
+    let of_type = |kind: &str| -> Vec<&serde_json::Value> {
+        resources
+            .iter()
+            .copied()
+            .filter(|r| r.get("type").and_then(|t| t.as_str()) == Some(kind))
+            .collect()
+    };
+    let attribute = |resource: &serde_json::Value, name: &str| -> Option<String> {
+        match resource.get("attributes")?.get(name)? {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    };
+
+    let mut items = of_type("Item");
+    if items.is_empty() {
+        return Err("planning_center_no_items".to_string());
+    }
+    items.sort_by_key(|item| {
+        item.get("attributes")
+            .and_then(|a| a.get("sequence"))
+            .and_then(|s| s.as_i64())
+            .unwrap_or(i64::MAX)
+    });
+
+    let service_start = of_type("PlanTime")
+        .into_iter()
+        .filter(|t| attribute(t, "time_type").is_none_or(|kind| kind == "service"))
+        .filter_map(|t| attribute(t, "starts_at"))
+        .filter_map(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+        .min()
+        .map(|s| s.with_timezone(&chrono::Local).time());
+
+    let person_notes: Vec<(String, String)> = of_type("ItemNote")
+        .into_iter()
+        .filter(|note| {
+            attribute(note, "category_name").is_some_and(|c| {
+                let c = c.to_lowercase();
+                ["person", "leader", "minister", "speaker", "preacher"].iter().any(|k| c.contains(k))
+            })
+        })
+        .filter_map(|note| {
+            let item_id = note.pointer("/relationships/item/data/id")?.as_str()?.to_string();
+            Some((item_id, attribute(note, "content")?))
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    for item in items {
+        let title = attribute(item, "title").unwrap_or_default().trim().to_string();
+        if attribute(item, "item_type").as_deref() == Some("header") {
+            continue;
+        }
+        if matches!(attribute(item, "service_position").as_deref(), Some("pre") | Some("post")) {
+            warn(warnings, "outside_service_skipped", format!("\"{}\" is outside the service", title));
+            continue;
+        }
+        let item_id = item.get("id").and_then(|id| id.as_str()).unwrap_or_default();
+        rows.push(ImportedRow {
+            start: if rows.is_empty() { service_start } else { None },
+            duration_secs: item
+                .get("attributes")
+                .and_then(|a| a.get("length"))
+                .and_then(|l| l.as_i64()),
+            minister: person_notes
+                .iter()
+                .find(|(id, _)| id == item_id)
+                .map(|(_, content)| content.trim().to_string()),
+            session: title,
+            end: None,
+        });
+    }
+    if service_start.is_none() {
+        warn(warnings, "no_service_time", "plan has no service time; pass a start time");
+    }
+    Ok(rows)
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+pub fn export_schedule(
+    items: &[ScheduleItem],
+    format: ScheduleExportFormat,
+    date: NaiveDate,
+    name: &str,
+) -> ExportedFile {
+    let stem = safe_file_stem(name);
+    match format {
+        ScheduleExportFormat::Csv => ExportedFile {
+            file_name: format!("{}.csv", stem),
+            content_type: "text/csv",
+            data: export_csv(items).into_bytes(),
+        },
+        ScheduleExportFormat::PlanningCenterCsv => ExportedFile {
+            file_name: format!("{}-planning-center.csv", stem),
+            content_type: "text/csv",
+            data: export_planning_center_csv(items).into_bytes(),
+        },
+        ScheduleExportFormat::ICalendar => ExportedFile {
+            file_name: format!("{}.ics", stem),
+            content_type: "text/calendar",
+            data: export_ics(items, date).into_bytes(),
+        },
+    }
+}
+
+pub fn export_csv(items: &[ScheduleItem]) -> String {
+    let mut out = csv_line(&["Session", "Start Time", "End Time", "Duration", "Minister"]);
+    for item in items {
+        out.push_str(&csv_line(&[
+            &item.session,
+            &item.start_time,
+            &item.end_time,
+            &item.duration,
+            item.minister.as_deref().unwrap_or(""),
+        ]));
+    }
+    out
+}
+
+pub fn export_planning_center_csv(items: &[ScheduleItem]) -> String {
+    let mut out = csv_line(&["Item Title", "Item Type", "Item Length", "Start Time", "Person"]);
+    for item in items {
+        let length = parse_duration(&item.duration)
+            .map(|s| format!("{}:{:02}", s / 60, s % 60))
+            .unwrap_or_default();
+        out.push_str(&csv_line(&[
+            &item.session,
+            "item",
+            &length,
+            &item.start_time,
+            item.minister.as_deref().unwrap_or(""),
+        ]));
+    }
+    out
+}
+
+// Floating local times on `date`, so the calendar shows the same clock times
+// wherever it is opened.
+pub fn export_ics(items: &[ScheduleItem], date: NaiveDate) -> String {
+    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//SmartVerses//Schedule//EN".to_string(),
+    ];
+    for item in items {
+        let Ok(start) = parse_time_of_day(&item.start_time) else {
+            continue;
+        };
+        let end = parse_time_of_day(&item.end_time)
+            .ok()
+            .or_else(|| {
+                let delta = parse_duration(&item.duration).ok().and_then(TimeDelta::try_seconds)?;
+                Some(start.overflowing_add_signed(delta).0)
+            });
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:proassist-{}-{}@proassist", date.format("%Y%m%d"), item.id));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART:{}", date.and_time(start).format("%Y%m%dT%H%M%S")));
+        if let Some(end) = end {
+            lines.push(format!("DTEND:{}", date.and_time(end).format("%Y%m%dT%H%M%S")));
+        }
+        lines.push(format!("SUMMARY:{}", ics_escape(&item.session)));
+        if let Some(minister) = item.minister.as_deref().filter(|m| !m.trim().is_empty()) {
+            lines.push(format!("DESCRIPTION:{}", ics_escape(&format!("Minister: {}", minister))));
+            lines.push(format!("X-PROASSIST-MINISTER:{}", ics_escape(minister)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold_ics_line(&line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+// Lines longer than 75 octets continue on the next line after a space.
+fn fold_ics_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, Utc};
+
+    fn import(text: &str, format: ScheduleImportFormat, options: &ScheduleImportOptions) -> ScheduleImportResult {
+        import_schedule(text.as_bytes(), format, options).unwrap()
+    }
+
+    fn rows(items: &[ScheduleItem]) -> Vec<(String, String, String, String, Option<String>)> {
+        items
+            .iter()
+            .map(|i| (i.session.clone(), i.start_time.clone(), i.end_time.clone(), i.duration.clone(), i.minister.clone()))
+            .collect()
+    }
+
+    fn row(session: &str, start: &str, end: &str, duration: &str, minister: Option<&str>) -> (String, String, String, String, Option<String>) {
+        (session.to_string(), start.to_string(), end.to_string(), duration.to_string(), minister.map(str::to_string))
+    }
+
+    // Local clock time of a UTC instant, as the importer shows it.
+    fn local_time(hour: u32, minute: u32) -> String {
+        let utc = Utc.with_ymd_and_hms(2026, 10, 18, hour, minute, 0).unwrap();
+        format_time_of_day(utc.with_timezone(&Local).time())
+    }
+
+    #[test]
+    fn csv_columns_are_found_by_header() {
+        let text = "\u{feff}Item;Starts;Ends;Length;Speaker\r\nWelcome;10:00 AM;;5;Ann\r\n\"Sermon; part 1\";;;30 min;Bob\r\n";
+        let result = import(text, ScheduleImportFormat::Csv, &ScheduleImportOptions::default());
+        assert_eq!(
+            rows(&result.schedule),
+            vec![
+                row("Welcome", "10:00 AM", "10:05 AM", "05mins", Some("Ann")),
+                row("Sermon; part 1", "10:05 AM", "10:35 AM", "30mins", Some("Bob")),
+            ]
+        );
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn csv_columns_can_be_mapped_by_name_or_number() {
+        let text = "Who,What,From,Until\nAnn,Prayer,09.30,09:40\n";
+        let options = ScheduleImportOptions {
+            columns: ScheduleColumnMapping {
+                session: Some("what".to_string()),
+                start_time: Some("3".to_string()),
+                end_time: Some("Until".to_string()),
+                minister: Some("1".to_string()),
+                ..ScheduleColumnMapping::default()
+            },
+            ..ScheduleImportOptions::default()
+        };
+        let result = import(text, ScheduleImportFormat::Csv, &options);
+        assert_eq!(rows(&result.schedule), vec![row("Prayer", "9:30 AM", "9:40 AM", "10mins", Some("Ann"))]);
+
+        let options = ScheduleImportOptions {
+            columns: ScheduleColumnMapping {
+                session: Some("7".to_string()),
+                ..ScheduleColumnMapping::default()
+            },
+            ..ScheduleImportOptions::default()
+        };
+        let error = import_schedule(text.as_bytes(), ScheduleImportFormat::Csv, &options).err();
+        assert_eq!(error.as_deref(), Some("column_not_found:7"));
+    }
+
+    #[test]
+    fn csv_time_range_cells_fill_start_and_end() {
+        let text = "Session,Time\nWorship,10:30 AM - 10:45 AM\nAnnouncements,10:45-10:50\n";
+        let result = import(text, ScheduleImportFormat::Csv, &ScheduleImportOptions::default());
+        assert_eq!(
+            rows(&result.schedule),
+            vec![
+                row("Worship", "10:30 AM", "10:45 AM", "15mins", None),
+                row("Announcements", "10:45 AM", "10:50 AM", "05mins", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn ics_lines_are_unfolded_and_events_sorted() {
+        let text = concat!(
+            "BEGIN:VCALENDAR\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART;TZID=America/New_York:20261018T103000\r\n",
+            "DURATION:PT45M\r\n",
+            "SUMMARY:Sermon: The Good\r\n",
+            "  Shepherd\r\n",
+            "ORGANIZER;CN=\"Pastor Sam\":mailto:sam@example.com\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART:20261018T100000\r\n",
+            "DTEND:20261018T103000\r\n",
+            "SUMMARY:Worship\\, part 1\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART:20261025T100000\r\n",
+            "SUMMARY:Next week\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART;VALUE=DATE:20261018\r\n",
+            "SUMMARY:Harvest Sunday\r\n",
+            "END:VEVENT\r\n",
+            "END:VCALENDAR\r\n",
+        );
+        let result = import(text, ScheduleImportFormat::ICalendar, &ScheduleImportOptions::default());
+        // TZID times are read as local clock times.
+        assert_eq!(
+            rows(&result.schedule),
+            vec![
+                row("Worship, part 1", "10:00 AM", "10:30 AM", "30mins", None),
+                row("Sermon: The Good Shepherd", "10:30 AM", "11:15 AM", "45mins", Some("Pastor Sam")),
+            ]
+        );
+        let codes: Vec<&str> = result.warnings.iter().map(|w| w.code.as_str()).collect();
+        assert_eq!(codes, vec!["all_day_event_skipped", "other_dates_skipped"]);
+
+        let options = ScheduleImportOptions {
+            date: Some("2026-10-25".to_string()),
+            ..ScheduleImportOptions::default()
+        };
+        let result = import(text, ScheduleImportFormat::ICalendar, &options);
+        assert_eq!(rows(&result.schedule), vec![row("Next week", "10:00 AM", "", "", None)]);
+    }
+
+    #[test]
+    fn ics_utc_times_are_converted_to_local() {
+        let text = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20261018T150000Z\nDTEND:20261018T153000Z\nSUMMARY:Livestream\nEND:VEVENT\nEND:VCALENDAR\n";
+        let result = import(text, ScheduleImportFormat::ICalendar, &ScheduleImportOptions::default());
+        assert_eq!(
+            rows(&result.schedule),
+            vec![row("Livestream", &local_time(15, 0), &local_time(15, 30), "30mins", None)]
+        );
+    }
+
+    #[test]
+    fn ics_durations() {
+        assert_eq!(ics_duration("PT1H30M"), Some(5400));
+        assert_eq!(ics_duration("P1DT2H"), Some(93_600));
+        assert_eq!(ics_duration("P1W"), Some(604_800));
+        assert_eq!(ics_duration("-PT15M"), Some(-900));
+        assert_eq!(ics_duration("1H"), None);
+        assert_eq!(ics_duration("PT1X"), None);
+    }
+
+    #[test]
+    fn planning_center_json_round_trips_through_csv() {
+        let text = r#"{
+            "data": { "type": "Plan", "id": "1" },
+            "included": [
+                { "type": "PlanTime", "id": "t1", "attributes": { "time_type": "rehearsal", "starts_at": "2026-10-18T08:00:00Z" } },
+                { "type": "PlanTime", "id": "t2", "attributes": { "time_type": "service", "starts_at": "2026-10-18T10:00:00Z" } },
+                { "type": "Item", "id": "i2", "attributes": { "title": "Sermon", "sequence": 3, "length": 1800, "item_type": "item" } },
+                { "type": "Item", "id": "h1", "attributes": { "title": "Worship", "sequence": 1, "item_type": "header" } },
+                { "type": "Item", "id": "i1", "attributes": { "title": "Opening Song", "sequence": 2, "length": 300 } },
+                { "type": "Item", "id": "p1", "attributes": { "title": "Countdown", "sequence": 0, "length": 300, "service_position": "pre" } },
+                { "type": "ItemNote", "id": "n1", "attributes": { "category_name": "Person", "content": " Pastor Sam " },
+                  "relationships": { "item": { "data": { "type": "Item", "id": "i2" } } } }
+            ]
+        }"#;
+        let result = import(text, ScheduleImportFormat::PlanningCenterJson, &ScheduleImportOptions::default());
+        let expected = vec![
+            row("Opening Song", &local_time(10, 0), &local_time(10, 5), "05mins", None),
+            row("Sermon", &local_time(10, 5), &local_time(10, 35), "30mins", Some("Pastor Sam")),
+        ];
+        assert_eq!(rows(&result.schedule), expected);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, "outside_service_skipped");
+
+        let exported = export_csv(&result.schedule);
+        let reimported = import(&exported, ScheduleImportFormat::Csv, &ScheduleImportOptions::default());
+        assert_eq!(rows(&reimported.schedule), expected);
+
+        let exported = export_planning_center_csv(&result.schedule);
+        let reimported = import(&exported, ScheduleImportFormat::PlanningCenterCsv, &ScheduleImportOptions::default());
+        assert_eq!(rows(&reimported.schedule), expected);
+    }
+
+    #[test]
+    fn planning_center_json_without_items_is_rejected() {
+        let error = import_schedule(br#"{"data": []}"#, ScheduleImportFormat::PlanningCenterJson, &ScheduleImportOptions::default()).err();
+        assert_eq!(error.as_deref(), Some("planning_center_no_items"));
+    }
+}
@@ -0,0 +1,168 @@
+//timer_alerts.rs contains the rules that turn countdown milestones into stage cues
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const TIMER_ALERTS_FILE_NAME: &str = "timer_alerts.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AlertTrigger {
+    // Fires when the countdown reaches `seconds` remaining (0 = time's up).
+    Remaining { seconds: i64 },
+    // Fires each time another `interval_secs` of overrun has passed.
+    OverrunEvery { interval_secs: i64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertMidiAction {
+    pub device_id: String,
+    pub channel: u8, // 1-16
+    pub note: u8,
+    #[serde(default = "default_midi_velocity")]
+    pub velocity: u8,
+}
+
+fn default_midi_velocity() -> u8 {
+    127
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerAlertRule {
+    pub id: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    pub trigger: AlertTrigger,
+    pub message: String, // "{overrun}" is replaced with the overrun, e.g. "2:00"
+    #[serde(default)]
+    pub flash: bool,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub midi: Option<AlertMidiAction>,
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+// What stage clients receive in `timer_alert` and webhooks receive as the body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerAlert {
+    pub rule_id: String,
+    pub message: String,
+    pub flash: bool,
+    pub color: Option<String>,
+    pub time_left: i64,
+    pub session_name: Option<String>,
+    pub fired_at: u64,
+}
+
+// Shipped disabled so existing installs see no new cues until they opt in.
+pub fn default_alert_rules() -> Vec<TimerAlertRule> {
+    let rule = |id: &str, trigger: AlertTrigger, message: &str, color: &str| TimerAlertRule {
+        id: id.to_string(),
+        enabled: false,
+        trigger,
+        message: message.to_string(),
+        flash: true,
+        color: Some(color.to_string()),
+        midi: None,
+        webhook_url: None,
+    };
+    vec![
+        rule("two_minutes", AlertTrigger::Remaining { seconds: 120 }, "2 minutes", "#f5a623"),
+        rule("time_up", AlertTrigger::Remaining { seconds: 0 }, "Time's up", "#e53935"),
+        rule("overrun", AlertTrigger::OverrunEvery { interval_secs: 60 }, "Over by {overrun}", "#e53935"),
+    ]
+}
+
+pub fn validate_rules(rules: &[TimerAlertRule]) -> Result<(), String> {
+    let mut ids = std::collections::HashSet::new();
+    for rule in rules {
+        if rule.id.trim().is_empty() || !ids.insert(rule.id.as_str()) {
+            return Err(format!("invalid_rule_id:{}", rule.id));
+        }
+        match rule.trigger {
+            AlertTrigger::Remaining { seconds } if seconds < 0 => {
+                return Err(format!("invalid_trigger:{}", rule.id));
+            }
+            AlertTrigger::OverrunEvery { interval_secs } if interval_secs <= 0 => {
+                return Err(format!("invalid_trigger:{}", rule.id));
+            }
+            _ => {}
+        }
+        if let Some(midi) = &rule.midi {
+            if midi.channel == 0 || midi.channel > 16 || midi.note > 127 || midi.velocity > 127 {
+                return Err(format!("invalid_midi:{}", rule.id));
+            }
+        }
+        if let Some(url) = &rule.webhook_url {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(format!("invalid_webhook_url:{}", rule.id));
+            }
+        }
+    }
+    Ok(())
+}
+
+// The saved rules, or the defaults when none have been saved yet.
+pub fn load_rules(path: &Path) -> Result<Vec<TimerAlertRule>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(data) => serde_json::from_str(&data).map_err(|e| format!("invalid_timer_alerts:{}", e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(default_alert_rules()),
+        Err(e) => Err(format!("read_failed:{}", e)),
+    }
+}
+
+pub fn save_rules(path: &Path, rules: &[TimerAlertRule]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("create_dir_failed:{}", e))?;
+    }
+    let data = serde_json::to_string_pretty(rules).map_err(|e| format!("serialize_failed:{}", e))?;
+    std::fs::write(path, data).map_err(|e| format!("write_failed:{}", e))
+}
+
+fn format_overrun(seconds: i64) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+// Tracks the last published `time_left` of the running countdown so each
+// milestone fires once, when the clock crosses it. Adding time moves the
+// clock back above a milestone, so it can fire again.
+#[derive(Debug, Clone, Default)]
+pub struct TimerAlertTracker {
+    last_time_left: Option<i64>,
+}
+
+impl TimerAlertTracker {
+    // Call when a countdown starts or restarts.
+    pub fn reset(&mut self) {
+        self.last_time_left = None;
+    }
+
+    pub fn evaluate<'a>(&mut self, rules: &'a [TimerAlertRule], time_left: i64) -> Vec<(&'a TimerAlertRule, String)> {
+        let Some(previous) = self.last_time_left.replace(time_left) else {
+            return Vec::new();
+        };
+        if time_left >= previous {
+            return Vec::new();
+        }
+
+        rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .filter_map(|rule| {
+                let fires = match rule.trigger {
+                    AlertTrigger::Remaining { seconds } => previous > seconds && time_left <= seconds,
+                    AlertTrigger::OverrunEvery { interval_secs } => {
+                        let steps = |left: i64| (-left).max(0) / interval_secs;
+                        steps(time_left) > steps(previous)
+                    }
+                };
+                fires.then(|| (rule, rule.message.replace("{overrun}", &format_overrun((-time_left).max(0)))))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,295 @@
+//timer_engine.rs contains the server-side countdown that stage displays follow
+use crate::TimerState;
+use chrono::{DateTime, Local, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+// How often the server pushes `timer_update` while a timer is running.
+pub const TIMER_TICK_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerMode {
+    #[default]
+    Countdown,
+    CountUp, // stopwatch; `time_left` carries the elapsed seconds
+}
+
+// Elapsed time is measured on the monotonic clock, so wall-clock changes
+// (NTP corrections, DST) never make the countdown jump.
+#[derive(Debug, Clone, Default)]
+pub struct TimerEngine {
+    mode: TimerMode,
+    initial_secs: i64,
+    duration_secs: i64, // countdown length (or count-up offset) after adjustments
+    elapsed_before_pause: Duration,
+    running_since: Option<Instant>,
+    active: bool, // started and not stopped since
+    target: Option<NaiveTime>,
+    session_name: Option<String>,
+    end_time: Option<String>,
+}
+
+impl TimerEngine {
+    pub fn start(&mut self, seconds: i64, session_name: Option<String>, end_time: Option<String>, now: Instant) {
+        *self = TimerEngine {
+            mode: TimerMode::Countdown,
+            initial_secs: seconds,
+            duration_secs: seconds,
+            running_since: Some(now),
+            active: true,
+            session_name,
+            end_time,
+            ..TimerEngine::default()
+        };
+    }
+
+    // Counts down to a local time of day; `seconds` is the time until `target`.
+    pub fn start_until(&mut self, target: NaiveTime, seconds: i64, session_name: Option<String>, now: Instant) {
+        self.start(seconds, session_name, Some(format_time_of_day(target)), now);
+        self.target = Some(target);
+    }
+
+    pub fn start_count_up(&mut self, session_name: Option<String>, now: Instant) {
+        self.start(0, session_name, None, now);
+        self.mode = TimerMode::CountUp;
+    }
+
+    pub fn pause(&mut self, now: Instant) -> Result<(), String> {
+        let since = self.running_since.take().ok_or_else(|| "timer_not_running".to_string())?;
+        self.elapsed_before_pause += now.saturating_duration_since(since);
+        Ok(())
+    }
+
+    pub fn resume(&mut self, now: Instant) -> Result<(), String> {
+        if !self.active {
+            return Err("timer_not_started".to_string());
+        }
+        if self.running_since.is_some() {
+            return Err("timer_already_running".to_string());
+        }
+        self.running_since = Some(now);
+        Ok(())
+    }
+
+    // Back to the starting value, paused, dropping any adjustments.
+    pub fn reset(&mut self) -> Result<(), String> {
+        if !self.active {
+            return Err("timer_not_started".to_string());
+        }
+        self.duration_secs = self.initial_secs;
+        self.elapsed_before_pause = Duration::ZERO;
+        self.running_since = None;
+        Ok(())
+    }
+
+    pub fn stop(&mut self) {
+        *self = TimerEngine::default();
+    }
+
+    // Positive values add time to the clock, negative values take it away.
+    pub fn add_time(&mut self, seconds: i64) -> Result<(), String> {
+        if !self.active {
+            return Err("timer_not_started".to_string());
+        }
+        self.duration_secs = self.duration_secs.saturating_add(seconds);
+        Ok(())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub fn target(&self) -> Option<NaiveTime> {
+        self.target
+    }
+
+    pub fn session_name(&self) -> Option<String> {
+        self.session_name.clone()
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.elapsed_before_pause
+            + self
+                .running_since
+                .map(|since| now.saturating_duration_since(since))
+                .unwrap_or(Duration::ZERO)
+    }
+
+    // Whole seconds shown on the clock. A 300s countdown shows 300 for its
+    // first second (matching the frontend's one-second tick) and goes negative
+    // once overrun; count-up shows elapsed seconds.
+    pub fn time_left(&self, now: Instant) -> i64 {
+        let elapsed = self.elapsed(now).as_secs() as i64;
+        match self.mode {
+            TimerMode::Countdown => self.duration_secs.saturating_sub(elapsed),
+            TimerMode::CountUp => self.duration_secs.saturating_add(elapsed).max(0),
+        }
+    }
+
+    // `wall_clock_ms` is the Unix time matching `now`; the deadline is derived
+    // from the monotonic remaining time so it stays fixed while running.
+    pub fn snapshot(&self, now: Instant, wall_clock_ms: u64) -> TimerState {
+        let time_left = if self.active { self.time_left(now) } else { 0 };
+        let deadline = if self.is_running() {
+            let elapsed_ms = self.elapsed(now).as_millis() as i64;
+            let zero_offset_ms = match self.mode {
+                TimerMode::Countdown => self.duration_secs.saturating_mul(1000).saturating_sub(elapsed_ms),
+                TimerMode::CountUp => self.duration_secs.saturating_mul(1000).saturating_add(elapsed_ms).saturating_neg(),
+            };
+            u64::try_from((wall_clock_ms as i64).saturating_add(zero_offset_ms)).ok()
+        } else {
+            None
+        };
+        TimerState {
+            is_running: self.is_running(),
+            time_left: time_left.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            session_name: self.session_name.clone(),
+            end_time: self.end_time.clone(),
+            is_overrun: self.active && self.mode == TimerMode::Countdown && time_left < 0,
+            mode: self.mode,
+            deadline,
+            server_time: Some(wall_clock_ms),
+        }
+    }
+}
+
+// Accepts "11:45", "23:45:30", "11:45 AM", "11:45am" and "9 PM".
+pub fn parse_time_of_day(value: &str) -> Result<NaiveTime, String> {
+    let normalized = value.trim().to_uppercase().replace('.', "");
+    let (clock, meridiem) = match normalized.strip_suffix("AM").or_else(|| normalized.strip_suffix('A')) {
+        Some(clock) => (clock.trim(), Some(false)),
+        None => match normalized.strip_suffix("PM").or_else(|| normalized.strip_suffix('P')) {
+            Some(clock) => (clock.trim(), Some(true)),
+            None => (normalized.as_str(), None),
+        },
+    };
+
+    let parts: Vec<&str> = clock.split(':').map(|p| p.trim()).collect();
+    let number = |i: usize| -> Result<u32, String> {
+        match parts.get(i) {
+            Some(p) if !p.is_empty() => p.parse::<u32>().map_err(|_| format!("invalid_time:{}", value)),
+            Some(_) => Err(format!("invalid_time:{}", value)),
+            None => Ok(0),
+        }
+    };
+    if parts.is_empty() || parts.len() > 3 {
+        return Err(format!("invalid_time:{}", value));
+    }
+    let (mut hour, minute, second) = (number(0)?, number(1)?, number(2)?);
+    if parts.len() == 1 && meridiem.is_none() {
+        return Err(format!("invalid_time:{}", value));
+    }
+
+    if let Some(pm) = meridiem {
+        if hour == 0 || hour > 12 {
+            return Err(format!("invalid_time:{}", value));
+        }
+        hour = match (hour, pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (h, true) => h + 12,
+            (h, false) => h,
+        };
+    }
+    NaiveTime::from_hms_opt(hour, minute, second).ok_or_else(|| format!("invalid_time:{}", value))
+}
+
+pub fn format_time_of_day(time: NaiveTime) -> String {
+    time.format("%-I:%M %p").to_string()
+}
+
+// Seconds from `now` until the next `target` on the local clock. Targets up to
+// 12 hours in the past count as today (the timer starts overrun); anything
+// earlier rolls over to tomorrow.
+pub fn seconds_until(target: NaiveTime, now: DateTime<Local>) -> i64 {
+    let now = now.naive_local();
+    let seconds = (now.date().and_time(target) - now).num_seconds();
+    if seconds < -12 * 3600 {
+        seconds + 24 * 3600
+    } else {
+        seconds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn local(hour: u32, minute: u32) -> DateTime<Local> {
+        let naive = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap().and_hms_opt(hour, minute, 0).unwrap();
+        Local.from_local_datetime(&naive).earliest().unwrap()
+    }
+
+    #[test]
+    fn parses_twelve_and_twenty_four_hour_times() {
+        assert_eq!(parse_time_of_day("11:45 AM"), Ok(NaiveTime::from_hms_opt(11, 45, 0).unwrap()));
+        assert_eq!(parse_time_of_day("11:45pm"), Ok(NaiveTime::from_hms_opt(23, 45, 0).unwrap()));
+        assert_eq!(parse_time_of_day("12:05 a.m."), Ok(NaiveTime::from_hms_opt(0, 5, 0).unwrap()));
+        assert_eq!(parse_time_of_day("9 PM"), Ok(NaiveTime::from_hms_opt(21, 0, 0).unwrap()));
+        assert_eq!(parse_time_of_day("23:45:30"), Ok(NaiveTime::from_hms_opt(23, 45, 30).unwrap()));
+        assert!(parse_time_of_day("9").is_err());
+        assert!(parse_time_of_day("13:00 PM").is_err());
+        assert!(parse_time_of_day("25:00").is_err());
+        assert!(parse_time_of_day("noon").is_err());
+    }
+
+    #[test]
+    fn countdown_reaches_zero_then_overruns() {
+        let start = Instant::now();
+        let mut engine = TimerEngine::default();
+        engine.start(3, Some("Sermon".to_string()), None, start);
+
+        assert_eq!(engine.time_left(start), 3);
+        assert_eq!(engine.time_left(start + Duration::from_secs(3)), 0);
+        let state = engine.snapshot(start + Duration::from_secs(3), 10_000);
+        assert!(!state.is_overrun);
+        assert_eq!(state.deadline, Some(10_000));
+
+        let state = engine.snapshot(start + Duration::from_secs(5), 12_000);
+        assert_eq!(state.time_left, -2);
+        assert!(state.is_overrun);
+        assert!(state.is_running);
+    }
+
+    #[test]
+    fn paused_time_does_not_count() {
+        let start = Instant::now();
+        let mut engine = TimerEngine::default();
+        engine.start(10, None, None, start);
+
+        engine.pause(start + Duration::from_secs(4)).unwrap();
+        assert_eq!(engine.pause(start + Duration::from_secs(5)), Err("timer_not_running".to_string()));
+        assert_eq!(engine.time_left(start + Duration::from_secs(100)), 6);
+        assert_eq!(engine.snapshot(start + Duration::from_secs(100), 0).deadline, None);
+
+        engine.resume(start + Duration::from_secs(100)).unwrap();
+        assert_eq!(engine.resume(start + Duration::from_secs(101)), Err("timer_already_running".to_string()));
+        assert_eq!(engine.time_left(start + Duration::from_secs(102)), 4);
+
+        engine.reset().unwrap();
+        assert!(!engine.is_running());
+        assert_eq!(engine.time_left(start + Duration::from_secs(200)), 10);
+    }
+
+    #[test]
+    fn time_of_day_target_rolls_over_midnight() {
+        let target = NaiveTime::from_hms_opt(0, 10, 0).unwrap();
+        assert_eq!(seconds_until(target, local(23, 50)), 20 * 60);
+
+        let start = Instant::now();
+        let mut engine = TimerEngine::default();
+        engine.start_until(target, seconds_until(target, local(23, 50)), None, start);
+        assert_eq!(engine.time_left(start + Duration::from_secs(60)), 19 * 60);
+        assert_eq!(engine.snapshot(start, 0).end_time.as_deref(), Some("12:10 AM"));
+
+        // A target a few minutes ago is today, so the timer starts overrun.
+        let target = NaiveTime::from_hms_opt(23, 40, 0).unwrap();
+        assert_eq!(seconds_until(target, local(23, 50)), -10 * 60);
+    }
+}
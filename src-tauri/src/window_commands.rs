@@ -1,4 +1,5 @@
 //window_commands.rs contains the commands for the window
+use crate::monitors::{self, SafeMonitorInfo};
 use log::error;
 use tauri::{AppHandle, Manager, WebviewWindowBuilder};
 
@@ -8,9 +9,10 @@ pub async fn open_dialog(
     _webview_window: tauri::WebviewWindow,
     dialog_window: String,
     monitor_index: Option<usize>,
+    monitor: Option<SafeMonitorInfo>,
 ) -> Result<(), String> {
     // Existing logic for other dialogs (if any)
-    open_dialog_impl(app_handle, dialog_window, monitor_index)
+    open_dialog_impl(app_handle, dialog_window, monitor_index, monitor)
         .await
         .map_err(|e| e.to_string())
 }
@@ -19,6 +21,7 @@ async fn open_dialog_impl(
     handle: AppHandle,
     dialog_window: String,
     monitor_index: Option<usize>,
+    monitor: Option<SafeMonitorInfo>,
 ) -> Result<(), String> {
     let dialog_label = format!("dialog-{}", dialog_window);
     let title = if dialog_window == "audience-test" {
@@ -44,37 +47,13 @@ async fn open_dialog_impl(
             .inner_size(800.0, 600.0)
             .min_inner_size(800.0, 600.0);
 
-        // Calculate position if a monitor is selected
-        if let Some(index) = monitor_index {
-            if let Ok(mut monitors) = handle.available_monitors() {
-                monitors.sort_by(|a, b| {
-                    let a_pos = a.position();
-                    let b_pos = b.position();
-                    let a_primary = a_pos.x == 0 && a_pos.y == 0;
-                    let b_primary = b_pos.x == 0 && b_pos.y == 0;
-                    if a_primary != b_primary {
-                        return if a_primary { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater };
-                    }
-                    if a_pos.x != b_pos.x {
-                        return a_pos.x.cmp(&b_pos.x);
-                    }
-                    if a_pos.y != b_pos.y {
-                        return a_pos.y.cmp(&b_pos.y);
-                    }
-                    let a_size = a.size();
-                    let b_size = b.size();
-                    if a_size.width != b_size.width {
-                        return a_size.width.cmp(&b_size.width);
-                    }
-                    if a_size.height != b_size.height {
-                        return a_size.height.cmp(&b_size.height);
-                    }
-                    let a_name = a.name().map(|s| s.as_str()).unwrap_or("");
-                    let b_name = b.name().map(|s| s.as_str()).unwrap_or("");
-                    a_name.cmp(&b_name)
-                });
+        crate::monitor_watcher::forget_window_monitor(&dialog_label);
 
-                if let Some(monitor) = monitors.get(index) {
+        // Calculate position if a monitor is selected (by fingerprint or index)
+        if monitor_index.is_some() || monitor.is_some() {
+            if let Ok(mut monitors) = handle.available_monitors() {
+                if let Some(monitor) = monitors::select_monitor(&mut monitors, monitor_index, monitor.as_ref()) {
+                    crate::monitor_watcher::remember_window_monitor(&dialog_label, monitor, is_second_screen);
                     let monitor_position = monitor.position();
                     let monitor_size = monitor.size();
                     let scale_factor = monitor.scale_factor();
@@ -0,0 +1,301 @@
+import React, { useCallback, useEffect, useState } from "react";
+import type { Monitor } from "@tauri-apps/api/window";
+import { FaLayerGroup } from "react-icons/fa";
+import {
+  applyDisplayLayoutProfile,
+  deleteDisplayLayoutProfile,
+  findMonitorIndex,
+  getDisplayOutputState,
+  listDisplayLayoutProfiles,
+  listDisplayOutputs,
+  loadDisplayScripture,
+  loadDisplaySlides,
+  openDisplayOutputWindow,
+  removeDisplayOutput,
+  saveDisplayLayoutProfile,
+  sendToDisplayOutput,
+  toMonitorFingerprint,
+} from "../services/displayService";
+import {
+  DisplayLayoutProfile,
+  DisplaySettings,
+  MAIN_DISPLAY_OUTPUT,
+  MonitorFingerprint,
+} from "../types/display";
+import MonitorSelectDropdown from "./MonitorSelectDropdown";
+import { sectionStyle, sectionHeaderStyle } from "../utils/settingsSectionStyles";
+
+// Same rule as the backend: up to 32 letters, digits, "-" or "_", lowercased.
+const OUTPUT_ID_PATTERN = /^[a-z0-9_-]{1,32}$/;
+
+interface DisplayOutputsSectionProps {
+  monitors: Monitor[];
+  onRefreshMonitors: () => void;
+  mainSettings: DisplaySettings;
+}
+
+// The monitor an output's settings point at, as an index into `monitors`.
+function selectedMonitorIndex(monitors: Monitor[], settings: DisplaySettings): number | null {
+  const index = settings.monitor ? findMonitorIndex(monitors, settings.monitor) : null;
+  return index ?? settings.monitorIndex;
+}
+
+function selectedMonitor(monitors: Monitor[], settings: DisplaySettings): MonitorFingerprint | null {
+  if (settings.monitor) return settings.monitor;
+  const index = settings.monitorIndex;
+  return index !== null && monitors[index] ? toMonitorFingerprint(monitors[index]) : null;
+}
+
+// Extra outputs (stage monitor, lower third, ...) beside the main audience
+// display, and named layouts placing every output on a monitor. Each output
+// has its own window and state; external tools can feed them through
+// PUT /api/v1/display?output=<id>.
+const DisplayOutputsSection: React.FC<DisplayOutputsSectionProps> = ({
+  monitors,
+  onRefreshMonitors,
+  mainSettings,
+}) => {
+  const [outputSettings, setOutputSettings] = useState<Record<string, DisplaySettings>>({});
+  const [newOutputId, setNewOutputId] = useState("");
+  const [profiles, setProfiles] = useState<DisplayLayoutProfile[]>([]);
+  const [newProfileName, setNewProfileName] = useState("");
+  const [message, setMessage] = useState("");
+
+  const refreshOutputs = useCallback(async () => {
+    try {
+      const ids = (await listDisplayOutputs()).filter((id) => id !== MAIN_DISPLAY_OUTPUT);
+      const states = await Promise.all(ids.map((id) => getDisplayOutputState(id)));
+      setOutputSettings(Object.fromEntries(ids.map((id, i) => [id, states[i].settings])));
+    } catch (error) {
+      console.warn("[Display] Failed to list display outputs:", error);
+    }
+  }, []);
+
+  const refreshProfiles = useCallback(async () => {
+    try {
+      setProfiles(await listDisplayLayoutProfiles());
+    } catch (error) {
+      console.warn("[Display] Failed to list layout profiles:", error);
+    }
+  }, []);
+
+  useEffect(() => {
+    void refreshOutputs();
+    void refreshProfiles();
+  }, [refreshOutputs, refreshProfiles]);
+
+  const run = async (action: () => Promise<void>, failure: string) => {
+    setMessage("");
+    try {
+      await action();
+    } catch (error) {
+      setMessage(`${failure}: ${error}`);
+    }
+    await refreshOutputs();
+  };
+
+  // Copies what the main display shows, keeping the output's own settings.
+  const showMainContent = (output: string, settings: DisplaySettings) =>
+    sendToDisplayOutput(output, {
+      scripture: loadDisplayScripture(),
+      slides: loadDisplaySlides(),
+      settings,
+    });
+
+  // Stored in the output's settings, so it survives restarts and rearranged monitors.
+  const selectOutputMonitor = async (output: string, index: number) => {
+    const current = await getDisplayOutputState(output);
+    await sendToDisplayOutput(output, {
+      ...current,
+      settings: {
+        ...current.settings,
+        monitorIndex: index,
+        monitor: monitors[index] ? toMonitorFingerprint(monitors[index]) : null,
+      },
+    });
+  };
+
+  const saveCurrentLayout = async () => {
+    const entries: { output: string; monitor: MonitorFingerprint }[] = [];
+    const mainMonitor = selectedMonitor(monitors, mainSettings);
+    if (mainMonitor) entries.push({ output: MAIN_DISPLAY_OUTPUT, monitor: mainMonitor });
+    for (const [output, settings] of Object.entries(outputSettings)) {
+      const monitor = selectedMonitor(monitors, settings);
+      if (monitor) entries.push({ output, monitor });
+    }
+    if (entries.length === 0) {
+      throw new Error("pick a monitor for at least one output first");
+    }
+    await saveDisplayLayoutProfile(newProfileName.trim(), entries);
+    setNewProfileName("");
+    await refreshProfiles();
+  };
+
+  const applyProfile = async (name: string) => {
+    const results = await applyDisplayLayoutProfile(name);
+    const problems = results
+      .filter((r) => r.status === "monitor_missing" || r.status === "failed")
+      .map((r) => `${r.output}: ${r.status === "failed" ? r.error ?? "failed" : "monitor not connected"}`);
+    if (problems.length > 0) {
+      throw new Error(problems.join("; "));
+    }
+  };
+
+  const outputs = Object.keys(outputSettings).sort();
+  const normalizedNewId = newOutputId.trim().toLowerCase();
+  const newIdValid =
+    OUTPUT_ID_PATTERN.test(normalizedNewId) &&
+    normalizedNewId !== MAIN_DISPLAY_OUTPUT &&
+    !outputs.includes(normalizedNewId);
+
+  return (
+    <div style={sectionStyle}>
+      <div style={sectionHeaderStyle}>
+        <FaLayerGroup />
+        <h3 style={{ margin: 0 }}>Additional Outputs</h3>
+      </div>
+      <p className="instruction-text" style={{ marginTop: 0 }}>
+        Open more display windows, e.g. a stage monitor. A new output starts with the main
+        display's content and settings.
+      </p>
+
+      {outputs.map((output) => (
+        <div
+          key={output}
+          style={{ display: "flex", gap: "8px", alignItems: "center", flexWrap: "wrap", marginBottom: "8px" }}
+        >
+          <strong style={{ minWidth: "120px" }}>{output}</strong>
+          <div style={{ minWidth: "220px" }}>
+            <MonitorSelectDropdown
+              monitors={monitors}
+              selectedIndex={selectedMonitorIndex(monitors, outputSettings[output])}
+              onSelect={(index) => run(() => selectOutputMonitor(output, index), "Failed to set monitor")}
+              onRefresh={onRefreshMonitors}
+            />
+          </div>
+          <button
+            type="button"
+            className="secondary"
+            onClick={() =>
+              run(
+                () =>
+                  openDisplayOutputWindow(
+                    output,
+                    outputSettings[output].monitorIndex,
+                    outputSettings[output].monitor ?? null
+                  ),
+                "Failed to open window"
+              )
+            }
+          >
+            Open window
+          </button>
+          <button
+            type="button"
+            className="secondary"
+            onClick={() =>
+              run(() => showMainContent(output, outputSettings[output]), "Failed to update output")
+            }
+          >
+            Show main content
+          </button>
+          <button
+            type="button"
+            className="secondary"
+            onClick={() => run(() => removeDisplayOutput(output), "Failed to remove output")}
+          >
+            Remove
+          </button>
+        </div>
+      ))}
+
+      <div style={{ display: "flex", gap: "8px", alignItems: "center", flexWrap: "wrap" }}>
+        <input
+          type="text"
+          placeholder="Output id, e.g. stage"
+          value={newOutputId}
+          maxLength={32}
+          onChange={(event) => setNewOutputId(event.target.value)}
+          style={{ width: "200px" }}
+        />
+        <button
+          type="button"
+          disabled={!newIdValid}
+          onClick={() =>
+            run(async () => {
+              await showMainContent(normalizedNewId, { ...mainSettings, monitorIndex: null, monitor: null });
+              setNewOutputId("");
+            }, "Failed to add output")
+          }
+        >
+          Add output
+        </button>
+      </div>
+      {newOutputId.trim() !== "" && !newIdValid && (
+        <p className="instruction-text">
+          Use up to 32 letters, digits, "-" or "_", different from "main" and existing outputs.
+        </p>
+      )}
+
+      <h4 style={{ marginBottom: "8px" }}>Layout Profiles</h4>
+      <p className="instruction-text" style={{ marginTop: 0 }}>
+        Save which monitor each output is on (e.g. "Sunday main hall") and restore it in one click.
+      </p>
+      {profiles.map((profile) => (
+        <div
+          key={profile.name}
+          style={{ display: "flex", gap: "8px", alignItems: "center", flexWrap: "wrap", marginBottom: "8px" }}
+        >
+          <strong style={{ minWidth: "120px" }}>{profile.name}</strong>
+          <span className="instruction-text" style={{ margin: 0 }}>
+            {profile.outputs.map((entry) => `${entry.output} → ${entry.monitor.name ?? "unnamed monitor"}`).join(", ")}
+          </span>
+          <button
+            type="button"
+            className="secondary"
+            onClick={() => run(() => applyProfile(profile.name), "Layout applied with problems")}
+          >
+            Apply
+          </button>
+          <button
+            type="button"
+            className="secondary"
+            onClick={() =>
+              run(async () => {
+                await deleteDisplayLayoutProfile(profile.name);
+                await refreshProfiles();
+              }, "Failed to delete layout")
+            }
+          >
+            Delete
+          </button>
+        </div>
+      ))}
+      <div style={{ display: "flex", gap: "8px", alignItems: "center", flexWrap: "wrap" }}>
+        <input
+          type="text"
+          placeholder="Layout name"
+          value={newProfileName}
+          maxLength={64}
+          onChange={(event) => setNewProfileName(event.target.value)}
+          style={{ width: "200px" }}
+        />
+        <button
+          type="button"
+          disabled={!newProfileName.trim()}
+          onClick={() => run(saveCurrentLayout, "Failed to save layout")}
+        >
+          Save current layout
+        </button>
+      </div>
+
+      {message && (
+        <p className="instruction-text" style={{ color: "var(--error)" }}>
+          {message}
+        </p>
+      )}
+    </div>
+  );
+};
+
+export default DisplayOutputsSection;
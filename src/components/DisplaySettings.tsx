@@ -7,11 +7,16 @@ import type { Monitor } from "@tauri-apps/api/window";
 import { useDebouncedEffect } from "../hooks/useDebouncedEffect";
 import { useAutoFontSize } from "../hooks/useAutoFontSize";
 import {
+  clampTimerFontSize,
   closeDisplayWindow,
+  findMonitorIndex,
   getAvailableMonitors,
   loadDisplaySettings,
   openDisplayWindow,
   saveDisplaySettings,
+  toMonitorFingerprint,
+  TIMER_FONT_SIZE_MAX,
+  TIMER_FONT_SIZE_MIN,
 } from "../services/displayService";
 import {
   DEFAULT_DISPLAY_SETTINGS,
@@ -22,6 +27,7 @@ import {
 import DisplayLayoutEditorModal from "./DisplayLayoutEditorModal";
 import SlidesLayoutEditorModal from "./SlidesLayoutEditorModal";
 import MonitorSelectDropdown from "./MonitorSelectDropdown";
+import DisplayOutputsSection from "./DisplayOutputsSection";
 import { sectionStyle, sectionHeaderStyle } from "../utils/settingsSectionStyles";
 import {
   getLiveSlidesServerInfo,
@@ -90,6 +96,8 @@ const DisplaySettings: React.FC = () => {
   const [isStartingServer, setIsStartingServer] = useState(false);
   const [testWindowStatus, setTestWindowStatus] = useState<string>("");
   const [osType, setOsType] = useState<"windows" | "mac" | "linux" | "unknown">("unknown");
+  // What's typed in the timer size box; only in-range values reach settings.
+  const [timerFontSizeDraft, setTimerFontSizeDraft] = useState<string | null>(null);
 
   useEffect(() => {
     const userAgent = navigator.userAgent;
@@ -120,12 +128,33 @@ const DisplaySettings: React.FC = () => {
     setMonitors(list);
     // Note: Don't auto-reset monitorIndex to 0 here - let the auto-start effect handle validation
     // This prevents race conditions where monitors are detected in different order at startup
+    // Follow the saved monitor when the monitors were rearranged.
+    setSettings((prev) => {
+      const index = prev.monitor ? findMonitorIndex(list, prev.monitor) : null;
+      return index === null || index === prev.monitorIndex ? prev : { ...prev, monitorIndex: index };
+    });
   };
 
+  // Keeps the monitor's fingerprint next to its index.
+  const monitorSelection = (index: number) => ({
+    monitorIndex: index,
+    monitor: monitors[index] ? toMonitorFingerprint(monitors[index]) : null,
+  });
+
   useEffect(() => {
     void loadMonitors();
   }, []);
 
+  // The backend watches for monitors being plugged in, unplugged or resized.
+  useEffect(() => {
+    const unlistenPromise = listen("monitors_changed", () => {
+      void loadMonitors();
+    });
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten()).catch(console.warn);
+    };
+  }, []);
+
   // Load server info for web display URL
   const loadServerInfo = async () => {
     try {
@@ -247,7 +276,7 @@ const DisplaySettings: React.FC = () => {
         // Auto-select first secondary monitor or primary if only one monitor
         const defaultIndex = monitors.length > 1 ? 1 : 0;
         console.log(`[Display] Invalid monitor index ${currentIndex} (have ${monitors.length} monitors), auto-selecting ${defaultIndex}`);
-        setSettings((prev) => ({ ...prev, monitorIndex: defaultIndex }));
+        setSettings((prev) => ({ ...prev, ...monitorSelection(defaultIndex) }));
         return; // Will retry on next render with valid monitorIndex
       }
 
@@ -443,7 +472,7 @@ const DisplaySettings: React.FC = () => {
             console.log(`[Display] Windows: Invalid monitor index ${currentMonitorIndex} (have ${monitors.length} monitors), auto-selecting ${defaultIndex}`);
             currentMonitorIndex = defaultIndex;
             // Update settings so it persists
-            setSettings((prev) => ({ ...prev, monitorIndex: defaultIndex }));
+            setSettings((prev) => ({ ...prev, ...monitorSelection(defaultIndex) }));
           }
 
           console.log(`[Display] Windows: Opening audience window on monitor ${currentMonitorIndex}`);
@@ -777,7 +806,7 @@ const DisplaySettings: React.FC = () => {
                   setSettings((prev) => ({
                     ...prev,
                     enabled: newEnabled,
-                    monitorIndex: defaultIndex,
+                    ...monitorSelection(defaultIndex),
                   }));
                 } else {
                   setSettings((prev) => ({
@@ -829,7 +858,7 @@ const DisplaySettings: React.FC = () => {
                   }
                   if (newEnabled && settings.monitorIndex === null && monitors.length > 0) {
                      const defaultIndex = monitors.length > 1 ? 1 : 0;
-                     setSettings((prev) => ({ ...prev, enabled: newEnabled, monitorIndex: defaultIndex }));
+                     setSettings((prev) => ({ ...prev, enabled: newEnabled, ...monitorSelection(defaultIndex) }));
                   } else {
                      setSettings((prev) => ({ ...prev, enabled: newEnabled }));
                   }
@@ -931,6 +960,9 @@ const DisplaySettings: React.FC = () => {
                 <div style={{ fontSize: "0.75rem", color: "var(--app-text-color-secondary)", marginTop: "4px" }}>
                   Local: http://localhost:{serverInfo.server_port}/display
                 </div>
+                <div style={{ fontSize: "0.75rem", color: "var(--app-text-color-secondary)", marginTop: "4px" }}>
+                  OBS/vMix overlays: /overlay/scripture, /overlay/speaker, /overlay/timer (e.g. ?theme=light&amp;position=bottom-left)
+                </div>
               </div>
               <button
                 onClick={async () => {
@@ -971,7 +1003,7 @@ const DisplaySettings: React.FC = () => {
               monitors={monitors}
               selectedIndex={settings.monitorIndex}
               onSelect={(index) =>
-                setSettings((prev) => ({ ...prev, monitorIndex: index }))
+                setSettings((prev) => ({ ...prev, ...monitorSelection(index) }))
               }
               onRefresh={() => void loadMonitors()}
             />
@@ -982,6 +1014,12 @@ const DisplaySettings: React.FC = () => {
         </div>
       </div>
 
+      <DisplayOutputsSection
+        monitors={monitors}
+        onRefreshMonitors={() => void loadMonitors()}
+        mainSettings={settings}
+      />
+
       {/* Background */}
       <div style={sectionStyle}>
         <div style={sectionHeaderStyle}>
@@ -1725,26 +1763,35 @@ const DisplaySettings: React.FC = () => {
             max={300}
             step={2}
             value={settings.timerFontSize}
-            onChange={(event) =>
+            onChange={(event) => {
+              setTimerFontSizeDraft(null);
               setSettings((prev) => ({
                 ...prev,
                 timerFontSize: Number(event.target.value),
-              }))
-            }
+              }));
+            }}
             style={{ flex: 1, minWidth: "180px" }}
           />
           <input
             type="number"
-            min={12}
-            max={300}
+            min={TIMER_FONT_SIZE_MIN}
+            max={TIMER_FONT_SIZE_MAX}
             step={1}
-            value={settings.timerFontSize}
-            onChange={(event) =>
-              setSettings((prev) => ({
-                ...prev,
-                timerFontSize: Number(event.target.value) || 0,
-              }))
-            }
+            value={timerFontSizeDraft ?? settings.timerFontSize}
+            onChange={(event) => {
+              const value = Number(event.target.value);
+              setTimerFontSizeDraft(event.target.value);
+              if (event.target.value !== "" && value === clampTimerFontSize(value)) {
+                setSettings((prev) => ({ ...prev, timerFontSize: value }));
+              }
+            }}
+            onBlur={() => {
+              if (timerFontSizeDraft !== null && timerFontSizeDraft !== "") {
+                const timerFontSize = clampTimerFontSize(Number(timerFontSizeDraft));
+                setSettings((prev) => ({ ...prev, timerFontSize }));
+              }
+              setTimerFontSizeDraft(null);
+            }}
             style={{ width: "80px" }}
           />
           <span style={{ color: "var(--app-text-color-secondary)", fontSize: "0.85rem" }}>px</span>
@@ -23,7 +23,11 @@ interface ImportFromLiveSlidesModalProps {
     itemName: string,
     templateName: string,
     slides: Pick<Slide, "text" | "layout" | "isAutoScripture">[],
-    options?: { liveSlidesSessionId?: string; liveSlidesLinked?: boolean }
+    options?: {
+      liveSlidesSessionId?: string;
+      liveSlidesLinked?: boolean;
+      ccliNumber?: string;
+    }
   ) => boolean;
   templates: Template[];
 }
@@ -134,6 +138,7 @@ const ImportFromLiveSlidesModal: React.FC<ImportFromLiveSlidesModalProps> = ({
     onImport(itemName.trim(), LIVE_SLIDES_TEMPLATE_NAME, slides, {
       liveSlidesSessionId: selectedSession.id,
       liveSlidesLinked: true, // join live updates
+      ccliNumber: selectedSession.ccli_number ?? undefined,
     });
     onClose();
   };
@@ -16,7 +16,11 @@ interface ImportFromNetworkModalProps {
     itemName: string,
     templateName: string,
     slides: Pick<Slide, "text" | "layout" | "isAutoScripture">[],
-    options?: { liveSlidesSessionId?: string; liveSlidesLinked?: boolean }
+    options?: {
+      liveSlidesSessionId?: string;
+      liveSlidesLinked?: boolean;
+      ccliNumber?: string;
+    }
   ) => boolean;
 }
 
@@ -149,7 +153,9 @@ const ImportFromNetworkModal: React.FC<ImportFromNetworkModalProps> = ({
         if (selectedSessionIds.has(key)) {
           attempted++;
           const slides = convertPlaylistItemSlides(item);
-          const success = onImport(item.title, item.templateName, slides);
+          const success = onImport(item.title, item.templateName, slides, {
+            ccliNumber: item.ccliNumber,
+          });
           if (success) {
             imported++;
           } else {
@@ -23,7 +23,11 @@ interface ImportModalProps {
     itemName: string,
     templateName: string,
     slides: Pick<Slide, "text" | "layout" | "isAutoScripture">[],
-    options?: { liveSlidesSessionId?: string; liveSlidesLinked?: boolean }
+    options?: {
+      liveSlidesSessionId?: string;
+      liveSlidesLinked?: boolean;
+      ccliNumber?: string;
+    }
   ) => void;
   templates: Template[];
 }
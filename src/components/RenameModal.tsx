@@ -3,9 +3,11 @@ import React, { useState, useEffect } from "react";
 interface RenameModalProps {
   isOpen: boolean;
   onClose: () => void;
-  onRename: (newName: string) => void;
+  onRename: (newName: string, ccliNumber?: string) => void;
   currentName: string;
   title: string;
+  // Shows a CCLI song number field (playlist items) when set; "" for none.
+  currentCcliNumber?: string;
 }
 
 const RenameModal: React.FC<RenameModalProps> = ({
@@ -14,14 +16,17 @@ const RenameModal: React.FC<RenameModalProps> = ({
   onRename,
   currentName,
   title,
+  currentCcliNumber,
 }) => {
   const [name, setName] = useState(currentName);
+  const [ccliNumber, setCcliNumber] = useState(currentCcliNumber ?? "");
 
   useEffect(() => {
     if (isOpen) {
       setName(currentName);
+      setCcliNumber(currentCcliNumber ?? "");
     }
-  }, [isOpen, currentName]);
+  }, [isOpen, currentName, currentCcliNumber]);
 
   if (!isOpen) {
     return null;
@@ -29,7 +34,7 @@ const RenameModal: React.FC<RenameModalProps> = ({
 
   const handleSave = () => {
     if (name.trim()) {
-      onRename(name.trim());
+      onRename(name.trim(), ccliNumber.trim());
       onClose();
     }
   };
@@ -55,6 +60,20 @@ const RenameModal: React.FC<RenameModalProps> = ({
             autoFocus
           />
         </div>
+        {currentCcliNumber !== undefined && (
+          <div className="form-group">
+            <label htmlFor="rename-ccli-input">CCLI Song Number</label>
+            <input
+              type="text"
+              id="rename-ccli-input"
+              inputMode="numeric"
+              placeholder="e.g. 22025"
+              value={ccliNumber}
+              onChange={(e) => setCcliNumber(e.target.value.replace(/\D/g, ""))}
+              onKeyDown={handleKeyDown}
+            />
+          </div>
+        )}
         <div className="modal-actions">
           <button onClick={onClose}>Cancel</button>
           <button onClick={handleSave} className="primary">
@@ -47,6 +47,7 @@ interface SlideDisplayAreaProps {
   };
   onResumeLiveSlidesSession?: () => void;
   proofreadCorrectedSlideIds?: string[]; // IDs of slides that were corrected by AI proofreading
+  cursorLiveSlideId?: string | null; // Live slide per the server cursor; undefined when not following one
 }
 
 interface ContextMenuState {
@@ -73,6 +74,7 @@ const SlideDisplayArea: React.FC<SlideDisplayAreaProps> = ({
   liveSlidesStatus,
   onResumeLiveSlidesSession,
   proofreadCorrectedSlideIds = [], // IDs of slides corrected by AI proofreading
+  cursorLiveSlideId,
 }) => {
   const { startSession } = useStageAssist();
   const [
@@ -99,6 +101,11 @@ const SlideDisplayArea: React.FC<SlideDisplayAreaProps> = ({
   const [showSearchOptions, setShowSearchOptions] = useState<boolean>(false);
   const [hasFocusedSearch, setHasFocusedSearch] = useState<boolean>(false);
 
+  // Follow go-live/clear made elsewhere (remote controllers, other windows).
+  useEffect(() => {
+    if (cursorLiveSlideId !== undefined) setLiveSlideId(cursorLiveSlideId);
+  }, [cursorLiveSlideId]);
+
   useEffect(() => {
     setEditingSlideId(null);
     setEditingLines([]);
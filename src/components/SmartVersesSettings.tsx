@@ -1914,6 +1914,33 @@ const SmartVersesSettings: React.FC<SmartVersesSettingsProps> = ({
                     >
                       Refresh Native Devices
                     </button>
+                    <select
+                      value={settings.nativeMicrophoneChannel || "average"}
+                      onChange={(e) =>
+                        handleChange(
+                          "nativeMicrophoneChannel",
+                          e.target.value === "average" ? undefined : e.target.value
+                        )
+                      }
+                      style={{ ...inputStyle, marginTop: "var(--spacing-2)" }}
+                      disabled={
+                        settings.runTranscriptionInBrowser ||
+                        settings.remoteTranscriptionEnabled
+                      }
+                    >
+                      <option value="average">All Channels (Average)</option>
+                      <option value="left">Left</option>
+                      <option value="right">Right</option>
+                      {[3, 4, 5, 6, 7, 8].map((n) => (
+                        <option key={n} value={String(n)}>
+                          Input {n}
+                        </option>
+                      ))}
+                    </select>
+                    <p style={helpTextStyle}>
+                      Pick one channel on multi-channel interfaces or when a
+                      stereo mic sounds thin (averaging cancels out-of-phase channels).
+                    </p>
                     {nativeDevicesError && (
                       <p style={{ ...helpTextStyle, color: "var(--error)" }}>
                         Failed to load native devices: {nativeDevicesError}
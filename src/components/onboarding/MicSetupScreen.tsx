@@ -8,6 +8,8 @@ import {
   loadSmartVersesSettings,
   saveSmartVersesSettings,
 } from "../../services/transcriptionService";
+import { startNativeAudioStream } from "../../services/nativeAudioStream";
+import { listenNativeAudioLevel } from "../../services/recorderService";
 import { mapAudioLevel } from "../../utils/audioMeter";
 import "./onboarding.css";
 
@@ -19,13 +21,6 @@ type NativeAudioInputDevice = {
 
 const AUDIO_ACTIVE_THRESHOLD = 0.04;
 
-const base64ToUint8Array = (b64: string): Uint8Array => {
-  const bin = atob(b64);
-  const bytes = new Uint8Array(bin.length);
-  for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
-  return bytes;
-};
-
 const computeRmsFromInt16 = (samples: Int16Array): number => {
   if (samples.length === 0) return 0;
   let sumSquares = 0;
@@ -61,9 +56,13 @@ const MicSetupScreen: React.FC<MicSetupScreenProps> = ({
   const [audioLevel, setAudioLevel] = useState(0);
   const [audioActive, setAudioActive] = useState(false);
   const [audioDetected, setAudioDetected] = useState(false);
+  const [peakDb, setPeakDb] = useState<number | null>(null);
+  const [clipping, setClipping] = useState(false);
 
   const audioLevelRef = useRef(0);
   const nativeUnlistenRef = useRef<null | (() => void)>(null);
+  const levelUnlistenRef = useRef<null | (() => void)>(null);
+  const clippingTimeoutRef = useRef<NodeJS.Timeout | null>(null);
   const activityTimeoutRef = useRef<NodeJS.Timeout | null>(null);
   const testTimerRef = useRef<NodeJS.Timeout | null>(null);
 
@@ -136,6 +135,8 @@ const MicSetupScreen: React.FC<MicSetupScreenProps> = ({
       setAudioActive(false);
       setAudioDetected(false);
       setAudioLevel(0);
+      setPeakDb(null);
+      setClipping(false);
       audioLevelRef.current = 0;
 
       if (activityTimeoutRef.current) {
@@ -143,26 +144,30 @@ const MicSetupScreen: React.FC<MicSetupScreenProps> = ({
         activityTimeoutRef.current = null;
       }
 
-      const events = await import("@tauri-apps/api/event");
-      const core = await import("@tauri-apps/api/core");
-
       if (nativeUnlistenRef.current) {
         nativeUnlistenRef.current();
         nativeUnlistenRef.current = null;
       }
 
-      nativeUnlistenRef.current = await events.listen<{ data_b64: string }>(
-        "native_audio_chunk",
-        (evt) => {
-          const bytes = base64ToUint8Array(evt.payload.data_b64);
-          if (bytes.byteLength < 2) return;
-
-          const sampleCount = Math.floor(bytes.byteLength / 2);
-          const int16 = new Int16Array(
-            bytes.buffer,
-            bytes.byteOffset,
-            sampleCount
-          );
+      // Peak and clipping come from the native meter; the bar below is still
+      // driven by the PCM chunks so it keeps moving between meter blocks.
+      levelUnlistenRef.current?.();
+      levelUnlistenRef.current = await listenNativeAudioLevel((level) => {
+        setPeakDb(level.peak_db);
+        if (level.clipping) {
+          setClipping(true);
+          if (clippingTimeoutRef.current) {
+            clearTimeout(clippingTimeoutRef.current);
+          }
+          clippingTimeoutRef.current = setTimeout(() => {
+            setClipping(false);
+          }, 2000);
+        }
+      });
+
+      const stream = await startNativeAudioStream(
+        { deviceId: selectedMicId },
+        (int16) => {
           const rms = computeRmsFromInt16(int16);
           const nextLevel = audioLevelRef.current * 0.65 + rms * 0.35;
           audioLevelRef.current = nextLevel;
@@ -178,12 +183,14 @@ const MicSetupScreen: React.FC<MicSetupScreenProps> = ({
               setAudioActive(false);
             }, 1200);
           }
+        },
+        (message) => {
+          console.error("Microphone test stream failed:", message);
+          setError(`Microphone capture failed: ${message}`);
+          stopMicTest();
         }
       );
-
-      await core.invoke("start_native_audio_stream", {
-        deviceId: selectedMicId,
-      });
+      nativeUnlistenRef.current = stream.detach;
       
       // Auto-stop after 10 seconds
       testTimerRef.current = setTimeout(() => {
@@ -209,11 +216,21 @@ const MicSetupScreen: React.FC<MicSetupScreenProps> = ({
       activityTimeoutRef.current = null;
     }
 
+    if (clippingTimeoutRef.current) {
+      clearTimeout(clippingTimeoutRef.current);
+      clippingTimeoutRef.current = null;
+    }
+
     if (nativeUnlistenRef.current) {
       nativeUnlistenRef.current();
       nativeUnlistenRef.current = null;
     }
 
+    if (levelUnlistenRef.current) {
+      levelUnlistenRef.current();
+      levelUnlistenRef.current = null;
+    }
+
     void import("@tauri-apps/api/core").then((core) => {
       return core.invoke("stop_native_audio_stream");
     });
@@ -347,6 +364,19 @@ const MicSetupScreen: React.FC<MicSetupScreenProps> = ({
                     transition: "width 80ms linear, background-color 150ms ease",
                   }} />
                 </div>
+
+                {peakDb !== null && (
+                  <p style={{
+                    marginTop: "0.5rem",
+                    textAlign: "center",
+                    fontSize: "0.8rem",
+                    color: clipping ? "rgb(220, 38, 38)" : "var(--onboarding-text-secondary)",
+                  }}>
+                    {clipping
+                      ? "Input is clipping - lower the microphone gain"
+                      : `Peak ${peakDb.toFixed(1)} dBFS`}
+                  </p>
+                )}
                 
                 {/* Success indicator */}
                 {audioDetected && (
@@ -22,6 +22,7 @@ import {
   saveSmartVersesSettings,
 } from "../../services/transcriptionService";
 import {
+  getMonitorFingerprint,
   loadDisplaySettings,
   saveDisplaySettings,
 } from "../../services/displayService";
@@ -172,7 +173,9 @@ const OnboardingWizard: React.FC<OnboardingWizardProps> = ({
 
   const handleMonitorChange = (index: number | null) => {
     updateState({ selectedMonitorIndex: index });
-    syncDisplaySettings({ monitorIndex: index });
+    void getMonitorFingerprint(index).then((monitor) =>
+      syncDisplaySettings({ monitorIndex: index, monitor })
+    );
   };
 
   useEffect(() => {
@@ -288,6 +288,9 @@ const TestSmartVersesScreen: React.FC<TestSmartVersesScreenProps> = ({
         service.setNativeMicrophoneDeviceId?.(
           settings.selectedNativeMicrophoneId || null
         );
+        service.setNativeMicrophoneChannel?.(
+          settings.nativeMicrophoneChannel || null
+        );
       }
       if (settings.selectedMicrophoneId) {
         service.setMicrophone(settings.selectedMicrophoneId);
@@ -2,6 +2,14 @@ import React, { createContext, useCallback, useContext, useEffect, useMemo, useR
 import { ScheduleItem, TimerState, TimeAdjustmentMode, ScheduleItemAutomation, RECORDING_AUTOMATION_TYPES } from "../types/propresenter";
 import { startTimerOnAllEnabled, stopTimerOnAllEnabled } from "../services/propresenterService";
 import { saveDisplayTimerState } from "../services/displayService";
+import {
+  getServerTimerState,
+  listenTimerUpdate,
+  setScheduleAutoAdvance,
+  startServerTimer,
+  StartServerTimerOptions,
+  stopServerTimer,
+} from "../services/scheduleService";
 import { loadNetworkSyncSettings, networkSyncManager } from "../services/networkSyncService";
 import {
   applySmartAutomationsToSchedule,
@@ -119,6 +127,7 @@ export interface StageAssistSettings {
   isAutoPlay: boolean;
   isAllowOverrun: boolean;
   triggerOnce: boolean; // When true, each timer can only be triggered once per session until reset
+  autoAdvance: boolean; // Server moves to each session at its start time and starts its countdown
 }
 
 const defaultSchedule: ScheduleItem[] = [
@@ -152,6 +161,7 @@ const defaultSettings: StageAssistSettings = {
   isAutoPlay: false,
   isAllowOverrun: false,
   triggerOnce: true, // Default: timers can only be triggered once per schedule item
+  autoAdvance: false,
 };
 
 export function formatStageAssistTime(seconds: number) {
@@ -271,23 +281,41 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
     // Mark settings as loaded after initial load
     setSettingsLoaded(true);
 
-    // Restore runtime timer state so it survives navigation (and even app reload)
-    const savedRuntime = localStorage.getItem(RUNTIME_STORAGE_KEY);
-    if (savedRuntime) {
+    // Restore runtime state so it survives navigation (and even app reload).
+    // The server timer keeps running across webview reloads; after an app
+    // restart a countdown that was still running is restarted on the server.
+    let savedRuntime: { timerState: TimerState; savedAt: number; currentSessionIndex: number | null } | null = null;
+    try {
+      const raw = localStorage.getItem(RUNTIME_STORAGE_KEY);
+      savedRuntime = raw ? JSON.parse(raw) : null;
+      setCurrentSessionIndex(savedRuntime?.currentSessionIndex ?? null);
+    } catch {
+      // ignore
+    }
+    (async () => {
       try {
-        const parsed = JSON.parse(savedRuntime) as { timerState: TimerState; savedAt: number; currentSessionIndex: number | null };
-        const elapsed = Math.floor((Date.now() - parsed.savedAt) / 1000);
-        const restoredTimeLeft = parsed.timerState.isRunning ? parsed.timerState.timeLeft - elapsed : parsed.timerState.timeLeft;
-        setTimerState({
-          ...parsed.timerState,
-          timeLeft: restoredTimeLeft,
-          isOverrun: restoredTimeLeft < 0,
-        });
-        setCurrentSessionIndex(parsed.currentSessionIndex ?? null);
+        const serverState = await getServerTimerState();
+        if (serverState.isRunning || serverState.timeLeft !== 0) {
+          setTimerState(serverState);
+          return;
+        }
       } catch {
-        // ignore
+        // server timer unavailable; fall back to the saved state
       }
-    }
+      if (!savedRuntime) return;
+      const saved = savedRuntime.timerState;
+      const elapsed = Math.floor((Date.now() - savedRuntime.savedAt) / 1000);
+      const restoredTimeLeft = saved.isRunning ? saved.timeLeft - elapsed : saved.timeLeft;
+      if (saved.isRunning && restoredTimeLeft > 0) {
+        startServerTimer({
+          seconds: restoredTimeLeft,
+          sessionName: saved.sessionName,
+          endTime: saved.endTime,
+        }).catch((error) => console.warn("Failed to restore timer:", error));
+      } else {
+        setTimerState({ ...saved, isRunning: false, timeLeft: restoredTimeLeft, isOverrun: restoredTimeLeft < 0 });
+      }
+    })();
   }, []);
 
   // Persist schedule/settings
@@ -337,23 +365,33 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
   // Track the last session index we triggered automations for (to avoid duplicate triggers)
   const lastTriggeredSessionIndexRef = useRef<number | null>(null);
 
+  // The server timer engine owns the countdown; the UI renders its
+  // `timer-update` events. Resolves with any error as a message list.
+  const startEngineTimer = useCallback(async (options: StartServerTimerOptions) => {
+    try {
+      setTimerState(await startServerTimer(options));
+      return [];
+    } catch (error) {
+      console.error("Failed to start server timer:", error);
+      return [`Timer failed to start: ${error}`];
+    }
+  }, []);
+
+  const sessionTimerOptions = useCallback(
+    (session: ScheduleItem): StartServerTimerOptions => {
+      const duration = settings.useDurations ? parseDurationToSeconds(session.duration) : undefined;
+      // Without a duration the engine counts down to the session's end time.
+      return { seconds: duration || undefined, endTime: session.endTime, sessionName: session.session };
+    },
+    [settings.useDurations]
+  );
+
   const startSessionLocally = useCallback(
     (index: number, sessionSchedule: ScheduleItem[]) => {
       const session = sessionSchedule[index];
       if (!session) return;
 
-      const duration = settings.useDurations ? parseDurationToSeconds(session.duration) : undefined;
-      const end = new Date(`${new Date().toDateString()} ${session.endTime}`);
-      const now = new Date();
-      const timeLeft = duration || Math.floor((end.getTime() - now.getTime()) / 1000);
-
-      setTimerState({
-        isRunning: true,
-        timeLeft,
-        sessionName: session.session,
-        endTime: session.endTime,
-        isOverrun: false,
-      });
+      void startEngineTimer(sessionTimerOptions(session));
       setCurrentSessionIndex(index);
 
       // Still mark as triggered for UI/consistency if triggerOnce is enabled, but do not block follower updates.
@@ -361,11 +399,11 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
         setTriggeredSessions((prev) => new Set(prev).add(session.id));
       }
     },
-    [settings.triggerOnce, settings.useDurations]
+    [settings.triggerOnce, startEngineTimer, sessionTimerOptions]
   );
 
   const stopTimerLocally = useCallback(() => {
-    setTimerState((prev) => ({ ...prev, isRunning: false }));
+    stopServerTimer().catch((error) => console.error("Failed to stop server timer:", error));
   }, []);
 
   // Function to run local automations for a session (used by Follow Master Timer)
@@ -547,6 +585,37 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
     }
   }, [settings, settingsLoaded]);
 
+  // The server keeps auto-advance in memory; re-apply the saved setting and
+  // turn it off if the schedule's times can't be read.
+  useEffect(() => {
+    if (!settingsLoaded) return;
+    setScheduleAutoAdvance(settings.autoAdvance).catch((error) => {
+      console.warn("Failed to set schedule auto-advance:", error);
+      if (settings.autoAdvance) setSettings((s) => ({ ...s, autoAdvance: false }));
+    });
+  }, [settingsLoaded, settings.autoAdvance]);
+
+  // Follow auto-advance changes made through the REST API
+  useEffect(() => {
+    let unlisten: null | (() => void) = null;
+
+    (async () => {
+      try {
+        const events = await import("@tauri-apps/api/event");
+        unlisten = await events.listen<{ enabled: boolean }>("schedule-auto-advance-changed", (event) => {
+          const enabled = Boolean(event.payload?.enabled);
+          setSettings((s) => (s.autoAdvance === enabled ? s : { ...s, autoAdvance: enabled }));
+        });
+      } catch (error) {
+        console.warn("[API] Failed to listen for auto-advance changes:", error);
+      }
+    })();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, []);
+
   // Persist runtime timer state (for navigation + reload safety)
   useEffect(() => {
     localStorage.setItem(
@@ -555,61 +624,34 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
     );
   }, [timerState, currentSessionIndex]);
 
-  // Update timer state on server for remote viewing
-  useEffect(() => {
-    // Dynamically import to avoid issues if server is not running
-    import("../services/scheduleService")
-      .then(({ updateTimerState }) => {
-        updateTimerState(timerState);
-      })
-      .catch((error) => {
-        // Silently fail if server is not running
-        console.debug("Timer service not available:", error);
-      });
-  }, [timerState]);
-
   // Keep audience display timer in sync
   useEffect(() => {
     saveDisplayTimerState(timerState);
   }, [timerState]);
 
-  // Countdown ticking (lives at app level so it survives route changes)
-  const tickIntervalRef = useRef<number | null>(null);
+  // Render the server timer (started here, from the REST API or by schedule
+  // auto-advance) so every screen shows the same countdown.
   useEffect(() => {
-    if (!timerState.isRunning) {
-      if (tickIntervalRef.current) {
-        window.clearInterval(tickIntervalRef.current);
-        tickIntervalRef.current = null;
-      }
-      return;
-    }
+    let unlisten: null | (() => void) = null;
+    let cancelled = false;
 
-    if (tickIntervalRef.current) return;
-
-    tickIntervalRef.current = window.setInterval(() => {
-      setTimerState((prev) => {
-        if (!prev.isRunning) return prev;
-        const newTimeLeft = prev.timeLeft - 1;
-        return {
-          ...prev,
-          timeLeft: newTimeLeft,
-          isOverrun: newTimeLeft < 0,
-        };
-      });
-    }, 1000);
+    listenTimerUpdate((state) => setTimerState(state))
+      .then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+      .catch((error) => console.warn("Failed to listen for timer updates:", error));
 
     return () => {
-      if (tickIntervalRef.current) {
-        window.clearInterval(tickIntervalRef.current);
-        tickIntervalRef.current = null;
-      }
+      cancelled = true;
+      if (unlisten) unlisten();
     };
-  }, [timerState.isRunning]);
+  }, []);
 
   // Listen for AI-triggered timer stop events
   useEffect(() => {
     const handleAITimerStop = () => {
-      setTimerState((prev) => ({ ...prev, isRunning: false }));
+      stopServerTimer().catch((error) => console.error("Failed to stop server timer:", error));
     };
 
     window.addEventListener("ai-timer-stopped", handleAITimerStop);
@@ -632,8 +674,10 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
     };
   }, []);
 
-  const startSession = useCallback(
-    async (index: number) => {
+  // `engineStarted` is set when the server already started the session's
+  // countdown (REST advance, auto-advance), so only ProPresenter follows.
+  const runSession = useCallback(
+    async (index: number, engineStarted: boolean) => {
       const session = schedule[index];
       if (!session) return { success: 0, failed: 0, errors: [] };
 
@@ -643,17 +687,7 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
       }
 
       const duration = settings.useDurations ? parseDurationToSeconds(session.duration) : undefined;
-      const end = new Date(`${new Date().toDateString()} ${session.endTime}`);
-      const now = new Date();
-      const timeLeft = duration || Math.floor((end.getTime() - now.getTime()) / 1000);
-
-      setTimerState({
-        isRunning: true,
-        timeLeft,
-        sessionName: session.session,
-        endTime: session.endTime,
-        isOverrun: false,
-      });
+      const engineErrors = engineStarted ? [] : await startEngineTimer(sessionTimerOptions(session));
       setCurrentSessionIndex(index);
 
       // Mark session as triggered (if trigger once is enabled)
@@ -661,11 +695,14 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
         setTriggeredSessions((prev) => new Set(prev).add(session.id));
       }
 
-      return await startTimerOnAllEnabled(session.session, duration, settings.useDurations ? undefined : session.endTime);
+      const result = await startTimerOnAllEnabled(session.session, duration, settings.useDurations ? undefined : session.endTime);
+      return { ...result, errors: [...engineErrors, ...result.errors] };
     },
-    [schedule, settings.useDurations, settings.triggerOnce, triggeredSessions]
+    [schedule, settings.useDurations, settings.triggerOnce, triggeredSessions, startEngineTimer, sessionTimerOptions]
   );
 
+  const startSession = useCallback((index: number) => runSession(index, false), [runSession]);
+
   // Auto-play next session (runs at app level so it survives navigation)
   useEffect(() => {
     if (
@@ -699,18 +736,13 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
           parseInt(countdownSeconds);
       if (!computed || computed <= 0) return { success: 0, failed: 0, errors: [] };
 
-      setTimerState({
-        isRunning: true,
-        timeLeft: computed,
-        sessionName: "Countdown",
-        endTime: "",
-        isOverrun: false,
-      });
+      const engineErrors = await startEngineTimer({ seconds: computed, sessionName: "Countdown" });
       setCurrentSessionIndex(null);
 
-      return await startTimerOnAllEnabled("Countdown", computed);
+      const result = await startTimerOnAllEnabled("Countdown", computed);
+      return { ...result, errors: [...engineErrors, ...result.errors] };
     },
-    [countdownHours, countdownMinutes, countdownSeconds]
+    [countdownHours, countdownMinutes, countdownSeconds, startEngineTimer]
   );
 
   useEffect(() => {
@@ -749,32 +781,22 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
       const [hours, minutes] = finalTime.split(":").map(Number);
       if (Number.isNaN(hours) || Number.isNaN(minutes)) return { success: 0, failed: 0, errors: [] };
 
-      const now = new Date();
-      const target = new Date();
-      target.setHours(finalPeriod === "PM" && hours !== 12 ? hours + 12 : hours);
-      target.setMinutes(minutes);
-      target.setSeconds(0);
-      if (target < now) target.setDate(target.getDate() + 1);
-
-      const timeLeft = Math.floor((target.getTime() - now.getTime()) / 1000);
       const endTimeStr = `${finalTime} ${finalPeriod}`;
-
-      setTimerState({
-        isRunning: true,
-        timeLeft,
-        sessionName: "Countdown",
-        endTime: endTimeStr,
-        isOverrun: false,
-      });
+      const engineErrors = await startEngineTimer({ endTime: endTimeStr, sessionName: "Countdown" });
       setCurrentSessionIndex(null);
 
-      return await startTimerOnAllEnabled("Countdown", undefined, endTimeStr);
+      const result = await startTimerOnAllEnabled("Countdown", undefined, endTimeStr);
+      return { ...result, errors: [...engineErrors, ...result.errors] };
     },
-    [countdownToPeriod, countdownToTime]
+    [countdownToPeriod, countdownToTime, startEngineTimer]
   );
 
   const stopTimer = useCallback(async () => {
-    setTimerState((prev) => ({ ...prev, isRunning: false }));
+    try {
+      setTimerState(await stopServerTimer());
+    } catch (error) {
+      console.error("Failed to stop server timer:", error);
+    }
     return await stopTimerOnAllEnabled();
   }, []);
 
@@ -797,6 +819,74 @@ export const StageAssistProvider: React.FC<{ children: React.ReactNode }> = ({ c
     };
   }, [stopTimer]);
 
+  // Schedules replaced through the REST API (imports, edits) are adopted here
+  useEffect(() => {
+    let unlisten: null | (() => void) = null;
+
+    (async () => {
+      try {
+        const events = await import("@tauri-apps/api/event");
+        unlisten = await events.listen<{ schedule: any[]; currentSessionIndex: number | null }>(
+          "api-schedule-updated",
+          (event) => {
+            const incoming = event.payload?.schedule;
+            if (!Array.isArray(incoming)) return;
+            setSchedule(
+              normalizeSchedule(
+                incoming.map((item) => ({
+                  id: item.id,
+                  session: item.session,
+                  startTime: item.start_time,
+                  endTime: item.end_time,
+                  duration: item.duration,
+                  minister: item.minister ?? undefined,
+                }))
+              )
+            );
+            setCurrentSessionIndex(event.payload.currentSessionIndex ?? null);
+          }
+        );
+      } catch (error) {
+        console.warn("[API] Failed to listen for schedule updates:", error);
+      }
+    })();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, []);
+
+  // Session changes made by the backend (REST advance/goto, auto-advance at start times)
+  useEffect(() => {
+    let unlisten: null | (() => void) = null;
+
+    (async () => {
+      try {
+        const events = await import("@tauri-apps/api/event");
+        unlisten = await events.listen<{ currentSessionIndex: number | null; startTimer?: boolean }>(
+          "schedule-session-changed",
+          async (event) => {
+            const index = event.payload?.currentSessionIndex ?? null;
+            if (index !== null && event.payload?.startTimer) {
+              const result = await runSession(index, true);
+              if (result.errors?.length) {
+                console.warn("[API] Session start errors:", result.errors);
+              }
+            } else {
+              setCurrentSessionIndex(index);
+            }
+          }
+        );
+      } catch (error) {
+        console.warn("[API] Failed to listen for session changes:", error);
+      }
+    })();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [runSession]);
+
   // Mark a session as triggered
   const markSessionTriggered = useCallback((sessionId: number) => {
     setTriggeredSessions((prev) => new Set(prev).add(sessionId));
@@ -7,8 +7,12 @@ import {
   DisplayScripture,
   DisplaySettings,
   DisplayLayoutRect,
+  DisplayMargins,
+  DisplayTransition,
+  DisplayOutputState,
   DisplaySlides,
   DisplayTimerState,
+  MAIN_DISPLAY_OUTPUT,
 } from "../types/display";
 import { formatStageAssistTime } from "../contexts/StageAssistContext";
 
@@ -52,19 +56,33 @@ const resolveLineFontFamily = (
 import {
   loadDisplaySettings,
   closeDisplayWindow,
+  getDisplayOutputState,
   loadDisplayScripture,
   loadDisplaySlides,
   loadDisplayTimerState,
+  normalizeDisplayOutputState,
+  RawDisplayState,
 } from "../services/displayService";
 import "../App.css";
 
-const rectStyle = (rect: DisplayLayoutRect): React.CSSProperties => ({
-  position: "absolute",
-  left: `${rect.x * 100}%`,
-  top: `${rect.y * 100}%`,
-  width: `${rect.width * 100}%`,
-  height: `${rect.height * 100}%`,
-});
+// Boxes are laid out inside the margins, which are fractions of the screen.
+const rectStyle = (rect: DisplayLayoutRect, margins?: DisplayMargins): React.CSSProperties => {
+  const m = margins ?? { top: 0, right: 0, bottom: 0, left: 0 };
+  const scaleX = 1 - m.left - m.right;
+  const scaleY = 1 - m.top - m.bottom;
+  return {
+    position: "absolute",
+    left: `${(m.left + rect.x * scaleX) * 100}%`,
+    top: `${(m.top + rect.y * scaleY) * 100}%`,
+    width: `${rect.width * scaleX * 100}%`,
+    height: `${rect.height * scaleY * 100}%`,
+  };
+};
+
+const transitionStyle = (transition?: DisplayTransition): React.CSSProperties =>
+  transition?.kind === "fade" && transition.durationMs > 0
+    ? { animation: `display-fade-in ${transition.durationMs}ms ease` }
+    : {};
 
 const AudienceDisplayPage: React.FC = () => {
   const [windowLabel] = useState<string>(() => {
@@ -78,14 +96,23 @@ const AudienceDisplayPage: React.FC = () => {
   const dialogWindowName = isDialogWindow
     ? windowLabel.replace(/^dialog-/, "") || "audience-test"
     : "audience-test";
+  // Windows for other outputs are opened as /audience-display?output=<id> and
+  // follow that output's state on the server instead of the display:* events.
+  const [displayOutput] = useState<string>(
+    // Lowercased like the backend and display.html, so ?output=Stage matches "stage".
+    () =>
+      new URLSearchParams(window.location.search).get("output")?.trim().toLowerCase() ||
+      MAIN_DISPLAY_OUTPUT
+  );
+  const isMainOutput = displayOutput === MAIN_DISPLAY_OUTPUT;
   const [settings, setSettings] = useState<DisplaySettings>(() =>
     loadDisplaySettings()
   );
   const [scripture, setScripture] = useState<DisplayScripture>(() =>
-    loadDisplayScripture()
+    isMainOutput ? loadDisplayScripture() : { verseText: "", reference: "" }
   );
   const [slides, setSlides] = useState<DisplaySlides>(() =>
-    loadDisplaySlides()
+    isMainOutput ? loadDisplaySlides() : { lines: [] }
   );
   const [timerState, setTimerState] = useState<DisplayTimerState>(() =>
     loadDisplayTimerState()
@@ -324,7 +351,41 @@ const AudienceDisplayPage: React.FC = () => {
   ];
 
   useEffect(() => {
+    const applyOutputState = (state: DisplayOutputState) => {
+      setScripture(state.scripture);
+      setSlides(state.slides);
+      setSettings(state.settings);
+    };
+
     const setupListeners = async () => {
+      const unlistenTimer = await listen<DisplayTimerState>(
+        "display:timer",
+        (event) => {
+          setTimerState(event.payload);
+        }
+      );
+
+      if (!isMainOutput) {
+        const unlistenOutput = await listen<{ output: string; state: RawDisplayState }>(
+          "display-output-updated",
+          (event) => {
+            if (event.payload.output !== displayOutput) return;
+            applyOutputState(normalizeDisplayOutputState(event.payload.state));
+          }
+        );
+        getDisplayOutputState(displayOutput)
+          .then(applyOutputState)
+          .catch((error) => {
+            console.warn(`[Display] Output "${displayOutput}" has no content yet:`, error);
+            setScripture({ verseText: "", reference: "" });
+            setSlides({ lines: [] });
+          });
+        return () => {
+          unlistenTimer();
+          unlistenOutput();
+        };
+      }
+
       const unlistenScripture = await listen<DisplayScripture>(
         "display:scripture",
         (event) => {
@@ -337,12 +398,6 @@ const AudienceDisplayPage: React.FC = () => {
           setSlides(event.payload);
         }
       );
-      const unlistenTimer = await listen<DisplayTimerState>(
-        "display:timer",
-        (event) => {
-          setTimerState(event.payload);
-        }
-      );
       const unlistenSettings = await listen<DisplaySettings>(
         "display:settings",
         (event) => {
@@ -370,7 +425,7 @@ const AudienceDisplayPage: React.FC = () => {
     return () => {
       cleanup?.();
     };
-  }, []);
+  }, [displayOutput, isMainOutput]);
 
   // Handle mouse movement to show close button after ~2 seconds of movement
   useEffect(() => {
@@ -444,6 +499,12 @@ const AudienceDisplayPage: React.FC = () => {
 
   const handleClose = async () => {
     try {
+      if (!isMainOutput) {
+        // Other outputs don't have a checkbox in the display settings to update.
+        await getCurrentWindow().close();
+        return;
+      }
+
       // Emit event to notify settings that the display was closed by user
       // This allows the checkbox to be unchecked
       await emit("display:window-closed", {
@@ -496,7 +557,7 @@ const AudienceDisplayPage: React.FC = () => {
               key={`slide-line-${index}`}
               ref={slideLineBoxRefs[index]}
               style={{
-                ...rectStyle(rect),
+                ...rectStyle(rect, settings.margins),
                 display: "flex",
                 alignItems: "center",
                 justifyContent: "center",
@@ -505,8 +566,10 @@ const AudienceDisplayPage: React.FC = () => {
               }}
             >
               <div
+                key={content}
                 ref={slideLineContentRefs[index]}
                 style={{
+                  ...transitionStyle(settings.transition),
                   fontFamily: resolveLineFontFamily(
                     settings.textFont,
                     settings.slidesLineStyles?.[index]
@@ -534,7 +597,7 @@ const AudienceDisplayPage: React.FC = () => {
         <div
           ref={textBoxRef}
           style={{
-            ...rectStyle(settings.layout.text),
+            ...rectStyle(settings.layout.text, settings.margins),
             display: "flex",
             alignItems: "center",
             justifyContent: "center",
@@ -543,10 +606,12 @@ const AudienceDisplayPage: React.FC = () => {
           }}
         >
           <div
+            key={scripture.verseText}
             ref={textContentRef}
             style={{
+              ...transitionStyle(settings.transition),
               fontFamily: settings.textFont,
-              fontSize: `${textFontSize}px`,
+              fontSize: `${settings.textFontSize ?? textFontSize}px`,
               lineHeight: 1.2,
               whiteSpace: "pre-wrap",
               wordBreak: "break-word",
@@ -563,7 +628,7 @@ const AudienceDisplayPage: React.FC = () => {
         <div
           ref={referenceBoxRef}
           style={{
-            ...rectStyle(settings.layout.reference),
+            ...rectStyle(settings.layout.reference, settings.margins),
             display: "flex",
             alignItems: "center",
             justifyContent: "center",
@@ -572,10 +637,12 @@ const AudienceDisplayPage: React.FC = () => {
           }}
         >
           <div
+            key={displayReference}
             ref={referenceContentRef}
             style={{
+              ...transitionStyle(settings.transition),
               fontFamily: settings.referenceFont,
-              fontSize: `${referenceFontSize}px`,
+              fontSize: `${settings.referenceFontSize ?? referenceFontSize}px`,
               lineHeight: 1.1,
               whiteSpace: "pre-wrap",
               wordBreak: "break-word",
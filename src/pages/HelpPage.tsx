@@ -952,7 +952,7 @@ const HelpPage: React.FC = () => {
               <strong>Copy to Clipboard:</strong> Copy all slides in an item to clipboard
             </li>
             <li>
-              <strong>Rename:</strong> Rename playlists and items using edit button
+              <strong>Rename:</strong> Rename playlists and items using edit button; items also take a CCLI song number for usage reports
             </li>
             <li>
               <strong>Take Off:</strong> Right-click live slide → <strong>Take Off</strong> to trigger ProPresenter take-off clicks
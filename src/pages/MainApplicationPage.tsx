@@ -36,10 +36,13 @@ import {
 } from "react-icons/fa";
 import "../App.css"; // Ensure global styles are applied
 import { invoke } from "@tauri-apps/api/core"; // Tauri v2 core invoke
+import { listen } from "@tauri-apps/api/event";
 import { formatSlidesForClipboard } from "../utils/slideUtils"; // Added import
 import TranscriptOptionsMenu from "../components/transcription/TranscriptOptionsMenu";
 import { saveTranscriptFile } from "../utils/transcriptDownload";
 import {
+  controlLiveSlide,
+  getLiveSlideCursor,
   getLiveSlidesServerInfo,
   LiveSlidesWebSocket,
   loadLiveSlidesSettings,
@@ -51,6 +54,7 @@ import {
 } from "../services/liveSlideService";
 import {
   LiveSlide,
+  LiveSlideCursor,
   LiveSlidesProPresenterActivationRule,
   WsTranscriptionStream,
 } from "../types/liveSlides";
@@ -114,6 +118,9 @@ const MainApplicationPage: React.FC = () => {
   const [isCreateBlankOpen, setIsCreateBlankOpen] = useState(false);
   const [isRenameOpen, setIsRenameOpen] = useState(false);
   const [renameInitialName, setRenameInitialName] = useState("");
+  const [renameInitialCcliNumber, setRenameInitialCcliNumber] = useState<
+    string | undefined
+  >(undefined);
   const [renameTarget, setRenameTarget] = useState<
     | { type: "playlist"; id: string }
     | { type: "item"; playlistId: string; id: string }
@@ -319,6 +326,12 @@ const MainApplicationPage: React.FC = () => {
     {}
   );
 
+  // Last cursor index seen per session (server cursor or our own go-live), so
+  // the echo of our own control_live_slide call is not applied twice.
+  const liveSlideCursorBySessionRef = useRef<Record<string, number | null>>({});
+  // Slide the server cursor points at in the current item; null when cleared.
+  const [cursorLiveSlideId, setCursorLiveSlideId] = useState<string | null | undefined>(undefined);
+
   // Keep refs in sync with state
   useEffect(() => {
     liveSlidesLockBySessionRef.current = liveSlidesLockBySession;
@@ -603,6 +616,7 @@ const MainApplicationPage: React.FC = () => {
     if (!currentPlaylist) return;
     setRenameTarget({ type: "playlist", id: currentPlaylist.id });
     setRenameInitialName(currentPlaylist.name);
+    setRenameInitialCcliNumber(undefined);
     setIsRenameOpen(true);
   };
 
@@ -614,10 +628,11 @@ const MainApplicationPage: React.FC = () => {
       id: currentPlaylistItem.id,
     });
     setRenameInitialName(currentPlaylistItem.title);
+    setRenameInitialCcliNumber(currentPlaylistItem.ccliNumber ?? "");
     setIsRenameOpen(true);
   };
 
-  const handleRename = (newName: string) => {
+  const handleRename = (newName: string, ccliNumber?: string) => {
     if (!renameTarget) return;
     if (renameTarget.type === "playlist") {
       setPlaylists((prev) =>
@@ -632,7 +647,9 @@ const MainApplicationPage: React.FC = () => {
           return {
             ...p,
             items: p.items.map((it) =>
-              it.id === renameTarget.id ? { ...it, title: newName } : it
+              it.id === renameTarget.id
+                ? { ...it, title: newName, ccliNumber: ccliNumber || undefined }
+                : it
             ),
           };
         })
@@ -833,9 +850,11 @@ const MainApplicationPage: React.FC = () => {
   };
 
   // Function to make a slide live
+  // `fromCursor` is set when following the server cursor, which is already there.
   const handleMakeSlideLive = async (
     slide: Slide,
-    playlistItem: PlaylistItem | undefined
+    playlistItem: PlaylistItem | undefined,
+    fromCursor = false
   ) => {
     if (!playlistItem) {
       console.error("Cannot make slide live: playlist item data is missing.");
@@ -894,6 +913,15 @@ const MainApplicationPage: React.FC = () => {
       } catch (_) {
         // Server may not be running or no clients; ignore.
       }
+      liveSlideCursorBySessionRef.current[sid] = slide.order - 1;
+      if (!fromCursor) {
+        try {
+          await controlLiveSlide(sid, "goto", slide.order - 1);
+        } catch (error) {
+          // Server not running or session not shared yet; the local go-live still applies.
+          console.warn("[LiveSlides] Failed to move live cursor:", error);
+        }
+      }
     }
 
     const liveSlidesSettings = isLiveSlidesItem
@@ -1285,7 +1313,8 @@ const MainApplicationPage: React.FC = () => {
   // Function to handle "Take Off" action
   const handleTakeOffSlide = async (
     slide: Slide,
-    playlistItem: PlaylistItem | undefined
+    playlistItem: PlaylistItem | undefined,
+    fromCursor = false
   ) => {
     if (!playlistItem) {
       console.error("Cannot take off slide: playlist item data is missing.");
@@ -1413,9 +1442,69 @@ const MainApplicationPage: React.FC = () => {
       } catch (_) {
         // Server may not be running; ignore.
       }
+      liveSlideCursorBySessionRef.current[sid] = null;
+      if (!fromCursor) {
+        try {
+          await controlLiveSlide(sid, "clear");
+        } catch (error) {
+          console.warn("[LiveSlides] Failed to clear live cursor:", error);
+        }
+      }
     }
   };
 
+  // Follow the server's live cursor, which remote controllers (REST, /ws) move.
+  const liveSlideChangedRef = useRef<(cursor: LiveSlideCursor) => void>(() => {});
+  liveSlideChangedRef.current = (cursor: LiveSlideCursor) => {
+    const item = currentPlaylistItem;
+    if (!item?.liveSlidesSessionId || item.liveSlidesSessionId !== cursor.session_id) return;
+    const slides = item.slides;
+    const liveSlide = cursor.index === null ? null : slides.find((s) => s.order === cursor.index! + 1);
+    setCursorLiveSlideId(liveSlide?.id ?? null);
+
+    const previous = liveSlideCursorBySessionRef.current[cursor.session_id] ?? null;
+    if (previous === cursor.index) return;
+    if (liveSlide) {
+      void handleMakeSlideLive(liveSlide, item, true);
+    } else if (previous !== null) {
+      const current = slides.find((s) => s.order === previous + 1);
+      if (current) void handleTakeOffSlide(current, item, true);
+    }
+    liveSlideCursorBySessionRef.current[cursor.session_id] = cursor.index;
+  };
+
+  useEffect(() => {
+    const unlistenPromise = listen<LiveSlideCursor>("live-slide-changed", (event) => {
+      liveSlideChangedRef.current(event.payload);
+    });
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten()).catch(console.warn);
+    };
+  }, []);
+
+  // Show where the server cursor is when switching to a Live Slides item.
+  const currentLiveSessionId = currentPlaylistItem?.liveSlidesSessionId;
+  useEffect(() => {
+    setCursorLiveSlideId(undefined);
+    if (!currentLiveSessionId) return;
+    let cancelled = false;
+    getLiveSlideCursor(currentLiveSessionId)
+      .then((cursor) => {
+        if (cancelled) return;
+        liveSlideCursorBySessionRef.current[cursor.session_id] = cursor.index;
+        const slide = cursor.index === null
+          ? null
+          : currentPlaylistItem?.slides.find((s) => s.order === cursor.index! + 1);
+        setCursorLiveSlideId(slide?.id ?? null);
+      })
+      .catch(() => {
+        // Session not on the server (yet); nothing to follow.
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [currentLiveSessionId]);
+
   const handleAddTranscriptionSlide = useCallback(
     (text: string) => {
       if (!selectedPlaylistId || !selectedItemId) {
@@ -1800,7 +1889,11 @@ const MainApplicationPage: React.FC = () => {
     itemName: string,
     templateName: string,
     slidesFromModal: Pick<Slide, "text" | "layout" | "isAutoScripture">[],
-    options?: { liveSlidesSessionId?: string; liveSlidesLinked?: boolean }
+    options?: {
+      liveSlidesSessionId?: string;
+      liveSlidesLinked?: boolean;
+      ccliNumber?: string;
+    }
   ): boolean => {
     if (!selectedPlaylistId) {
       alert("No playlist selected to add the imported item to.");
@@ -1836,6 +1929,7 @@ const MainApplicationPage: React.FC = () => {
       liveSlidesLinked: options?.liveSlidesSessionId
         ? options?.liveSlidesLinked ?? true
         : undefined,
+      ccliNumber: options?.ccliNumber,
       // Copy ProPresenter activation settings from template
       defaultProPresenterActivation: selectedTemplate?.proPresenterActivation,
     };
@@ -2899,6 +2993,7 @@ const MainApplicationPage: React.FC = () => {
         >
           <SlideDisplayArea
             playlistItem={currentPlaylistItem}
+            cursorLiveSlideId={cursorLiveSlideId}
             template={templates.find(
               (t) => t.name === currentPlaylistItem?.templateName
             )}
@@ -3544,8 +3639,9 @@ const MainApplicationPage: React.FC = () => {
         onClose={() => setIsRenameOpen(false)}
         onRename={handleRename}
         currentName={renameInitialName}
+        currentCcliNumber={renameInitialCcliNumber}
         title={
-          renameTarget?.type === "item" ? "Rename Item" : "Rename Playlist"
+          renameTarget?.type === "item" ? "Edit Item" : "Rename Playlist"
         }
       />
       <RenameModal
@@ -50,6 +50,7 @@ import {
   generateFinalMp3Path,
   base64ToBlob,
 } from "../services/recorderService";
+import { listenNativeAudioError } from "../services/nativeAudioStream";
 import {
   RecorderSettings,
   MediaDeviceOption,
@@ -319,6 +320,7 @@ const RecorderPage: React.FC = () => {
   const [audioRecordedPath, setAudioRecordedPath] = useState<string | null>(null);
   const [audioRecordedUrl, setAudioRecordedUrl] = useState<string | null>(null);
   const [audioLevels, setAudioLevels] = useState<number[]>(new Array(60).fill(0.1));
+  const [audioError, setAudioError] = useState<string | null>(null);
   const [nativeAudioDevices, setNativeAudioDevices] = useState<NativeAudioDevice[]>([]);
 
   // Toggle states for video/mic enable
@@ -1009,6 +1011,7 @@ const RecorderPage: React.FC = () => {
       setIsAudioStarting(true);
       audioRecordingModeRef.current = "native";
       setAudioRecordedPath(null);
+      setAudioError(null);
 
       const filePath = generateNativeAudioFilePath(settings, currentSession?.session);
       console.log("[NativeAudioRecording] Starting native recording...");
@@ -1055,6 +1058,7 @@ const RecorderPage: React.FC = () => {
       setIsAudioStarting(true);
       audioRecordingModeRef.current = "web";
       setAudioRecordedPath(null);
+      setAudioError(null);
 
       // For web audio recording, the selectedAudioDeviceId might be a native device ID
       // which doesn't work with browser getUserMedia. Try with the ID first,
@@ -1399,6 +1403,31 @@ const RecorderPage: React.FC = () => {
   );
 
 
+  // A native recording whose device fails (e.g. unplugged) is stopped so the
+  // WAV written so far is finalized, and the operator is told why.
+  const stopAudioRecordingCoreRef = useRef(stopAudioRecordingCore);
+  useEffect(() => {
+    stopAudioRecordingCoreRef.current = stopAudioRecordingCore;
+  }, [stopAudioRecordingCore]);
+
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    let cancelled = false;
+    void listenNativeAudioError((error) => {
+      if (error.source !== "recording" || audioRecordingModeRef.current !== "native") return;
+      console.error("[NativeAudioRecording] Stream error:", error.message);
+      setAudioError(`Microphone capture failed: ${error.message}`);
+      void stopAudioRecordingCoreRef.current();
+    }).then((fn) => {
+      if (cancelled) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, []);
+
   // ============================================================================
   // Automation Event Handlers (for Follow Master Timer feature)
   // ============================================================================
@@ -1846,6 +1875,21 @@ const RecorderPage: React.FC = () => {
               </select>
             </div>
 
+            {audioError && (
+              <div style={{
+                textAlign: "center",
+                padding: "8px",
+                marginBottom: "8px",
+                backgroundColor: "rgba(220, 38, 38, 0.15)",
+                borderRadius: "8px",
+                color: "#ef4444",
+                fontSize: "0.9rem",
+                fontWeight: 500,
+              }}>
+                {audioError}
+              </div>
+            )}
+
             {/* Audio Visualizer or Preview */}
             {audioRecordedUrl && audioStatus === "stopped" ? (
               <div style={styles.audioVisualizer}>
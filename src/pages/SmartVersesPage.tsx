@@ -2683,6 +2683,7 @@ const SmartVersesPage: React.FC = () => {
       service.setAudioCaptureMode?.(settings.audioCaptureMode === "native" ? "native" : "webrtc");
       if (settings.audioCaptureMode === "native") {
         service.setNativeMicrophoneDeviceId?.(settings.selectedNativeMicrophoneId || null);
+        service.setNativeMicrophoneChannel?.(settings.nativeMicrophoneChannel || null);
       }
 
       if (settings.selectedMicrophoneId) {
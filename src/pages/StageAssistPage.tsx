@@ -33,6 +33,13 @@ import {
 } from "../contexts/StageAssistContext";
 import { getSyncStatus, loadNetworkSyncSettings, SyncStatus } from "../services/networkSyncService";
 import { loadLiveSlidesSettings } from "../services/liveSlideService";
+import { setScheduleAutoAdvance } from "../services/scheduleService";
+import {
+  clearStageMessage,
+  getStageMessage,
+  listenStageMessage,
+  StageMessage,
+} from "../services/stageMessageService";
 import {
   applySmartAutomationsToSchedule,
   mergeScheduleWithLocalAutomations,
@@ -118,6 +125,30 @@ const StageAssistPage: React.FC = () => {
     };
   }, []);
 
+  // Message currently shown to the speaker on stage clients
+  const [stageMessage, setStageMessage] = useState<StageMessage | null>(null);
+  useEffect(() => {
+    let unlisten: null | (() => void) = null;
+    let cancelled = false;
+
+    getStageMessage()
+      .then((message) => {
+        if (!cancelled) setStageMessage(message);
+      })
+      .catch(() => {});
+    listenStageMessage(setStageMessage)
+      .then((fn) => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+      .catch((error) => console.warn("Failed to listen for stage messages:", error));
+
+    return () => {
+      cancelled = true;
+      if (unlisten) unlisten();
+    };
+  }, []);
+
   // Remote Access Link Modal state
   const [showRemoteAccessModal, setShowRemoteAccessModal] = useState(false);
 
@@ -1354,6 +1385,36 @@ const StageAssistPage: React.FC = () => {
           <div style={{ fontSize: "0.875rem", opacity: 0.8 }}>
             {timerState.endTime ? `Until ${timerState.endTime}` : "Current"}
           </div>
+          {stageMessage && (
+            <div
+              style={{
+                marginTop: "var(--spacing-3)",
+                padding: "var(--spacing-2) var(--spacing-3)",
+                backgroundColor: "rgba(0, 0, 0, 0.25)",
+                borderRadius: "8px",
+                display: "flex",
+                alignItems: "center",
+                justifyContent: "space-between",
+                gap: "var(--spacing-2)",
+              }}
+              title={`Shown on stage (${stageMessage.priority} priority)`}
+            >
+              <span style={{ fontSize: "0.875rem", textAlign: "left" }}>
+                Stage message: {stageMessage.text}
+              </span>
+              <button
+                onClick={() => {
+                  clearStageMessage().catch((error) =>
+                    showToast(`Failed to clear stage message: ${error}`, "error")
+                  );
+                }}
+                className="secondary"
+                style={{ padding: "2px 10px", fontSize: "0.8rem" }}
+              >
+                Clear
+              </button>
+            </div>
+          )}
         </div>
       </div>
 
@@ -2354,6 +2415,31 @@ const StageAssistPage: React.FC = () => {
         >
           Allow Overrun: {settings.isAllowOverrun ? "ON" : "OFF"}
         </button>
+        <button
+          onClick={async () => {
+            const enabled = !settings.autoAdvance;
+            try {
+              await setScheduleAutoAdvance(enabled);
+              setSettings((s) => ({ ...s, autoAdvance: enabled }));
+            } catch (error) {
+              showToast(`Can't follow start times: ${error}`, "error");
+            }
+          }}
+          style={{
+            padding: "var(--spacing-2) var(--spacing-4)",
+            backgroundColor: settings.autoAdvance
+              ? "rgb(34, 197, 94)"
+              : "rgb(29, 78, 216)",
+            color: "white",
+            border: "none",
+            borderRadius: "8px",
+            cursor: "pointer",
+            fontWeight: 600,
+          }}
+          title="Move to each session at its start time and start its countdown"
+        >
+          Follow Start Times: {settings.autoAdvance ? "ON" : "OFF"}
+        </button>
         <div
           style={{
             display: "flex",
@@ -9,12 +9,19 @@ import {
   DISPLAY_SCRIPTURE_KEY,
   DISPLAY_SLIDES_KEY,
   DISPLAY_SETTINGS_KEY,
+  DISPLAY_SETTINGS_VERSION,
   DISPLAY_TIMER_KEY,
   DisplayScripture,
   DisplaySettings,
   DisplaySlides,
   DisplayTimerState,
   DisplayLayoutRect,
+  DisplayOutputState,
+  DisplayHistoryEntry,
+  CcliReport,
+  DisplayLayoutProfile,
+  LayoutProfileApplyResult,
+  MonitorFingerprint,
   SlideLineStyle,
 } from "../types/display";
 
@@ -99,6 +106,7 @@ export function loadDisplaySettings(): DisplaySettings {
       const settings: DisplaySettings = {
         ...DEFAULT_DISPLAY_SETTINGS,
         ...parsed,
+        version: DISPLAY_SETTINGS_VERSION,
         layout: mergeLayout(DEFAULT_DISPLAY_LAYOUT, parsed.layout),
         slidesLayout: mergeSlidesLayout(DEFAULT_SLIDES_LAYOUT, parsed.slidesLayout),
         slidesLineStyles: normalizeSlideLineStyles(parsed.slidesLineStyles),
@@ -136,6 +144,7 @@ export function loadDisplaySettings(): DisplaySettings {
       if (typeof parsed.timerFontSize !== "number" || Number.isNaN(parsed.timerFontSize)) {
         settings.timerFontSize = DEFAULT_DISPLAY_SETTINGS.timerFontSize;
       }
+      settings.timerFontSize = clampTimerFontSize(settings.timerFontSize);
 
       // Guard against invalid values from older/local data
       if (typeof parsed.enabled !== "boolean") {
@@ -147,6 +156,9 @@ export function loadDisplaySettings(): DisplaySettings {
       if (settings.monitorIndex === null) {
         settings.enabled = false;
       }
+      if (!parsed.monitor || typeof parsed.monitor !== "object") {
+        settings.monitor = null;
+      }
       
       return settings;
     }
@@ -156,6 +168,15 @@ export function loadDisplaySettings(): DisplaySettings {
   return DEFAULT_DISPLAY_SETTINGS;
 }
 
+// The backend rejects the whole display update outside this range.
+export const TIMER_FONT_SIZE_MIN = 8;
+export const TIMER_FONT_SIZE_MAX = 1000;
+
+export function clampTimerFontSize(size: number): number {
+  if (!Number.isFinite(size)) return DEFAULT_DISPLAY_SETTINGS.timerFontSize;
+  return Math.min(TIMER_FONT_SIZE_MAX, Math.max(TIMER_FONT_SIZE_MIN, size));
+}
+
 export function saveDisplaySettings(settings: DisplaySettings): void {
   try {
     localStorage.setItem(DISPLAY_SETTINGS_KEY, JSON.stringify(settings));
@@ -269,12 +290,7 @@ export function saveDisplayTimerState(payload: DisplayTimerState): void {
 }
 
 // Safe monitor info structure matching Rust SafeMonitorInfo
-interface SafeMonitorInfo {
-  name: string | null;
-  position: [number, number];
-  size: [number, number];
-  scale_factor: number;
-}
+type SafeMonitorInfo = MonitorFingerprint;
 
 // Convert SafeMonitorInfo to Monitor-like format for compatibility
 // Note: workArea is omitted as it's not available and was causing serialization errors
@@ -323,6 +339,43 @@ function sortMonitors(monitors: Monitor[]): Monitor[] {
   });
 }
 
+export function toMonitorFingerprint(monitor: Monitor): MonitorFingerprint {
+  return {
+    name: monitor.name,
+    position: [monitor.position.x, monitor.position.y],
+    size: [monitor.size.width, monitor.size.height],
+    scale_factor: monitor.scaleFactor,
+  };
+}
+
+// The fingerprint of the monitor at `index` in the sorted list, or null.
+export async function getMonitorFingerprint(index: number | null): Promise<MonitorFingerprint | null> {
+  if (index === null) return null;
+  const monitors = await getAvailableMonitors();
+  return monitors[index] ? toMonitorFingerprint(monitors[index]) : null;
+}
+
+// Where a saved monitor sits in the sorted list now. Mirrors the backend's
+// matching: the name must match when recorded, position only breaks ties.
+export function findMonitorIndex(monitors: Monitor[], wanted: MonitorFingerprint): number | null {
+  let bestIndex: number | null = null;
+  let bestScore = -1;
+  for (let index = 0; index < monitors.length; index++) {
+    const monitor = monitors[index];
+    const sameSize = monitor.size.width === wanted.size[0] && monitor.size.height === wanted.size[1];
+    if (wanted.name ? monitor.name !== wanted.name : !sameSize) continue;
+    const score =
+      (sameSize ? 4 : 0) +
+      (Math.abs(monitor.scaleFactor - wanted.scale_factor) < 0.01 ? 2 : 0) +
+      (monitor.position.x === wanted.position[0] && monitor.position.y === wanted.position[1] ? 1 : 0);
+    if (score > bestScore) {
+      bestIndex = index;
+      bestScore = score;
+    }
+  }
+  return bestIndex;
+}
+
 export async function getAvailableMonitors(): Promise<Monitor[]> {
   try {
     // Use our safe Rust command that handles optional workArea
@@ -368,7 +421,11 @@ export async function openDisplayWindow(settings: DisplaySettings): Promise<void
     
     // Call the Rust command to create or show the window
     // The parent_window parameter is automatically injected by Tauri (it's the window calling this command)
-    await invoke("open_audience_display_window", { monitorIndex: settings.monitorIndex });
+    // The saved monitor wins over the index, which shifts when monitors are rearranged.
+    await invoke("open_audience_display_window", {
+      monitorIndex: settings.monitorIndex,
+      monitor: settings.monitor ?? null,
+    });
     
     // Try to get the window reference for future operations
     try {
@@ -418,6 +475,127 @@ export async function closeDisplayWindow(): Promise<void> {
   }
 }
 
+// ============================================================================
+// Named display outputs (other than "main", which follows the functions above)
+// ============================================================================
+
+export type RawDisplayState = {
+  scripture: {
+    verse_text: string;
+    reference: string;
+    translation_short_name?: string | null;
+  };
+  slides: string[];
+  settings: Partial<DisplaySettings>;
+};
+
+export function normalizeDisplayOutputState(raw: RawDisplayState): DisplayOutputState {
+  return {
+    scripture: {
+      verseText: raw.scripture?.verse_text ?? "",
+      reference: raw.scripture?.reference ?? "",
+      translationShortName: raw.scripture?.translation_short_name ?? undefined,
+    },
+    slides: { lines: Array.isArray(raw.slides) ? raw.slides : [] },
+    settings: { ...DEFAULT_DISPLAY_SETTINGS, ...raw.settings },
+  };
+}
+
+export async function listDisplayOutputs(): Promise<string[]> {
+  return invoke<string[]>("list_display_outputs");
+}
+
+export async function getDisplayOutputState(output: string): Promise<DisplayOutputState> {
+  const raw = await invoke<RawDisplayState>("get_display_state", { output });
+  return normalizeDisplayOutputState(raw);
+}
+
+export async function sendToDisplayOutput(output: string, state: DisplayOutputState): Promise<void> {
+  let backgroundImageDataUrl = "";
+  if (state.settings.backgroundImagePath) {
+    backgroundImageDataUrl = await loadImageAsDataUrl(state.settings.backgroundImagePath);
+  }
+  await invoke("update_display_state", {
+    output,
+    verseText: state.scripture.verseText,
+    reference: state.scripture.reference,
+    translationShortName: state.scripture.translationShortName ?? null,
+    slides: state.slides.lines.slice(0, 6),
+    settings: JSON.parse(
+      JSON.stringify({
+        ...state.settings,
+        timerFontSize: clampTimerFontSize(state.settings.timerFontSize),
+        backgroundImageDataUrl,
+      })
+    ),
+  });
+}
+
+export async function openDisplayOutputWindow(
+  output: string,
+  monitorIndex: number | null,
+  monitor: MonitorFingerprint | null = null
+): Promise<void> {
+  await invoke("open_audience_display_window", { monitorIndex, output, monitor });
+}
+
+export async function removeDisplayOutput(output: string): Promise<void> {
+  await invoke("remove_display_output", { output });
+}
+
+// ============================================================================
+// Display layout profiles
+// ============================================================================
+
+export async function getAvailableMonitorFingerprints(): Promise<MonitorFingerprint[]> {
+  return invoke<MonitorFingerprint[]>("get_available_monitors_safe");
+}
+
+export async function listDisplayLayoutProfiles(): Promise<DisplayLayoutProfile[]> {
+  return invoke<DisplayLayoutProfile[]>("list_display_layout_profiles");
+}
+
+export async function saveDisplayLayoutProfile(
+  name: string,
+  outputs: { output: string; monitor: MonitorFingerprint }[]
+): Promise<DisplayLayoutProfile> {
+  return invoke<DisplayLayoutProfile>("save_display_layout_profile", { name, outputs });
+}
+
+export async function deleteDisplayLayoutProfile(name: string): Promise<void> {
+  await invoke("delete_display_layout_profile", { name });
+}
+
+// Opens or moves every output in the profile onto its monitor.
+export async function applyDisplayLayoutProfile(name: string): Promise<LayoutProfileApplyResult[]> {
+  return invoke<LayoutProfileApplyResult[]>("apply_display_layout_profile", { name });
+}
+
+// ============================================================================
+// Display history
+// ============================================================================
+
+// `from`/`to` accept epoch ms, RFC 3339 or YYYY-MM-DD.
+export async function getDisplayHistory(from?: string, to?: string): Promise<DisplayHistoryEntry[]> {
+  return invoke<DisplayHistoryEntry[]>("get_display_history", { from: from ?? null, to: to ?? null });
+}
+
+export async function exportDisplayHistory(filePath: string, from?: string, to?: string): Promise<string> {
+  return invoke<string>("export_display_history", { filePath, from: from ?? null, to: to ?? null });
+}
+
+export async function clearDisplayHistory(): Promise<void> {
+  await invoke("clear_display_history");
+}
+
+export async function getCcliReport(from?: string, to?: string): Promise<CcliReport> {
+  return invoke<CcliReport>("get_ccli_report", { from: from ?? null, to: to ?? null });
+}
+
+export async function exportCcliReport(filePath: string, from?: string, to?: string): Promise<string> {
+  return invoke<string>("export_ccli_report", { filePath, from: from ?? null, to: to ?? null });
+}
+
 export function initializeAudienceDisplayOnStartup(options?: {
   maxRetries?: number;
   retryDelayMs?: number;
@@ -444,11 +622,18 @@ export function initializeAudienceDisplayOnStartup(options?: {
       return;
     }
 
-    let monitorIndex = settings.monitorIndex;
+    let monitorIndex = settings.monitor ? findMonitorIndex(monitors, settings.monitor) : null;
+    monitorIndex ??= settings.monitorIndex;
     if (monitorIndex === null || monitorIndex >= monitors.length) {
       monitorIndex = monitors.length > 1 ? 1 : 0;
-      const updatedSettings = { ...settings, monitorIndex };
+      const updatedSettings = {
+        ...settings,
+        monitorIndex,
+        monitor: toMonitorFingerprint(monitors[monitorIndex]),
+      };
       saveDisplaySettings(updatedSettings);
+    } else if (monitorIndex !== settings.monitorIndex) {
+      saveDisplaySettings({ ...settings, monitorIndex });
     }
 
     const isWindows = navigator.userAgent.includes("Win");
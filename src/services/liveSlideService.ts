@@ -1,5 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 import {
+  LiveSlideAction,
+  LiveSlideCursor,
   LiveSlideSession,
   LiveSlidesState,
   LiveSlidesSettings,
@@ -128,6 +130,19 @@ export async function getLiveSlideSessions(): Promise<LiveSlideSession[]> {
   return await invoke<LiveSlideSession[]>("get_live_slide_sessions");
 }
 
+export async function getLiveSlideCursor(sessionId: string): Promise<LiveSlideCursor> {
+  return await invoke<LiveSlideCursor>("get_live_slide_cursor", { sessionId });
+}
+
+// Moves the server's live cursor, which every client (including this window) follows.
+export async function controlLiveSlide(
+  sessionId: string,
+  action: LiveSlideAction,
+  index?: number
+): Promise<LiveSlideCursor> {
+  return await invoke<LiveSlideCursor>("control_live_slide", { sessionId, action, index });
+}
+
 export async function getLiveSlidesServerInfo(): Promise<LiveSlidesState> {
   return await invoke<LiveSlidesState>("get_live_slides_server_info");
 }
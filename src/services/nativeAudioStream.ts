@@ -0,0 +1,141 @@
+/**
+ * Native Audio Stream
+ *
+ * Starts the native (cpal) capture stream and receives PCM16 chunks as raw
+ * bytes over a Tauri IPC channel, instead of base64 `native_audio_chunk`
+ * events. Also pushes PCM to the native ASR as a raw request body.
+ */
+
+import { Channel, invoke } from "@tauri-apps/api/core";
+
+// A specific supported device config (see listNativeAudioInputConfigs);
+// unset fields use the device default.
+export interface NativeAudioInputConfig {
+  sampleRate?: number;
+  channels?: number;
+}
+
+export interface NativeAudioStreamOptions {
+  deviceId?: string | null;
+  input?: NativeAudioInputConfig;
+  channel?: string | null;   // "average" (default), "left", "right" or a 1-based channel number
+  sampleRate?: number;       // 8000-48000 Hz, default 16000
+  chunkMs?: number;          // 20-1000 ms, default 250
+  meterIntervalMs?: number;  // 50-100 ms, default 100
+}
+
+// What the backend actually streams (options are clamped to their ranges).
+export interface NativeAudioStreamFormat {
+  sample_rate: number;
+  chunk_ms: number;
+  chunk_samples: number;
+  binary: boolean;
+}
+
+export interface NativeAudioStream {
+  format: NativeAudioStreamFormat;
+  // Stops delivering chunks (and errors) to the callbacks; the capture keeps
+  // running until stopNativeAudioStream().
+  detach: () => void;
+}
+
+function toInt16(data: ArrayBuffer | Uint8Array | number[]): Int16Array {
+  if (data instanceof ArrayBuffer) return new Int16Array(data);
+  const bytes = data instanceof Uint8Array ? data.slice() : Uint8Array.from(data);
+  return new Int16Array(bytes.buffer, 0, Math.floor(bytes.byteLength / 2));
+}
+
+export async function startNativeAudioStream(
+  options: NativeAudioStreamOptions,
+  onChunk: (pcm16: Int16Array) => void,
+  onError?: (message: string) => void
+): Promise<NativeAudioStream> {
+  let attached = true;
+  const channel = new Channel<ArrayBuffer | Uint8Array | number[]>();
+  channel.onmessage = (data) => {
+    if (!attached) return;
+    const pcm16 = toInt16(data);
+    if (pcm16.length > 0) onChunk(pcm16);
+  };
+
+  const unlistenError = onError
+    ? await listenNativeAudioError((error) => {
+        if (attached && error.source === "stream") onError(error.message);
+      })
+    : null;
+
+  let format: NativeAudioStreamFormat;
+  try {
+    format = await invoke<NativeAudioStreamFormat>("start_native_audio_stream", {
+      deviceId: options.deviceId ?? undefined,
+      channel: options.channel ?? undefined,
+      sampleRate: options.sampleRate,
+      chunkMs: options.chunkMs,
+      meterIntervalMs: options.meterIntervalMs,
+      input: options.input,
+      onChunk: channel,
+    });
+  } catch (err) {
+    unlistenError?.();
+    throw err;
+  }
+
+  return {
+    format,
+    detach: () => {
+      attached = false;
+      unlistenError?.();
+    },
+  };
+}
+
+export async function stopNativeAudioStream(): Promise<void> {
+  await invoke("stop_native_audio_stream");
+}
+
+// A config range the device supports, in its native sample format.
+export interface NativeAudioSupportedConfig {
+  sample_format: string; // "i16", "i24", "f32", ...
+  channels: number;
+  min_sample_rate: number;
+  max_sample_rate: number;
+  is_default: boolean;
+}
+
+export async function listNativeAudioInputConfigs(
+  deviceId?: string | null
+): Promise<NativeAudioSupportedConfig[]> {
+  return await invoke<NativeAudioSupportedConfig[]>("list_native_audio_input_configs", {
+    deviceId: deviceId ?? undefined,
+  });
+}
+
+// Emitted when a running capture stream or recording fails (e.g. the
+// device was unplugged). Start failures are returned by the start call.
+export interface NativeAudioError {
+  source: "stream" | "recording";
+  message: string;
+}
+
+export async function listenNativeAudioError(
+  handler: (error: NativeAudioError) => void
+): Promise<() => void> {
+  const { listen } = await import("@tauri-apps/api/event");
+  return await listen<NativeAudioError>("native_audio_error", (evt) => handler(evt.payload));
+}
+
+export function int16ToFloat32(pcm16: Int16Array): Float32Array {
+  const float32 = new Float32Array(pcm16.length);
+  for (let i = 0; i < pcm16.length; i++) {
+    float32[i] = pcm16[i] / 32768.0;
+  }
+  return float32;
+}
+
+// Sends PCM16 to the native ASR (macOS) without a JSON number array.
+export async function pushNativeAsrAudio(pcm16: Int16Array): Promise<void> {
+  await invoke(
+    "asr_push_audio_raw",
+    new Uint8Array(pcm16.buffer, pcm16.byteOffset, pcm16.byteLength)
+  );
+}
@@ -22,6 +22,7 @@ import {
   AUDIO_BITRATE_CONFIG,
   RecordingResult,
 } from "../types/recorder";
+import type { NativeAudioInputConfig } from "./nativeAudioStream";
 
 // ============================================================================
 // Settings Management
@@ -229,13 +230,15 @@ export async function startNativeAudioRecording(
   deviceId: string | null,
   filePath: string,
   sampleRate?: number,
-  warmupMs?: number
+  warmupMs?: number,
+  input?: NativeAudioInputConfig
 ): Promise<void> {
   await invoke("start_native_audio_recording", {
     deviceId,
     filePath,
     sampleRate: sampleRate || 48000,
     warmupMs: warmupMs ?? 800,
+    input,
   });
 }
 
@@ -251,6 +254,31 @@ export async function getNativeAudioRecordingDuration(): Promise<number> {
   return await invoke<number>("get_audio_recording_duration");
 }
 
+// ============================================================================
+// Native Audio Level Metering
+// ============================================================================
+
+// Emitted by the native capture stream every 50-100ms (also sent to /ws
+// clients that send { type: "join_audio_levels" }, as { type: "native_audio_level", level }).
+export interface NativeAudioLevel {
+  rms_db: number;
+  peak_db: number;
+  clipping: boolean;
+  clipped_samples: number;
+  silent: boolean;       // below -60 dBFS for 3s or more
+  silence_ms: number;
+  block_ms: number;
+  sample_rate: number;
+  channels: number;
+}
+
+export async function listenNativeAudioLevel(
+  handler: (level: NativeAudioLevel) => void
+): Promise<() => void> {
+  const { listen } = await import("@tauri-apps/api/event");
+  return await listen<NativeAudioLevel>("native_audio_level", (evt) => handler(evt.payload));
+}
+
 // ============================================================================
 // Streaming Video Recording (Production-Grade - No Memory Accumulation)
 // ============================================================================
@@ -6,13 +6,27 @@ export interface ScheduleUpdatePayload {
   currentSessionIndex: number | null;
 }
 
+export interface ScheduleIssue {
+  index: number;
+  item_id: number;
+  code: string;
+  message: string;
+}
+
+export interface ScheduleValidation {
+  valid: boolean;
+  items: { index: number; id: number; start: string; end: string; duration_secs: number }[];
+  issues: ScheduleIssue[];
+}
+
 /**
- * Update the schedule on the server for remote viewing
+ * Update the schedule on the server for remote viewing.
+ * Resolves with the server's validation of the schedule's times (null on failure).
  */
 export async function updateSchedule(
   schedule: ScheduleItem[],
   currentSessionIndex: number | null
-): Promise<void> {
+): Promise<ScheduleValidation | null> {
   try {
     // Convert ScheduleItem to match Rust structure
     const rustSchedule = schedule.map((item) => ({
@@ -24,32 +38,93 @@ export async function updateSchedule(
       minister: item.minister,
     }));
 
-    await invoke("update_schedule", {
+    const validation = await invoke<ScheduleValidation>("update_schedule", {
       schedule: rustSchedule,
       currentSessionIndex: currentSessionIndex !== null ? currentSessionIndex : undefined,
     });
+    if (!validation.valid) {
+      console.warn("Schedule has timing issues:", validation.issues);
+    }
+    return validation;
   } catch (error) {
     console.error("Failed to update schedule on server:", error);
     // Don't throw - this is a non-critical feature
+    return null;
   }
 }
 
 /**
- * Update the timer state on the server for remote viewing
+ * Let the server advance the current session at each start time and start its countdown.
+ * Rejects with the validation messages when the stored schedule can't be parsed.
  */
-export async function updateTimerState(timerState: TimerState): Promise<void> {
-  try {
-    console.log("Updating timer state on server:", timerState);
-    await invoke("update_timer_state", {
-      // Tauri command args are camelCased on the JS side
-      isRunning: timerState.isRunning,
-      timeLeft: timerState.timeLeft,
-      sessionName: timerState.sessionName || undefined,
-      endTime: timerState.endTime || undefined,
-      isOverrun: timerState.isOverrun || false,
-    });
-  } catch (error) {
-    console.error("Failed to update timer state on server:", error);
-    // Don't throw - this is a non-critical feature
-  }
+export async function setScheduleAutoAdvance(enabled: boolean): Promise<void> {
+  await invoke("set_schedule_auto_advance", { enabled });
+}
+
+// The server timer engine's state, as published in `timer-update`.
+export interface ServerTimerState {
+  is_running: boolean;
+  time_left: number;
+  session_name?: string | null;
+  end_time?: string | null;
+  is_overrun: boolean;
+  mode?: "countdown" | "count_up";
+  deadline?: number | null;
+  server_time?: number | null;
+}
+
+export function toTimerState(state: ServerTimerState): TimerState {
+  return {
+    isRunning: state.is_running,
+    timeLeft: state.time_left,
+    sessionName: state.session_name ?? "",
+    endTime: state.end_time ?? "",
+    isOverrun: state.is_overrun,
+  };
+}
+
+export interface StartServerTimerOptions {
+  seconds?: number;
+  endTime?: string; // local time of day, e.g. "7:30 PM", used when no seconds are given
+  sessionName?: string;
+}
+
+/**
+ * Start the server timer engine, which owns the countdown shown in the
+ * desktop UI, the audience display and stage clients.
+ */
+export async function startServerTimer(options: StartServerTimerOptions): Promise<TimerState> {
+  const state = await invoke<ServerTimerState>("start_timer", {
+    seconds: options.seconds,
+    endTime: options.endTime || undefined,
+    sessionName: options.sessionName || undefined,
+  });
+  return toTimerState(state);
+}
+
+export async function pauseServerTimer(): Promise<TimerState> {
+  return toTimerState(await invoke<ServerTimerState>("pause_timer"));
+}
+
+export async function resumeServerTimer(): Promise<TimerState> {
+  return toTimerState(await invoke<ServerTimerState>("resume_timer"));
+}
+
+export async function stopServerTimer(): Promise<TimerState> {
+  return toTimerState(await invoke<ServerTimerState>("stop_timer"));
+}
+
+export async function getServerTimerState(): Promise<TimerState> {
+  return toTimerState(await invoke<ServerTimerState>("get_timer_state"));
+}
+
+/**
+ * Follow the server timer; the engine publishes `timer-update` on every
+ * start/stop and twice a second while running.
+ */
+export async function listenTimerUpdate(
+  handler: (timerState: TimerState) => void
+): Promise<() => void> {
+  const { listen } = await import("@tauri-apps/api/event");
+  return await listen<ServerTimerState>("timer-update", (evt) => handler(toTimerState(evt.payload)));
 }
@@ -0,0 +1,36 @@
+/**
+ * Stage Message Service
+ *
+ * Private messages for the speaker on stage clients (timer and schedule
+ * views), sent from the desktop or the REST API. The desktop shows the
+ * active message so the operator knows what the stage is seeing.
+ */
+
+import { invoke } from "@tauri-apps/api/core";
+
+export type StageMessagePriority = "low" | "normal" | "high" | "urgent";
+
+export interface StageMessage {
+  id: string;
+  text: string;
+  flash: boolean;
+  priority: StageMessagePriority;
+  created_at: number;
+  expires_at?: number | null; // Unix ms; unset = shown until cleared
+}
+
+export async function getStageMessage(): Promise<StageMessage | null> {
+  return await invoke<StageMessage | null>("get_stage_message");
+}
+
+export async function clearStageMessage(): Promise<void> {
+  await invoke("clear_stage_message");
+}
+
+// Called with the new message, or null when it is cleared or expires.
+export async function listenStageMessage(
+  handler: (message: StageMessage | null) => void
+): Promise<() => void> {
+  const { listen } = await import("@tauri-apps/api/event");
+  return await listen<StageMessage | null>("stage-message", (evt) => handler(evt.payload ?? null));
+}
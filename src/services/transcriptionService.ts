@@ -17,6 +17,11 @@
 
 import { getAssemblyAITemporaryToken } from "./assemblyaiTokenService";
 import { invoke } from "@tauri-apps/api/core";
+import {
+  int16ToFloat32,
+  pushNativeAsrAudio,
+  startNativeAudioStream,
+} from "./nativeAudioStream";
 import {
   TranscriptionEngine,
   TranscriptionCallbacks,
@@ -38,6 +43,18 @@ type NativeAudioInputDevice = {
   is_default: boolean;
 };
 
+// A native capture stream that fails mid-session (e.g. the mic was unplugged)
+// ends the session so the operator sees the error instead of silence.
+const stopOnNativeStreamError =
+  (service: ITranscriptionService, onError: (error: Error) => void) =>
+  (message: string): void => {
+    console.error("❌ Native audio stream error:", message);
+    onError(new Error(`Microphone capture failed: ${message}`));
+    service.stopTranscription().catch((err) => {
+      console.error("Error stopping transcription after capture error:", err);
+    });
+  };
+
 const isMacOS = (): boolean =>
   typeof navigator !== "undefined" && /Mac/.test(navigator.userAgent || "");
 
@@ -87,6 +104,8 @@ export interface ITranscriptionService {
   setMicrophone(deviceId: string): void;
   setAudioCaptureMode?(mode: "webrtc" | "native"): void;
   setNativeMicrophoneDeviceId?(deviceId: string | null): void;
+  // "average" (default), "left", "right" or a 1-based channel number
+  setNativeMicrophoneChannel?(channel: string | null): void;
   destroy?(): void; // Optional cleanup method to terminate workers and release resources
 }
 
@@ -117,6 +136,7 @@ export class AssemblyAITranscriptionService implements ITranscriptionService {
   private selectedMicId: string = "";
   private audioCaptureMode: "webrtc" | "native" = "webrtc";
   private selectedNativeDeviceId: string | null = null;
+  private selectedNativeChannel: string | null = null;
   private nativeUnlisten: null | (() => void) = null;
   private callbacks: TranscriptionCallbacks = {};
   private _isRecording: boolean = false;
@@ -184,6 +204,10 @@ export class AssemblyAITranscriptionService implements ITranscriptionService {
     this.selectedNativeDeviceId = deviceId;
   }
 
+  setNativeMicrophoneChannel(channel: string | null): void {
+    this.selectedNativeChannel = channel;
+  }
+
   async getNativeMicrophoneDevices(): Promise<NativeAudioInputDevice[]> {
     try {
       const mod = await import("@tauri-apps/api/core");
@@ -550,44 +574,25 @@ export class AssemblyAITranscriptionService implements ITranscriptionService {
     if (this.audioCaptureMode === "native") {
       console.log("🎛️ Using native audio capture (cpal) via Tauri backend...");
 
-      // Subscribe to native PCM chunks
-      const events = await import("@tauri-apps/api/event");
-      const core = await import("@tauri-apps/api/core");
-
-      const base64ToUint8Array = (b64: string): Uint8Array => {
-        const bin = atob(b64);
-        const bytes = new Uint8Array(bin.length);
-        for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
-        return bytes;
-      };
-
-      const unlisten = await events.listen<{ data_b64: string }>(
-        "native_audio_chunk",
-        (evt) => {
+      // Subscribe to native PCM chunks (raw bytes over an IPC channel)
+      const stream = await startNativeAudioStream(
+        {
+          deviceId: this.selectedNativeDeviceId,
+          channel: this.selectedNativeChannel,
+        },
+        (int16) => {
           if (!this.websocket || this.websocket.readyState !== WebSocket.OPEN) return;
           try {
-            const bytes = base64ToUint8Array(evt.payload.data_b64);
-            if (bytes.byteLength >= 2) {
-              const sampleCount = Math.floor(bytes.byteLength / 2);
-              const int16 = new Int16Array(
-                bytes.buffer,
-                bytes.byteOffset,
-                sampleCount
-              );
-              this.emitAudioLevel(this.computeRmsFromInt16(int16));
-            }
-            this.sendAudio(bytes);
+            this.emitAudioLevel(this.computeRmsFromInt16(int16));
+            this.sendAudio(int16);
           } catch {
-            // keep going; transient decode errors shouldn't kill transcription
+            // keep going; transient errors shouldn't kill transcription
           }
-        }
+        },
+        stopOnNativeStreamError(this, (error) => this.callbacks.onError?.(error))
       );
 
-      this.nativeUnlisten = unlisten;
-
-      await core.invoke("start_native_audio_stream", {
-        deviceId: this.selectedNativeDeviceId ?? undefined,
-      });
+      this.nativeUnlisten = stream.detach;
 
       console.log("🎙️ Native recording started");
     } else {
@@ -704,6 +709,7 @@ export class GroqTranscriptionService implements ITranscriptionService {
   private selectedMicId: string = "";
   private audioCaptureMode: "webrtc" | "native" = "webrtc";
   private selectedNativeDeviceId: string | null = null;
+  private selectedNativeChannel: string | null = null;
   private nativeUnlisten: null | (() => void) = null;
   private callbacks: TranscriptionCallbacks = {};
   private _isRecording: boolean = false;
@@ -752,6 +758,10 @@ export class GroqTranscriptionService implements ITranscriptionService {
     this.selectedNativeDeviceId = deviceId;
   }
 
+  setNativeMicrophoneChannel(channel: string | null): void {
+    this.selectedNativeChannel = channel;
+  }
+
   async getNativeMicrophoneDevices(): Promise<NativeAudioInputDevice[]> {
     try {
       const mod = await import("@tauri-apps/api/core");
@@ -876,28 +886,15 @@ export class GroqTranscriptionService implements ITranscriptionService {
 
   private async startNativeCapture() {
     console.log("🎛️ Using native audio capture for Groq...");
-    const events = await import("@tauri-apps/api/event");
-    const core = await import("@tauri-apps/api/core");
-
-    const base64ToFloat32Array = (b64: string): Float32Array => {
-      const bin = atob(b64);
-      const bytes = new Uint8Array(bin.length);
-      for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
-      // Convert PCM 16-bit (bytes) to Float32 (-1.0 to 1.0)
-      const int16View = new Int16Array(bytes.buffer);
-      const float32 = new Float32Array(int16View.length);
-      for (let i = 0; i < int16View.length; i++) {
-        float32[i] = int16View[i] / 32768.0;
-      }
-      return float32;
-    };
-
-    const unlisten = await events.listen<{ data_b64: string }>(
-      "native_audio_chunk",
-      (evt) => {
+    const stream = await startNativeAudioStream(
+      {
+        deviceId: this.selectedNativeDeviceId,
+        channel: this.selectedNativeChannel,
+      },
+      (int16) => {
         try {
-          const newSamples = base64ToFloat32Array(evt.payload.data_b64);
-          // Append to buffer
+          // Convert PCM 16-bit to Float32 (-1.0 to 1.0) and append to buffer
+          const newSamples = int16ToFloat32(int16);
           const tmp = new Float32Array(this.nativeBuffer.length + newSamples.length);
           tmp.set(this.nativeBuffer);
           tmp.set(newSamples, this.nativeBuffer.length);
@@ -905,13 +902,11 @@ export class GroqTranscriptionService implements ITranscriptionService {
         } catch (e) {
           console.error("Error processing native audio chunk:", e);
         }
-      }
+      },
+      stopOnNativeStreamError(this, (error) => this.callbacks.onError?.(error))
     );
 
-    this.nativeUnlisten = unlisten;
-    await core.invoke("start_native_audio_stream", {
-      deviceId: this.selectedNativeDeviceId ?? undefined,
-    });
+    this.nativeUnlisten = stream.detach;
   }
 
   private async processAudioChunk() {
@@ -1120,6 +1115,7 @@ export class MacNativeWhisperTranscriptionService implements ITranscriptionServi
   private audioCaptureMode: "webrtc" | "native" = "webrtc";
   private selectedMicId: string = "";
   private selectedNativeDeviceId: string | null = null;
+  private selectedNativeChannel: string | null = null;
   private nativeUnlisten: null | (() => void) = null;
 
   private mediaStream: MediaStream | null = null;
@@ -1171,6 +1167,10 @@ export class MacNativeWhisperTranscriptionService implements ITranscriptionServi
     this.selectedNativeDeviceId = deviceId;
   }
 
+  setNativeMicrophoneChannel(channel: string | null): void {
+    this.selectedNativeChannel = channel;
+  }
+
   async getNativeMicrophoneDevices(): Promise<NativeAudioInputDevice[]> {
     try {
       const mod = await import("@tauri-apps/api/core");
@@ -1271,21 +1271,13 @@ export class MacNativeWhisperTranscriptionService implements ITranscriptionServi
 
   private async startAudioCapture(): Promise<void> {
     if (this.audioCaptureMode === "native") {
-      const events = await import("@tauri-apps/api/event");
-      const core = await import("@tauri-apps/api/core");
-
-      const base64ToInt16Array = (b64: string): Int16Array => {
-        const bin = atob(b64);
-        const bytes = new Uint8Array(bin.length);
-        for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
-        return new Int16Array(bytes.buffer);
-      };
-
-      this.nativeUnlisten = await events.listen<{ data_b64: string }>(
-        "native_audio_chunk",
-        (evt) => {
+      const stream = await startNativeAudioStream(
+        {
+          deviceId: this.selectedNativeDeviceId,
+          channel: this.selectedNativeChannel,
+        },
+        (int16) => {
           try {
-            const int16 = base64ToInt16Array(evt.payload.data_b64);
             this.emitAudioLevel(this.computeRmsFromInt16(int16));
             void this.pushAudio(int16).catch(() => {
               // ignore
@@ -1293,12 +1285,10 @@ export class MacNativeWhisperTranscriptionService implements ITranscriptionServi
           } catch {
             // ignore
           }
-        }
+        },
+        stopOnNativeStreamError(this, (error) => this.callbacks.onError?.(error))
       );
-
-      await core.invoke("start_native_audio_stream", {
-        deviceId: this.selectedNativeDeviceId ?? undefined,
-      });
+      this.nativeUnlisten = stream.detach;
     } else {
       const constraints: MediaStreamConstraints = {
         audio: this.selectedMicId
@@ -1343,8 +1333,7 @@ export class MacNativeWhisperTranscriptionService implements ITranscriptionServi
   }
 
   private async pushAudio(pcm16: Int16Array): Promise<void> {
-    const payload = Array.from(pcm16);
-    await invoke("asr_push_audio", { pcmChunk: payload });
+    await pushNativeAsrAudio(pcm16);
   }
 
   private startPolling(): void {
@@ -1500,6 +1489,7 @@ export class OfflineWhisperTranscriptionService implements ITranscriptionService
   private selectedMicId: string = "";
   private audioCaptureMode: "webrtc" | "native" = "webrtc";
   private selectedNativeDeviceId: string | null = null;
+  private selectedNativeChannel: string | null = null;
   private nativeUnlisten: null | (() => void) = null;
   private nativeBuffer: Float32Array = new Float32Array(0);
   private rollingAudioBuffer: Float32Array = new Float32Array(0);
@@ -1575,6 +1565,10 @@ export class OfflineWhisperTranscriptionService implements ITranscriptionService
     this.selectedNativeDeviceId = deviceId;
   }
 
+  setNativeMicrophoneChannel(channel: string | null): void {
+    this.selectedNativeChannel = channel;
+  }
+
   async getNativeMicrophoneDevices(): Promise<NativeAudioInputDevice[]> {
     try {
       const mod = await import("@tauri-apps/api/core");
@@ -1946,26 +1940,14 @@ export class OfflineWhisperTranscriptionService implements ITranscriptionService
 
   private async startNativeCapture(): Promise<void> {
     console.log("🎛️ Using native audio capture for offline Whisper...");
-    const events = await import("@tauri-apps/api/event");
-    const core = await import("@tauri-apps/api/core");
-
-    const base64ToFloat32Array = (b64: string): Float32Array => {
-      const bin = atob(b64);
-      const bytes = new Uint8Array(bin.length);
-      for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
-      const int16View = new Int16Array(bytes.buffer);
-      const float32 = new Float32Array(int16View.length);
-      for (let i = 0; i < int16View.length; i++) {
-        float32[i] = int16View[i] / 32768.0;
-      }
-      return float32;
-    };
-
-    const unlisten = await events.listen<{ data_b64: string }>(
-      "native_audio_chunk",
-      (evt) => {
+    const stream = await startNativeAudioStream(
+      {
+        deviceId: this.selectedNativeDeviceId,
+        channel: this.selectedNativeChannel,
+      },
+      (int16) => {
         try {
-          const newSamples = base64ToFloat32Array(evt.payload.data_b64);
+          const newSamples = int16ToFloat32(int16);
           const tmp = new Float32Array(this.nativeBuffer.length + newSamples.length);
           tmp.set(this.nativeBuffer);
           tmp.set(newSamples, this.nativeBuffer.length);
@@ -1975,13 +1957,11 @@ export class OfflineWhisperTranscriptionService implements ITranscriptionService
         } catch (e) {
           console.error("Error processing native audio chunk:", e);
         }
-      }
+      },
+      stopOnNativeStreamError(this, (error) => this.callbacks.onError?.(error))
     );
 
-    this.nativeUnlisten = unlisten;
-    await core.invoke("start_native_audio_stream", {
-      deviceId: this.selectedNativeDeviceId ?? undefined,
-    });
+    this.nativeUnlisten = stream.detach;
   }
 
   private maybeProcessNativeAudio(): void {
@@ -2060,6 +2040,7 @@ export class OfflineMoonshineTranscriptionService implements ITranscriptionServi
   private selectedMicId: string = "";
   private audioCaptureMode: "webrtc" | "native" = "webrtc";
   private selectedNativeDeviceId: string | null = null;
+  private selectedNativeChannel: string | null = null;
   private nativeUnlisten: null | (() => void) = null;
   
   private readonly SAMPLE_RATE = 16000;
@@ -2092,6 +2073,10 @@ export class OfflineMoonshineTranscriptionService implements ITranscriptionServi
     this.selectedNativeDeviceId = deviceId;
   }
 
+  setNativeMicrophoneChannel(channel: string | null): void {
+    this.selectedNativeChannel = channel;
+  }
+
   async getNativeMicrophoneDevices(): Promise<NativeAudioInputDevice[]> {
     try {
       const mod = await import("@tauri-apps/api/core");
@@ -2355,38 +2340,24 @@ export class OfflineMoonshineTranscriptionService implements ITranscriptionServi
 
   private async startNativeCapture(): Promise<void> {
     console.log("🎛️ Using native audio capture for offline Moonshine...");
-    const events = await import("@tauri-apps/api/event");
-    const core = await import("@tauri-apps/api/core");
-
-    const base64ToFloat32Array = (b64: string): Float32Array => {
-      const bin = atob(b64);
-      const bytes = new Uint8Array(bin.length);
-      for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
-      const int16View = new Int16Array(bytes.buffer);
-      const float32 = new Float32Array(int16View.length);
-      for (let i = 0; i < int16View.length; i++) {
-        float32[i] = int16View[i] / 32768.0;
-      }
-      return float32;
-    };
-
-    const unlisten = await events.listen<{ data_b64: string }>(
-      "native_audio_chunk",
-      (evt) => {
+    const stream = await startNativeAudioStream(
+      {
+        deviceId: this.selectedNativeDeviceId,
+        channel: this.selectedNativeChannel,
+      },
+      (int16) => {
         if (!this.worker || !this._isRecording || !this.isModelReady) return;
         try {
-          const buffer = base64ToFloat32Array(evt.payload.data_b64);
+          const buffer = int16ToFloat32(int16);
           this.worker.postMessage({ type: "buffer", data: { buffer } });
         } catch (e) {
           console.error("Error processing native audio chunk:", e);
         }
-      }
+      },
+      stopOnNativeStreamError(this, (error) => this.callbacks.onError?.(error))
     );
 
-    this.nativeUnlisten = unlisten;
-    await core.invoke("start_native_audio_stream", {
-      deviceId: this.selectedNativeDeviceId ?? undefined,
-    });
+    this.nativeUnlisten = stream.detach;
   }
 
   async stopTranscription(): Promise<void> {
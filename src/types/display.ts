@@ -45,21 +45,42 @@ export type SlideLineStyle = Partial<FontStyle> & {
   fontFamily?: string;
 };
 
+export type DisplayMargins = {
+  top: number;
+  right: number;
+  bottom: number;
+  left: number;
+};
+
+export type DisplayTransition = {
+  kind: "none" | "fade";
+  durationMs: number;
+};
+
+// Mirrors DisplaySettings in src-tauri/src/display_settings.rs, which
+// validates it in update_display_state.
 export type DisplaySettings = {
+  version?: number;
   enabled: boolean;
   windowAudienceScreen: boolean;
   webEnabled: boolean;
   displayTranslation: boolean;
   monitorIndex: number | null;
+  // The monitor picked by monitorIndex; still found after monitors are rearranged.
+  monitor?: MonitorFingerprint | null;
   backgroundColor: string;
   backgroundImagePath?: string;
   textFont: string;
   referenceFont: string;
+  textFontSize?: number | null; // px; unset fits the text to its box
+  referenceFontSize?: number | null;
   textStyle: FontStyle;
   referenceStyle: FontStyle;
   layout: DisplayLayout;
   slidesLayout: DisplayLayoutRect[];
   slidesLineStyles: SlideLineStyle[];
+  margins?: DisplayMargins;
+  transition?: DisplayTransition;
   showTimer: boolean;
   timerFontSize: number;
 };
@@ -70,6 +91,73 @@ export type DisplayScripture = {
   translationShortName?: string;
 };
 
+// A named display output (main projector, stage monitor, lower-third, ...),
+// each with its own content and settings.
+export type DisplayOutputState = {
+  scripture: DisplayScripture;
+  slides: DisplaySlides;
+  settings: DisplaySettings;
+};
+
+// One entry of the persisted display history (what went live, and when).
+export type DisplayHistoryEntry = {
+  timestamp: number;
+  kind: "display_update" | "slide_go_live" | "scripture_go_live";
+  output?: string | null;
+  reference?: string | null;
+  translation?: string | null;
+  text: string;
+  liveSlideSessionId?: string | null;
+  liveSlideSessionName?: string | null;
+  slideIndex?: number | null;
+  ccliNumber?: string | null;
+  songTitle?: string | null;
+  scheduleIndex?: number | null;
+  scheduleSession?: string | null;
+  scheduleMinister?: string | null;
+};
+
+// A monitor as reported by get_available_monitors_safe. Saved profiles match
+// on name, size and scale, so they survive monitors being rearranged.
+export type MonitorFingerprint = {
+  name: string | null;
+  position: [number, number];
+  size: [number, number];
+  scale_factor: number;
+  fingerprint?: string;
+};
+
+// A named arrangement ("Sunday main hall") of display outputs on monitors.
+export type DisplayLayoutProfile = {
+  name: string;
+  outputs: { output: string; monitor: MonitorFingerprint }[];
+  updatedAt: number;
+};
+
+export type LayoutProfileApplyResult = {
+  output: string;
+  status: "opened" | "moved" | "unchanged" | "monitor_missing" | "failed";
+  monitor: MonitorFingerprint | null;
+  error: string | null;
+};
+
+export type CcliSong = {
+  ccliNumber: string;
+  title: string;
+};
+
+// Song usage for CCLI reporting; each song counts once per service. A service
+// ends after an hour with nothing live or when the schedule starts over.
+export type CcliReport = {
+  from: number | null;
+  to: number | null;
+  services: { date: string; startedAt: number; endedAt: number; songs: CcliSong[] }[];
+  songs: (CcliSong & { projections: number; serviceDates: string[] })[];
+};
+
+export const MAIN_DISPLAY_OUTPUT = "main";
+export const DISPLAY_SETTINGS_VERSION = 1;
+
 export const DISPLAY_SETTINGS_KEY = "proassist-display-settings";
 export const DISPLAY_SCRIPTURE_KEY = "proassist-display-scripture";
 export const DISPLAY_SLIDES_KEY = "proassist-display-slides";
@@ -106,11 +194,13 @@ export const DEFAULT_SLIDES_LAYOUT: DisplayLayoutRect[] = [
 ];
 
 export const DEFAULT_DISPLAY_SETTINGS: DisplaySettings = {
+  version: DISPLAY_SETTINGS_VERSION,
   enabled: false,
   windowAudienceScreen: false,
   webEnabled: false,
   displayTranslation: true,
   monitorIndex: null,
+  monitor: null,
   backgroundColor: "#000000",
   backgroundImagePath: "",
   textFont: "system-ui, -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif",
@@ -128,6 +218,8 @@ export const DEFAULT_DISPLAY_SETTINGS: DisplaySettings = {
   layout: DEFAULT_DISPLAY_LAYOUT,
   slidesLayout: DEFAULT_SLIDES_LAYOUT,
   slidesLineStyles: [],
+  margins: { top: 0, right: 0, bottom: 0, left: 0 },
+  transition: { kind: "none", durationMs: 300 },
   showTimer: false,
   timerFontSize: 150,
 };
@@ -82,6 +82,8 @@ export interface PlaylistItem {
    * This is the canonical serialization for Live Slides (blank line = new slide).
    */
   liveSlidesCachedRawText?: string;
+  /** CCLI song number, reported when the item is projected. "CCLI 12345" in the title also works. */
+  ccliNumber?: string;
   /**
    * Default ProPresenter presentation activation config for all slides in this item.
    * Individual slides can override this with their own proPresenterActivation property.
@@ -16,8 +16,20 @@ export interface LiveSlideSession {
   slides: LiveSlide[];
   raw_text: string;
   created_at: number;
+  ccli_number?: string | null; // From an import; used for CCLI usage reporting
 }
 
+// The slide on screen for a session, as kept by the server (`index` is null when cleared).
+export interface LiveSlideCursor {
+  session_id: string;
+  index: number | null;
+  total: number;
+  slide: LiveSlide | null;
+  updated_at: number;
+}
+
+export type LiveSlideAction = "next" | "prev" | "goto" | "clear";
+
 export interface LiveSlidesState {
   sessions: Record<string, LiveSlideSession>;
   server_running: boolean;
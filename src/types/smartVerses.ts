@@ -258,6 +258,7 @@ export interface SmartVersesSettings {
   selectedMicrophoneId?: string;
   audioCaptureMode?: AudioCaptureMode;
   selectedNativeMicrophoneId?: string;
+  nativeMicrophoneChannel?: string; // "average" (default), "left", "right" or a 1-based channel number
   streamTranscriptionsToWebSocket: boolean;
   runTranscriptionInBrowser?: boolean; // When true, opens external browser for transcription
   remoteTranscriptionEnabled?: boolean;